@@ -11,11 +11,24 @@ mod utils;
 type Result<T> = std::result::Result<T, syn::Error>;
 
 /// This macro setups the entry point of the framework.
+///
+/// Besides the program id, it accepts the options:
+/// - `heap_size = <bytes>`: replaces the default allocator with one that uses
+///   a heap of the given size, up to 256KB. It is opt-in: the allocator is
+///   only installed when the program enables its `custom-heap` feature, which
+///   also disables the default allocator, otherwise the default 32KB heap is
+///   kept. The allocator cannot know whether the heap frame was requested, so
+///   every transaction that executes the program must request it, including
+///   the ones that call it via CPI from other programs. Otherwise the program
+///   fails with an access violation in the first allocation beyond 32KB. The
+///   generated TypeScript `buildTransaction` and `withHeapFrame` helpers
+///   prepend the request to the instructions of the transaction.
+/// - `log_heap_usage`: logs the heap usage at the end of every instruction.
 #[proc_macro]
 pub fn setup(args: TokenStream) -> TokenStream {
-    let pubkey = parse_macro_input!(args as LitStr);
+    let args = parse_macro_input!(args as macros::setup::SetupArguments);
 
-    match macros::setup::processor(pubkey) {
+    match macros::setup::processor(args) {
         Ok(v) => v,
         Err(e) => e.to_compile_error().into(),
     }
@@ -174,9 +187,59 @@ pub fn enum_discriminants(input: TokenStream) -> TokenStream {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Generates a compact flag set from a fieldless enum. Every variant is a bit
+/// whose index can be fixed with the `discriminant` attribute:
+/// ```none
+/// #[derive(FankorFlags)]
+/// pub enum Permission {
+///     Read,
+///     Write,
+///     #[discriminant = 7]
+///     Admin,
+/// }
+/// ```
+/// This generates `PermissionFlags` backed by the smallest unsigned integer
+/// that fits all the bits, implementing `FankorFlags`, `CopyType` and Borsh.
+#[proc_macro_derive(FankorFlags, attributes(discriminant))]
+pub fn flags(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Item);
+
+    match macros::flags::processor(input) {
+        Ok(v) => v,
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 /// Derives the `Instruction` trait for the given struct/enum as well as:
 /// - `EnumDiscriminants` if it is an enum
 /// - `TsGen`
+///
+/// The callers of the instruction can be restricted with the attributes:
+/// - `top_level_only`: rejects the instruction when it is called through a CPI.
+/// - `callable_by = [<program>, ...]`: only accepts CPIs made directly by the
+///   top-level instruction of one of the programs. Deeper CPIs are rejected
+///   because their caller cannot be determined.
+/// - `no_reentrancy`: rejects the CPIs made by the current program to itself.
+///   The runtime already rejects re-entries through other programs, e.g.
+///   `X -> A -> B -> A`, and deeper CPIs are rejected because their caller
+///   cannot be determined, e.g. `X -> A -> A`.
+///
+/// Both `callable_by` and `no_reentrancy` require the Instructions sysvar in
+/// the account list.
+///
+/// The accounts that are not validated must be justified with a `/// CHECK:`
+/// doc comment or `#[account(unchecked = "<reason>")]`, also when they are
+/// wrapped in `Option`, `Vec`, `Box`, `Either`, `SingleEither` or
+/// `MaybeUninitialized`:
+/// - unchecked accounts without owner, address, signer or pda constraints.
+/// - writable accounts that are neither signer-gated nor constrained.
+///
+/// In pausable programs, `allow_when_paused` lets the instruction be executed
+/// while the program is paused. See `#[program(pausable = <account>)]`.
 #[proc_macro_attribute]
 pub fn instruction(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(args as FnkMetaArgumentList);
@@ -228,6 +291,33 @@ pub fn error_code(args: TokenStream, input: TokenStream) -> TokenStream {
 ///
 /// If `RESULT` is different from `()` then the instruction will store the result in the intermediate buffer as
 /// the instruction result.
+///
+/// The processor of the accounts must borrow them, i.e. `fn processor(&mut self, context: FankorContext)`,
+/// because after it succeeds the program runs their exit phase, `Instruction::exit`, that saves, reallocates
+/// or closes them returning its errors to the client.
+///
+/// The dispatch of the instructions can be wrapped with hooks following the signature:
+///
+/// ```none
+/// fn my_hook(context: &FankorContext, discriminant: u8, instruction_data: &[u8]) -> Result<()>;
+/// ```
+///
+/// - `before = <hook>`: runs before every instruction, including the fallback.
+/// - `after = <hook>`: runs after every instruction only if it succeeds.
+///
+/// A `Batch` instruction runs the hooks once for the whole batch, receiving its
+/// discriminant and data, not once per batched instruction.
+///
+/// The `pausable = <account>` argument, being `<account>` a type implementing
+/// `PausableConfig`, adds the `Pause`, `Unpause` and `TransferAdmin`
+/// instructions and rejects the rest while the program is paused. They expect
+/// the config account after their own accounts, unless they are declared with
+/// `#[instruction(allow_when_paused)]`.
+///
+/// The config account is not created by the program macro because the
+/// program must decide who can create it, e.g. only its upgrade authority.
+/// Create it from an instruction allowed while paused with
+/// `UninitializedAccount::init_pause_config`.
 #[proc_macro_attribute]
 pub fn program(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(args as FnkMetaArgumentList);
@@ -22,7 +22,8 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
             let mut fields = Vec::new();
             let mut codes = Vec::new();
             let mut discriminants = Vec::new();
-            let mut variant_idx = 0u8;
+            // Kept as u16 so the discriminant after 255 can be computed.
+            let mut variant_idx = 0u16;
             let mut used_discriminants = HashSet::new();
             let mut is_last_deprecated = false;
 
@@ -34,7 +35,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 });
 
                 let is_deprecated = is_deprecated(&variant.attrs);
-                let discriminant = get_discriminant(variant)?;
+                let discriminant = get_discriminant::<u8>(variant)?.map(u16::from);
 
                 // Calculate the discriminant.
                 if let Some(v) = discriminant {
@@ -56,6 +57,13 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                     ));
                 }
 
+                let code = u8::try_from(variant_idx).map_err(|_| {
+                    Error::new(
+                        variant.span(),
+                        "The discriminant cannot be greater than 255",
+                    )
+                })?;
+
                 if used_discriminants.contains(&variant_idx) {
                     return Err(Error::new(
                         variant.span(),
@@ -87,7 +95,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 }
 
                 codes.push(quote!(
-                    Self::#variant_ident => #variant_idx
+                    Self::#variant_ident => #code
                 ));
 
                 variant_idx += 1;
@@ -188,10 +196,15 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
 
 pub fn is_deprecated(attrs: &[Attribute]) -> bool {
     for attr in attrs.iter() {
-        if let Meta::Path(path) = &attr.meta {
-            if path.is_ident("deprecated") {
-                return true;
-            }
+        // Matches `#[deprecated]`, `#[deprecated(..)]` and `#[deprecated = ".."]`.
+        let path = match &attr.meta {
+            Meta::Path(path) => path,
+            Meta::List(list) => &list.path,
+            Meta::NameValue(name_value) => &name_value.path,
+        };
+
+        if path.is_ident("deprecated") {
+            return true;
         }
     }
     false
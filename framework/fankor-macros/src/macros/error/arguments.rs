@@ -1,5 +1,7 @@
 use crate::fnk_syn::FnkMetaArgumentList;
 use crate::Result;
+use proc_macro2::Span;
+use syn::Error;
 
 pub struct ErrorArguments {
     /// The starting offset of the error list.
@@ -7,6 +9,10 @@ pub struct ErrorArguments {
 
     /// Whether to add the TsGen macro or not.
     pub skip_ts_gen: bool,
+
+    /// Whether the errors are shared by all programs, so their TypeScript code
+    /// is generated by every program instead of being registered by the errors.
+    pub shared: bool,
 }
 
 impl ErrorArguments {
@@ -19,8 +25,16 @@ impl ErrorArguments {
         let result = ErrorArguments {
             offset: args.pop_number("offset", true)?,
             skip_ts_gen: args.pop_plain("skip_ts_gen", true)?,
+            shared: args.pop_plain("shared", true)?,
         };
 
+        if result.skip_ts_gen && result.shared {
+            return Err(Error::new(
+                Span::call_site(),
+                "The skip_ts_gen and shared arguments cannot be used together",
+            ));
+        }
+
         args.error_on_unknown()?;
 
         Ok(result)
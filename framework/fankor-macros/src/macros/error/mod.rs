@@ -7,6 +7,7 @@ use syn::{Error, Fields, Item};
 use crate::fnk_syn::FnkMetaArgumentList;
 use crate::macros::error::arguments::ErrorArguments;
 use crate::macros::error::variant::ErrorVariant;
+use crate::macros::ts_gen::ts_type_gen_impl;
 use crate::Result;
 
 mod arguments;
@@ -90,6 +91,48 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         }
     });
 
+    let name_hash_fn_variants = variants.iter().map(|v| {
+        let ErrorVariant {
+            name: variant_name,
+            fields,
+            ..
+        } = &v;
+        let variant_name_str = variant_name.to_string();
+
+        // Computed in a constant so the name is not included in the binary.
+        let hash = quote! {
+            {
+                const HASH: u32 = fankor::errors::error_name_hash(#variant_name_str);
+                HASH
+            }
+        };
+
+        match fields {
+            Fields::Named(_) => quote! {
+                #name::#variant_name{..} => #hash
+            },
+            Fields::Unnamed(_) => quote! {
+                #name::#variant_name(..) => #hash
+            },
+            Fields::Unit => quote! {
+                #name::#variant_name => #hash
+            },
+        }
+    });
+
+    let error_table_entries = variants
+        .iter()
+        .map(|v| {
+            let variant_name = &v.name;
+            let variant_name_str = variant_name.to_string();
+            let message_template = v.message_template.clone().unwrap_or_default();
+
+            quote! {
+                (#discriminant_name::#variant_name.code(), #variant_name_str, #message_template)
+            }
+        })
+        .collect::<Vec<_>>();
+
     let offset = match &attributes.offset {
         Some(offset) => quote! { + #offset },
         None => quote! { + 6000 },
@@ -199,7 +242,7 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         }
     });
 
-    let ts_gen = if attributes.skip_ts_gen {
+    let ts_gen = if attributes.skip_ts_gen || attributes.shared {
         quote! {}
     } else {
         quote! {
@@ -207,7 +250,7 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         }
     };
 
-    let result = quote! {
+    let final_enum = quote! {
         #[derive(::std::fmt::Debug, ::std::clone::Clone)]
         #[repr(u32)]
         #ts_gen
@@ -215,6 +258,47 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         #visibility enum #name #ty_generics #where_clause {
             #(#final_enum_variants,)*
         }
+    };
+
+    // The table of errors to reconstruct them off-chain.
+    let ts_error_table = if attributes.skip_ts_gen {
+        quote! {}
+    } else if attributes.shared {
+        // Shared errors are registered by the setup of every program, so only
+        // the TsTypeGen implementation is generated here.
+        let (ts_type_gen, _) = ts_type_gen_impl(&syn::parse2::<Item>(final_enum.clone())?)?;
+
+        quote! {
+            #[cfg(feature = "ts-gen")]
+            const _: () = {
+                #ts_type_gen
+            };
+        }
+    } else {
+        let table_name_str = format!("{}Table", name);
+        let test_name = format_ident!("__ts_gen_test__error_table_{}", name);
+        let test_name_str = test_name.to_string();
+
+        quote! {
+            #[cfg(feature = "ts-gen")]
+            #[automatically_derived]
+            #[allow(non_snake_case)]
+            mod #test_name {
+                use super::*;
+
+                #[test]
+                fn build() {
+                    // Register action.
+                    crate::__ts_gen_test__setup::BUILD_CONTEXT.register_action(#test_name_str, file!(), move |action_context| {
+                        action_context.add_error_table(#table_name_str, #name::ERROR_TABLE).unwrap();
+                    })
+                }
+            }
+        }
+    };
+
+    let result = quote! {
+        #final_enum
 
         #[automatically_derived]
         impl #impl_generics #name #ty_generics #where_clause {
@@ -224,6 +308,20 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
                 }
             }
 
+            /// The hash of the name, that identifies the error in the logs
+            /// when the `minimal-logs` feature is enabled.
+            pub const fn name_hash(&self) -> u32 {
+                match self {
+                    #(#name_hash_fn_variants),*
+                }
+            }
+
+            /// The code, name and message template of every error, to
+            /// reconstruct them off-chain.
+            pub const ERROR_TABLE: &'static [(u32, &'static str, &'static str)] = &[
+                #(#error_table_entries),*
+            ];
+
             pub fn message(&self) -> String {
                 format!("{}", self)
             }
@@ -251,10 +349,18 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         #[automatically_derived]
         impl #impl_generics From<#name #ty_generics> for fankor::errors::Error #where_clause {
             fn from(error_code: #name #ty_generics) -> fankor::errors::Error {
+                // Only the code and the name hash are kept to reduce the binary size.
+                let (error_name, error_msg) = if fankor::MINIMAL_LOGS {
+                    (String::new(), String::new())
+                } else {
+                    (error_code.name().to_string(), error_code.to_string())
+                };
+
                 fankor::errors::Error::from(fankor::errors::FankorError {
-                    error_name: error_code.name().to_string(),
+                    error_name,
+                    error_name_hash: error_code.name_hash(),
                     error_code_number: error_code.error_code(),
-                    error_msg: error_code.to_string(),
+                    error_msg,
                 })
             }
         }
@@ -276,6 +382,8 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
                 }
             }
         }
+
+        #ts_error_table
     };
 
     Ok(result.into())
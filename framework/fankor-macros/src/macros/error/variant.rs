@@ -9,6 +9,8 @@ use crate::Result;
 pub struct ErrorVariant {
     pub name: Ident,
     pub message: Option<TokenStream>,
+    /// The format string of the message, used to reconstruct it off-chain.
+    pub message_template: Option<String>,
     pub attributes: Vec<Attribute>,
     pub fields: Fields,
     pub code: Option<u32>,
@@ -28,6 +30,7 @@ impl ErrorVariant {
         let mut error_variant = ErrorVariant {
             name: variant.ident,
             message: None,
+            message_template: None,
             attributes: variant.attrs,
             fields: variant.fields,
             code,
@@ -67,12 +70,13 @@ impl ErrorVariant {
                     }
                 };
 
-                // Check first argument is a literal string.
+                // Check first argument is a literal string and keep it as the
+                // template of the message.
                 let expr_list: Punctuated<Expr, Token![,]> = parse_quote! { #args };
 
-                match expr_list.first() {
+                let message_template = match expr_list.first() {
                     Some(Expr::Lit(v)) => match &v.lit {
-                        Lit::Str(_) => {}
+                        Lit::Str(v) => v.value(),
                         v => {
                             return Err(Error::new(v.span(), "This must be a literal string"));
                         }
@@ -83,9 +87,10 @@ impl ErrorVariant {
                             "First attribute must be a literal string",
                         ));
                     }
-                }
+                };
 
                 self.message = Some(args.clone());
+                self.message_template = Some(message_template);
             } else if attribute_path.is_ident("deprecated") {
                 let attribute_span = attribute.span();
 
@@ -0,0 +1,349 @@
+use std::collections::HashSet;
+
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{Error, Fields, Item};
+
+use crate::macros::enum_discriminants::get_discriminant;
+use crate::Result;
+
+pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
+    let item = match &input {
+        Item::Enum(item) => item,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "FankorFlags macro can only be applied to enum declarations",
+            ));
+        }
+    };
+
+    if !item.generics.params.is_empty() {
+        return Err(Error::new(
+            item.generics.span(),
+            "FankorFlags macro does not support generics",
+        ));
+    }
+
+    let visibility = &item.vis;
+    let name = &item.ident;
+    let flags_name = format_ident!("{}Flags", name);
+
+    // Calculate the bit of each variant.
+    let mut variants = Vec::with_capacity(item.variants.len());
+    let mut bit_idx = 0u8;
+    let mut used_bits = HashSet::new();
+
+    for variant in item.variants.iter() {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(
+                variant.span(),
+                "FankorFlags macro can only be applied to fieldless enums",
+            ));
+        }
+
+        if let Some(v) = get_discriminant::<u8>(variant)? {
+            bit_idx = v;
+        }
+
+        if bit_idx >= 64 {
+            return Err(Error::new(
+                variant.span(),
+                "A flag set cannot contain more than 64 bits",
+            ));
+        }
+
+        if !used_bits.insert(bit_idx) {
+            return Err(Error::new(
+                variant.span(),
+                format!("The discriminant attribute is already in use: {}", bit_idx),
+            ));
+        }
+
+        variants.push((&variant.ident, bit_idx));
+        bit_idx += 1;
+    }
+
+    if variants.is_empty() {
+        return Err(Error::new(
+            item.span(),
+            "FankorFlags macro requires at least one variant",
+        ));
+    }
+
+    // Select the smallest integer that contains all the bits.
+    let max_bit = variants.iter().map(|(_, bit)| *bit).max().unwrap();
+    let (repr, byte_size) = match max_bit {
+        0..=7 => (quote!(u8), 1usize),
+        8..=15 => (quote!(u16), 2usize),
+        16..=31 => (quote!(u32), 4usize),
+        _ => (quote!(u64), 8usize),
+    };
+
+    let variant_names = variants.iter().map(|(ident, _)| ident).collect::<Vec<_>>();
+    let bits = variants
+        .iter()
+        .map(|(ident, bit)| quote!(Self::#ident => #bit))
+        .collect::<Vec<_>>();
+    let all_bits = variants
+        .iter()
+        .map(|(_, bit)| {
+            let mask = 1u64 << bit;
+            quote!(#mask)
+        })
+        .collect::<Vec<_>>();
+
+    let result = quote! {
+        #[automatically_derived]
+        impl #name {
+            /// The index of the bit of this flag.
+            pub const fn bit(&self) -> u8 {
+                match self {
+                    #(#bits,)*
+                }
+            }
+
+            /// The mask of this flag.
+            pub const fn mask(&self) -> #repr {
+                1 << self.bit()
+            }
+        }
+
+        #[automatically_derived]
+        #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+        #visibility struct #flags_name(#repr);
+
+        #[automatically_derived]
+        impl #flags_name {
+            pub const EMPTY: Self = Self(0);
+            pub const ALL: Self = Self((0 #(| #all_bits)*) as #repr);
+
+            // CONSTRUCTORS -------------------------------------------------------
+
+            /// Creates a new flag set from its bits, failing if any unknown bit is set.
+            pub const fn from_bits(bits: #repr) -> Option<Self> {
+                if bits & !Self::ALL.0 != 0 {
+                    None
+                } else {
+                    Some(Self(bits))
+                }
+            }
+
+            /// Creates a new flag set from its bits, ignoring the unknown ones.
+            pub const fn from_bits_truncate(bits: #repr) -> Self {
+                Self(bits & Self::ALL.0)
+            }
+
+            // GETTERS ------------------------------------------------------------
+
+            pub const fn bits(&self) -> #repr {
+                self.0
+            }
+
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            pub const fn is_all(&self) -> bool {
+                self.0 == Self::ALL.0
+            }
+
+            // METHODS ------------------------------------------------------------
+
+            /// Whether `flag` is set.
+            pub const fn contains(&self, flag: #name) -> bool {
+                self.0 & flag.mask() != 0
+            }
+
+            /// Whether all the flags of `other` are set.
+            pub const fn contains_all(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Whether any of the flags of `other` is set.
+            pub const fn intersects(&self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            pub fn insert(&mut self, flag: #name) {
+                self.0 |= flag.mask();
+            }
+
+            pub fn remove(&mut self, flag: #name) {
+                self.0 &= !flag.mask();
+            }
+
+            pub fn toggle(&mut self, flag: #name) {
+                self.0 ^= flag.mask();
+            }
+
+            /// Inserts or removes `flag` depending on `value`.
+            pub fn set(&mut self, flag: #name, value: bool) {
+                if value {
+                    self.insert(flag);
+                } else {
+                    self.remove(flag);
+                }
+            }
+
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            pub const fn intersection(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+
+            pub const fn difference(self, other: Self) -> Self {
+                Self(self.0 & !other.0)
+            }
+
+            /// Iterates over the flags that are set.
+            pub fn iter(&self) -> impl Iterator<Item = #name> {
+                let bits = self.0;
+                IntoIterator::into_iter([#(#name::#variant_names),*])
+                    .filter(move |flag| bits & flag.mask() != 0)
+            }
+        }
+
+        #[automatically_derived]
+        impl From<#name> for #flags_name {
+            fn from(flag: #name) -> Self {
+                Self(flag.mask())
+            }
+        }
+
+        #[automatically_derived]
+        impl std::iter::FromIterator<#name> for #flags_name {
+            fn from_iter<I: IntoIterator<Item = #name>>(iter: I) -> Self {
+                let mut result = Self::EMPTY;
+
+                for flag in iter {
+                    result.insert(flag);
+                }
+
+                result
+            }
+        }
+
+        #[automatically_derived]
+        impl std::ops::BitOr for #flags_name {
+            type Output = Self;
+
+            fn bitor(self, other: Self) -> Self {
+                self.union(other)
+            }
+        }
+
+        #[automatically_derived]
+        impl std::ops::BitAnd for #flags_name {
+            type Output = Self;
+
+            fn bitand(self, other: Self) -> Self {
+                self.intersection(other)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::fankor::prelude::borsh::BorshSerialize for #flags_name {
+            fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                ::fankor::prelude::borsh::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::fankor::prelude::borsh::BorshDeserialize for #flags_name {
+            fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+                let bits = <#repr as ::fankor::prelude::borsh::BorshDeserialize>::deserialize(buf)?;
+
+                Self::from_bits(bits).ok_or_else(|| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, "Unknown flags")
+                })
+            }
+        }
+
+        #[automatically_derived]
+        impl ::fankor::prelude::FankorFlags for #flags_name {
+            type Flag = #name;
+
+            const BYTE_SIZE: usize = #byte_size;
+
+            fn bits_u64(&self) -> u64 {
+                self.0 as u64
+            }
+
+            fn flag_bit(flag: &#name) -> u8 {
+                flag.bit()
+            }
+
+            fn from_bits_unchecked(bits: u64) -> Self {
+                Self(bits as #repr)
+            }
+
+            fn all_bits() -> u64 {
+                Self::ALL.0 as u64
+            }
+        }
+
+        #[automatically_derived]
+        impl<'info> ::fankor::prelude::CopyType<'info> for #flags_name {
+            type ZeroCopyType = ::fankor::prelude::bitset::ZcFlags<'info, Self>;
+
+            fn min_byte_size() -> usize {
+                #byte_size
+            }
+        }
+    };
+
+    // TypeScript generation.
+    let ts_enum_name = format!("{}Flag", name);
+    let ts_variants = variants
+        .iter()
+        .map(|(ident, bit)| format!("{} = {},", ident, bit))
+        .collect::<Vec<_>>();
+    let ts_enum = format!(
+        "export enum {} {{
+            {}
+        }}",
+        ts_enum_name,
+        ts_variants.join("\n"),
+    );
+    let ts_schema = format!("fnk.TFnkFlags({})", byte_size);
+
+    let result = quote! {
+        #result
+
+        #[cfg(feature = "ts-gen")]
+        #[automatically_derived]
+        impl ::fankor::prelude::TsTypeGen for #flags_name {
+            fn value(&self) -> std::borrow::Cow<'static, str> {
+                std::borrow::Cow::Owned(format!("new fnk.FnkFlags(new BN(\"{}\"))", self.0))
+            }
+
+            fn value_type() -> std::borrow::Cow<'static, str> {
+                std::borrow::Cow::Borrowed("fnk.FnkFlags")
+            }
+
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                std::borrow::Cow::Borrowed("fnk.FnkFlagsSchema")
+            }
+
+            fn generate_type(registered_types: &mut ::fankor::prelude::TsTypesCache) -> std::borrow::Cow<'static, str> {
+                // Registers the helper enum with the bit of every flag.
+                let name = std::borrow::Cow::Borrowed(#ts_enum_name);
+
+                if !registered_types.contains_key(&name) {
+                    registered_types.insert(name, std::borrow::Cow::Borrowed(#ts_enum));
+                }
+
+                Self::value_type()
+            }
+
+            fn generate_schema(_registered_schemas: &mut ::fankor::prelude::TsTypesCache) -> std::borrow::Cow<'static, str> {
+                std::borrow::Cow::Borrowed(#ts_schema)
+            }
+        }
+    };
+
+    Ok(result.into())
+}
@@ -1,5 +1,7 @@
 use proc_macro2::TokenStream;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{Error, Expr};
 
 use crate::fnk_syn::FnkMetaArgumentList;
 use crate::Result;
@@ -8,6 +10,11 @@ pub struct InstructionArguments {
     pub initial_validation: Option<Validation>,
     pub final_validation: Option<Validation>,
     pub phantom: bool,
+    pub top_level_only: bool,
+    pub callable_by: Option<Vec<TokenStream>>,
+    pub no_reentrancy: bool,
+    pub compact_layout: bool,
+    pub allow_when_paused: bool,
 }
 
 pub enum Validation {
@@ -42,10 +49,86 @@ impl InstructionArguments {
                 }
             },
             phantom: args.pop_plain("phantom", true)?,
+            top_level_only: args.pop_plain("top_level_only", true)?,
+            callable_by: {
+                match args.pop("callable_by", Some(true), true)? {
+                    Some(v) => match v.value.unwrap() {
+                        Expr::Array(v) => Some(
+                            v.elems
+                                .iter()
+                                .map(|v| v.to_token_stream())
+                                .collect::<Vec<_>>(),
+                        ),
+                        v => {
+                            return Err(Error::new(
+                                v.span(),
+                                "The correct pattern is #[instruction(callable_by = [<program>, ...])]",
+                            ));
+                        }
+                    },
+                    None => None,
+                }
+            },
+            no_reentrancy: args.pop_plain("no_reentrancy", true)?,
+            compact_layout: args.pop_plain("compact_layout", true)?,
+            allow_when_paused: args.pop_plain("allow_when_paused", true)?,
         };
 
         args.error_on_unknown()?;
 
+        if result.top_level_only && result.callable_by.is_some() {
+            return Err(Error::new(
+                args.list_span,
+                "The top_level_only and callable_by attributes are incompatible",
+            ));
+        }
+
         Ok(result)
     }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Builds the constant that allows the instruction to be executed while
+    /// the program is paused.
+    pub fn allow_when_paused_constant(&self) -> TokenStream {
+        if self.allow_when_paused {
+            quote! {
+                const ALLOW_WHEN_PAUSED: bool = true;
+            }
+        } else {
+            quote! {}
+        }
+    }
+
+    /// Builds the checks over the caller of the instruction.
+    pub fn caller_checks(&self) -> TokenStream {
+        let top_level_only = if self.top_level_only {
+            quote! {
+                context.check_top_level_call()?;
+            }
+        } else {
+            quote! {}
+        };
+
+        let callable_by = match &self.callable_by {
+            Some(programs) => quote! {
+                context.check_caller_program(&[#(<#programs as ::fankor::traits::ProgramType>::address()),*])?;
+            },
+            None => quote! {},
+        };
+
+        let no_reentrancy = if self.no_reentrancy {
+            quote! {
+                context.check_no_reentrancy()?;
+            }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            #top_level_only
+            #callable_by
+            #no_reentrancy
+        }
+    }
 }
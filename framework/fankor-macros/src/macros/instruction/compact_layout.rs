@@ -0,0 +1,56 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Builds the body of `try_from` for the compact layout, that reads the
+/// header before the accounts.
+pub fn try_from_body() -> TokenStream {
+    quote! {
+        let mut header = ::fankor::models::CompactLayoutReader::read(buf)?;
+        let result = <Self as ::fankor::traits::Instruction>::try_from_compact(context, &mut header, buf, accounts)?;
+        header.finish()?;
+
+        Ok(result)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Wraps the TypeScript code that writes the accounts into a compact writer
+/// that writes the header before the data.
+pub fn ts_metas(metas: String) -> String {
+    format!(
+        "const outerWriter = writer; {{ const writer = fnk.FnkCompactInstructionWriter.from(outerWriter); {} writer.flushInto(outerWriter); }}",
+        metas
+    )
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Builds the body of `serialize_into_instruction_parts` for the compact
+/// layout, that writes the header before the data.
+pub fn serialize_body(cpi: bool) -> TokenStream {
+    let call = if cpi {
+        quote! {
+            ::fankor::traits::CpiInstruction::serialize_into_instruction_parts_compact(self, &mut header, &mut data, metas, infos)?;
+        }
+    } else {
+        quote! {
+            ::fankor::traits::LpiInstruction::serialize_into_instruction_parts_compact(self, &mut header, &mut data, metas)?;
+        }
+    };
+
+    quote! {
+        let mut header = ::fankor::models::CompactLayoutWriter::new();
+        let mut data = Vec::new();
+        #call
+
+        header.write_into(writer)?;
+        writer.write_all(&data)?;
+
+        Ok(())
+    }
+}
@@ -3,7 +3,8 @@ use syn::ItemEnum;
 
 use crate::fnk_syn::FnkMetaArgumentList;
 use crate::macros::instruction::arguments::{InstructionArguments, Validation};
-use crate::macros::instruction::field::{check_fields, Field, FieldKind};
+use crate::macros::instruction::compact_layout;
+use crate::macros::instruction::field::{check_fields, check_unchecked_accounts, Field, FieldKind};
 use crate::Result;
 
 pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_macro::TokenStream> {
@@ -21,11 +22,14 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
         .map(|v| Field::from_variant(v.clone()))
         .collect::<Result<Vec<Field>>>()?;
     check_fields(&mapped_fields)?;
+    check_unchecked_accounts(&mapped_fields)?;
 
     let mut final_enum_variants = Vec::with_capacity(mapped_fields.len());
     let mut try_from_method_deserialize = Vec::with_capacity(mapped_fields.len());
+    let mut try_from_compact_method_deserialize = Vec::with_capacity(mapped_fields.len());
     let mut variant_consts = Vec::with_capacity(mapped_fields.len());
     let mut validate_method_variants = Vec::with_capacity(mapped_fields.len());
+    let mut exit_method_variants = Vec::with_capacity(mapped_fields.len());
     let mut discriminants = Vec::new();
 
     for mapped_field in &mapped_fields {
@@ -53,6 +57,23 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
                 }
             });
 
+            try_from_compact_method_deserialize.push(quote! {
+                #const_name => {
+                    let mut new_buf = &buf[1..];
+                    let mut new_accounts = *accounts;
+                    let result = <#ty as ::fankor::traits::Instruction>::try_from_compact(context, header, &mut new_buf, &mut new_accounts)?;
+
+                    *accounts = new_accounts;
+                    *buf = new_buf;
+
+                    #name::#variant_name(result)
+                }
+            });
+
+            exit_method_variants.push(quote! {
+                Self::#variant_name(v) => ::fankor::traits::Instruction::exit(v, context)
+            });
+
             validate_method_variants.push(match &mapped_field.kind {
                 // Rest is placed here because the instruction struct can be named like that.
                 FieldKind::Other | FieldKind::Rest => quote! {
@@ -84,6 +105,13 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
             try_from_method_deserialize.push(quote! {
                 #const_name => #name::#variant_name,
             });
+            try_from_compact_method_deserialize.push(quote! {
+                #const_name => #name::#variant_name,
+            });
+
+            exit_method_variants.push(quote! {
+                Self::#variant_name => Ok(())
+            });
 
             validate_method_variants.push(quote! {
                 Self::#variant_name => {}
@@ -115,10 +143,20 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
             }
         }
     });
-    let cpi_fn_elements = mapped_fields
+    let cpi_fn_elements = |compact: bool| {
+        mapped_fields
         .iter()
         .map(|v| {
             let variant_name = &v.name;
+            let call = if compact {
+                quote! {
+                    ::fankor::traits::CpiInstruction::serialize_into_instruction_parts_compact(v, header, writer, metas, infos)?
+                }
+            } else {
+                quote! {
+                    ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(v, writer, metas, infos)?
+                }
+            };
             let mut any = false;
             let (writable_let, writable_for) = if let Some(writable) = &v.writable {
                 let writable_let = quote! { let writable = #writable; };
@@ -151,7 +189,7 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
                     quote! {
                         #cpi_name::#variant_name(v) => {
                             let from = metas.len();
-                            ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(v, writer, metas, infos)?;
+                            #call;
                             let to = metas.len();
                             #writable_let
                             #signer_let
@@ -164,7 +202,7 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
                     }
                 } else {
                     quote! {
-                        #cpi_name::#variant_name(v) => ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(v, writer, metas, infos)?
+                        #cpi_name::#variant_name(v) => #call
                     }
                 }
             } else {
@@ -172,7 +210,9 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
                     #cpi_name::#variant_name => {}
                 }
             }
-        });
+        })
+        .collect::<Vec<_>>()
+    };
 
     // LpiInstruction implementation
     let lpi_name = format_ident!("Lpi{}", name);
@@ -190,8 +230,18 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
             }
         }
     });
-    let lpi_fn_elements = mapped_fields.iter().map(|v| {
+    let lpi_fn_elements = |compact: bool| {
+        mapped_fields.iter().map(|v| {
         let variant_name = &v.name;
+        let call = if compact {
+            quote! {
+                ::fankor::traits::LpiInstruction::serialize_into_instruction_parts_compact(v, header, writer, metas)?
+            }
+        } else {
+            quote! {
+                ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(v, writer, metas)?
+            }
+        };
 
         let mut any = false;
         let (writable_let, writable_for) = if let Some(writable) = &v.writable {
@@ -225,7 +275,7 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
                 quote! {
                     #lpi_name::#variant_name(v) => {
                         let from = metas.len();
-                        ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(v, writer, metas)?;
+                        #call;
                         let to = metas.len();
                         #writable_let
                         #signer_let
@@ -238,7 +288,7 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
                 }
             } else {
                 quote! {
-                    #lpi_name::#variant_name(v) => ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(v, writer, metas)?
+                    #lpi_name::#variant_name(v) => #call
                 }
             }
         } else {
@@ -246,9 +296,12 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
                 #lpi_name::#variant_name => {}
             }
         }
-    });
+    }).collect::<Vec<_>>()
+    };
 
     // Validations.
+    let caller_checks = arguments.caller_checks();
+    let allow_when_paused_constant = arguments.allow_when_paused_constant();
     let initial_validation = &arguments.initial_validation.map(|v| match v {
         Validation::Implicit => {
             quote! {
@@ -266,6 +319,71 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
         Validation::Explicit(v) => v,
     });
 
+    // Layout.
+    let try_from_body = if arguments.compact_layout {
+        compact_layout::try_from_body()
+    } else {
+        quote! {
+            if buf.is_empty() {
+                return Err(FankorErrorCode::NotEnoughDataToDeserializeInstruction.into());
+            }
+
+            #(#variant_consts)*
+            let result = match buf[0] {
+                #(#try_from_method_deserialize)*
+                _ => {
+                    return Err(FankorErrorCode::InstructionDidNotDeserialize {
+                        account: #name_str.to_string(),
+                    }
+                    .into())
+                }
+            };
+
+            // Validate instruction.
+            result.validate(context)?;
+
+            Ok(result)
+        }
+    };
+
+    let cpi_fn_elements_compact = cpi_fn_elements(true);
+    let cpi_fn_body = if arguments.compact_layout {
+        compact_layout::serialize_body(true)
+    } else {
+        let cpi_fn_elements = cpi_fn_elements(false);
+
+        quote! {
+            use ::fankor::prelude::BorshSerialize;
+
+            self.discriminant().code().serialize(writer)?;
+
+            match self {
+                #(#cpi_fn_elements),*
+            }
+
+            Ok(())
+        }
+    };
+
+    let lpi_fn_elements_compact = lpi_fn_elements(true);
+    let lpi_fn_body = if arguments.compact_layout {
+        compact_layout::serialize_body(false)
+    } else {
+        let lpi_fn_elements = lpi_fn_elements(false);
+
+        quote! {
+            use ::fankor::prelude::BorshSerialize;
+
+            self.discriminant().code().serialize(writer)?;
+
+            match self {
+                #(#lpi_fn_elements),*
+            }
+
+            Ok(())
+        }
+    };
+
     // Result
     let result = quote! {
         #[derive(EnumDiscriminants)]
@@ -281,11 +399,33 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
             type CPI = #cpi_name <'info>;
             type LPI = #lpi_name <'info>;
 
+            #allow_when_paused_constant
+
+            #[allow(unused_variables)]
+            fn exit(
+                &mut self,
+                context: &'info FankorContext<'info>,
+            ) -> ::fankor::errors::FankorResult<()> {
+                match self {
+                    #(#exit_method_variants,)*
+                }
+            }
+
             #[allow(non_upper_case_globals)]
             fn try_from(
                 context: &'info FankorContext<'info>,
                 buf: &mut &[u8],
                 accounts: &mut &'info [AccountInfo<'info>],
+            ) -> ::fankor::errors::FankorResult<Self> {
+                #try_from_body
+            }
+
+            #[allow(non_upper_case_globals)]
+            fn try_from_compact(
+                context: &'info FankorContext<'info>,
+                header: &mut ::fankor::models::CompactLayoutReader,
+                buf: &mut &[u8],
+                accounts: &mut &'info [AccountInfo<'info>],
             ) -> ::fankor::errors::FankorResult<Self> {
                 if buf.is_empty() {
                     return Err(FankorErrorCode::NotEnoughDataToDeserializeInstruction.into());
@@ -293,7 +433,7 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
 
                 #(#variant_consts)*
                 let result = match buf[0] {
-                    #(#try_from_method_deserialize)*
+                    #(#try_from_compact_method_deserialize)*
                     _ => {
                         return Err(FankorErrorCode::InstructionDidNotDeserialize {
                             account: #name_str.to_string(),
@@ -317,6 +457,8 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
             ) -> ::fankor::errors::FankorResult<()> {
                 use ::fankor::traits::Instruction;
 
+                #caller_checks
+
                 #initial_validation
 
                 match self {
@@ -350,13 +492,23 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
                 writer: &mut W,
                 metas: &mut Vec<AccountMeta>,
                 infos: &mut Vec<AccountInfo<'info>>,
+            ) -> FankorResult<()> {
+                #cpi_fn_body
+            }
+
+            fn serialize_into_instruction_parts_compact<W: std::io::Write>(
+                &self,
+                header: &mut ::fankor::models::CompactLayoutWriter,
+                writer: &mut W,
+                metas: &mut Vec<AccountMeta>,
+                infos: &mut Vec<AccountInfo<'info>>,
             ) -> FankorResult<()> {
                 use ::fankor::prelude::BorshSerialize;
 
                 self.discriminant().code().serialize(writer)?;
 
                 match self {
-                    #(#cpi_fn_elements),*
+                    #(#cpi_fn_elements_compact),*
                 }
 
                 Ok(())
@@ -383,13 +535,22 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
                 &self,
                 writer: &mut W,
                 metas: &mut Vec<::fankor::prelude::solana_program::instruction::AccountMeta>
+            ) -> ::fankor::errors::FankorResult<()> {
+                #lpi_fn_body
+            }
+
+            fn serialize_into_instruction_parts_compact<W: std::io::Write>(
+                &self,
+                header: &mut ::fankor::models::CompactLayoutWriter,
+                writer: &mut W,
+                metas: &mut Vec<::fankor::prelude::solana_program::instruction::AccountMeta>
             ) -> ::fankor::errors::FankorResult<()> {
                 use ::fankor::prelude::BorshSerialize;
 
                 self.discriminant().code().serialize(writer)?;
 
                 match self {
-                    #(#lpi_fn_elements),*
+                    #(#lpi_fn_elements_compact),*
                 }
 
                 Ok(())
@@ -441,6 +602,11 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
         "switch (_r_value_r_.type) {{ {} default: throw new Error('Invalid account type'); }}",
         metas_fields.join(""),
     );
+    let ts_metas = if arguments.compact_layout {
+        compact_layout::ts_metas(ts_metas)
+    } else {
+        ts_metas
+    };
 
     let get_metas_of_replacement_str =
         format!("getMetasOf{}(_r_value_r_,accountMetas, writer);", name_str);
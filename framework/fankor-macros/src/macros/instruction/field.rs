@@ -4,8 +4,8 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    Attribute, Error, Expr, Fields, GenericArgument, PathArguments, Token, Type, Variant,
-    Visibility,
+    Attribute, Error, Expr, ExprLit, Fields, GenericArgument, Lit, Meta, PathArguments, Token,
+    Type, Variant, Visibility,
 };
 
 use crate::Result;
@@ -28,6 +28,9 @@ pub struct Field {
     pub pda_program_id: Option<TokenStream>,
     pub constraints: Vec<DataAndError>,
     pub data: Vec<Data>,
+    pub unchecked: Option<TokenStream>,
+    /// Whether the field has a `/// CHECK:` doc comment.
+    pub check_doc: bool,
     pub attrs: Vec<Attribute>,
 }
 
@@ -70,6 +73,8 @@ impl Field {
             pda_program_id: None,
             constraints: Vec::new(),
             data: Vec::new(),
+            unchecked: None,
+            check_doc: false,
             attrs: Vec::new(),
         };
 
@@ -107,6 +112,8 @@ impl Field {
                     pda_program_id: None,
                     constraints: Vec::new(),
                     data: Vec::new(),
+                    unchecked: None,
+                    check_doc: false,
                     attrs: Vec::new(),
                 };
 
@@ -132,6 +139,8 @@ impl Field {
                     pda_program_id: None,
                     constraints: Vec::new(),
                     data: Vec::new(),
+                    unchecked: None,
+                    check_doc: false,
                     attrs: Vec::new(),
                 };
 
@@ -149,6 +158,7 @@ impl Field {
     fn parse_attributes(&mut self, mut attrs: Vec<Attribute>, is_enum: bool) -> Result<()> {
         while let Some(attribute) = attrs.pop() {
             if !attribute.path().is_ident("account") {
+                self.check_doc |= is_check_doc(&attribute);
                 self.attrs.push(attribute);
                 continue;
             }
@@ -639,6 +649,36 @@ impl Field {
                                 value: quote! {#value},
                             });
                         }
+                        "unchecked" => {
+                            if self.unchecked.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The unchecked argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The unchecked argument cannot have an error field",
+                                ));
+                            }
+
+                            // Check value.
+                            match &value {
+                                Expr::Lit(ExprLit {
+                                    lit: Lit::Str(v), ..
+                                }) if !v.value().trim().is_empty() => {}
+                                _ => {
+                                    return Err(Error::new(
+                                        name.span(),
+                                        "The unchecked argument must be a string with the reason why the account is safe: unchecked = \"<reason>\"",
+                                    ));
+                                }
+                            }
+
+                            self.unchecked = Some(quote! {#value});
+                        }
                         "validate" => {
                             return Err(Error::new(
                                 name.span(),
@@ -855,6 +895,12 @@ impl Field {
                                 "The data argument must use a value: data = <expr>",
                             ));
                         }
+                        "unchecked" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The unchecked argument must use a value: unchecked = \"<reason>\"",
+                            ));
+                        }
                         _ => {
                             return Err(Error::new(name.span(), "Unknown argument"));
                         }
@@ -947,6 +993,127 @@ pub fn check_fields(fields: &[Field]) -> Result<()> {
     Ok(())
 }
 
+/// Requires a justification, either a `/// CHECK:` doc comment or the
+/// `unchecked` argument, for the accounts that are not validated, i.e.:
+/// - unchecked accounts without owner, address, signer or PDA constraints.
+/// - writable accounts that are neither signer-gated nor constrained.
+///
+/// The accounts are also detected inside the generic containers, e.g.
+/// `Option<Either<Account<'info, T>, UncheckedAccount<'info>>>`.
+pub fn check_unchecked_accounts(fields: &[Field]) -> Result<()> {
+    for field in fields {
+        if field.unchecked.is_some() || field.check_doc {
+            continue;
+        }
+
+        let ty = match (&field.kind, &field.ty) {
+            (FieldKind::Option(v) | FieldKind::Vec(v), _) => Some(v.as_ref()),
+            (FieldKind::Other, Some(v)) => Some(v),
+            // Unit variants of the enums.
+            (FieldKind::Other, None) => continue,
+            (FieldKind::Rest, _) => None,
+        };
+        let is_unchecked = match ty {
+            Some(ty) => contains_type(ty, "UncheckedAccount") || contains_type(ty, "Rest"),
+            None => true,
+        };
+        let is_account = ty.map_or(false, |ty| {
+            contains_type(ty, "Account") || contains_type(ty, "ZcAccount")
+        });
+        let is_signer = is_enabled(&field.signer);
+        let is_pda = field.pda.is_some() || field.pda_bytes.is_some();
+
+        if is_unchecked && field.owner.is_none() && field.address.is_none() && !is_signer && !is_pda
+        {
+            return Err(unchecked_account_error(field));
+        }
+
+        if is_account
+            && is_enabled(&field.writable)
+            && !is_signer
+            && field.address.is_none()
+            && !is_pda
+            && field.constraints.is_empty()
+        {
+            return Err(Error::new(
+                field.name.span(),
+                format!(
+                    "The {} field is a writable account without signer, address, pda or custom constraints. Explain why it is safe with a `/// CHECK:` doc comment or the unchecked argument: #[account(unchecked = \"<reason>\")]",
+                    field.name
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn unchecked_account_error(field: &Field) -> Error {
+    Error::new(
+        field.name.span(),
+        format!(
+            "The {} field is an unchecked account without owner, address, signer or pda constraints. Explain why it is safe with a `/// CHECK:` doc comment or the unchecked argument: #[account(unchecked = \"<reason>\")]",
+            field.name
+        ),
+    )
+}
+
+/// Whether `ty` is the `name` type or any of the generic containers of
+/// accounts wrapping it.
+fn contains_type(ty: &Type, name: &str) -> bool {
+    let last_segment = match ty {
+        Type::Path(v) => match v.path.segments.last() {
+            Some(v) => v,
+            None => return false,
+        },
+        _ => return false,
+    };
+
+    if last_segment.ident == name {
+        return true;
+    }
+
+    let is_container = [
+        "Option",
+        "Vec",
+        "Box",
+        "Either",
+        "SingleEither",
+        "MaybeUninitialized",
+    ]
+    .iter()
+    .any(|v| last_segment.ident == v);
+
+    if !is_container {
+        return false;
+    }
+
+    match &last_segment.arguments {
+        PathArguments::AngleBracketed(v) => v.args.iter().any(|v| match v {
+            GenericArgument::Type(v) => contains_type(v, name),
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+/// Whether a flag argument is set to any value other than `false`.
+fn is_enabled(value: &Option<TokenStream>) -> bool {
+    value.as_ref().map_or(false, |v| v.to_string() != "false")
+}
+
+fn is_check_doc(attribute: &Attribute) -> bool {
+    match &attribute.meta {
+        Meta::NameValue(v) if v.path.is_ident("doc") => match &v.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(v), ..
+            }) => v.value().trim_start().starts_with("CHECK:"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -1010,3 +1177,210 @@ impl Parse for CustomMetaWithError {
         })
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use syn::{parse_quote, ItemEnum, ItemStruct};
+
+    use super::*;
+
+    fn check_struct(item: ItemStruct) -> Result<()> {
+        let fields = item
+            .fields
+            .into_iter()
+            .map(Field::from)
+            .collect::<Result<Vec<_>>>()?;
+
+        check_unchecked_accounts(&fields)
+    }
+
+    fn check_enum(item: ItemEnum) -> Result<()> {
+        let fields = item
+            .variants
+            .into_iter()
+            .map(Field::from_variant)
+            .collect::<Result<Vec<_>>>()?;
+
+        check_unchecked_accounts(&fields)
+    }
+
+    #[test]
+    fn test_unchecked_accounts() {
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                account: UncheckedAccount<'info>,
+            }
+        })
+        .expect_err("Unchecked account");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                rest: Rest<'info>,
+            }
+        })
+        .expect_err("Rest");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                /// CHECK: the account is only logged.
+                account: UncheckedAccount<'info>,
+            }
+        })
+        .expect("Check doc comment");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                #[account(unchecked = "the account is only logged")]
+                account: UncheckedAccount<'info>,
+            }
+        })
+        .expect("Unchecked argument");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                #[account(owner = &crate::ID)]
+                account: UncheckedAccount<'info>,
+            }
+        })
+        .expect("Owner constraint");
+    }
+
+    #[test]
+    fn test_unchecked_accounts_in_containers() {
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                either: Either<Account<'info, Data>, UncheckedAccount<'info>>,
+            }
+        })
+        .expect_err("Either");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                either: SingleEither<Account<'info, Data>, UncheckedAccount<'info>>,
+            }
+        })
+        .expect_err("SingleEither");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                account: Box<UncheckedAccount<'info>>,
+            }
+        })
+        .expect_err("Box");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                account: Option<MaybeUninitialized<'info, UncheckedAccount<'info>>>,
+            }
+        })
+        .expect_err("MaybeUninitialized");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                /// CHECK: the account is only logged.
+                either: Either<Account<'info, Data>, UncheckedAccount<'info>>,
+            }
+        })
+        .expect("Check doc comment");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                account: Account<'info, UncheckedAccount>,
+            }
+        })
+        .expect("Account data");
+    }
+
+    #[test]
+    fn test_writable_accounts() {
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                #[account(writable)]
+                account: Box<Account<'info, Data>>,
+            }
+        })
+        .expect_err("Writable account");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                #[account(writable)]
+                either: Either<Account<'info, Data>, Program<'info, System>>,
+            }
+        })
+        .expect_err("Writable either");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                #[account(writable)]
+                account: ZcAccount<'info, Data>,
+            }
+        })
+        .expect_err("Writable zero-copy account");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                #[account(writable)]
+                account: Box<ZcAccount<'info, Data>>,
+            }
+        })
+        .expect_err("Writable boxed zero-copy account");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                #[account(writable)]
+                #[account(pda = [b"seed"])]
+                account: Box<ZcAccount<'info, Data>>,
+            }
+        })
+        .expect("PDA zero-copy account");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                #[account(writable)]
+                #[account(signer)]
+                account: Box<Account<'info, Data>>,
+            }
+        })
+        .expect("Signer account");
+
+        check_struct(parse_quote! {
+            struct Instruction<'info> {
+                #[account(writable = false)]
+                account: Account<'info, Data>,
+            }
+        })
+        .expect("Readonly account");
+    }
+
+    #[test]
+    fn test_unchecked_accounts_in_enums() {
+        check_enum(parse_quote! {
+            enum Instruction<'info> {
+                Account(UncheckedAccount<'info>),
+            }
+        })
+        .expect_err("Unchecked account");
+
+        check_enum(parse_quote! {
+            enum Instruction<'info> {
+                Either(Either<Account<'info, Data>, UncheckedAccount<'info>>),
+            }
+        })
+        .expect_err("Either");
+
+        check_enum(parse_quote! {
+            enum Instruction<'info> {
+                /// CHECK: the account is only logged.
+                Account(UncheckedAccount<'info>),
+                #[account(unchecked = "the account is only logged")]
+                Other(UncheckedAccount<'info>),
+                Unit,
+            }
+        })
+        .expect("Justified accounts");
+    }
+}
@@ -7,6 +7,7 @@ use crate::macros::instruction::structs::process_struct;
 use crate::Result;
 
 mod arguments;
+mod compact_layout;
 mod enums;
 mod field;
 mod parser;
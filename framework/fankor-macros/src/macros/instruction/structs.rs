@@ -4,7 +4,8 @@ use syn::ItemStruct;
 
 use crate::fnk_syn::FnkMetaArgumentList;
 use crate::macros::instruction::arguments::{InstructionArguments, Validation};
-use crate::macros::instruction::field::{check_fields, Field};
+use crate::macros::instruction::compact_layout;
+use crate::macros::instruction::field::{check_fields, check_unchecked_accounts, Field};
 use crate::Result;
 
 pub fn process_struct(
@@ -23,6 +24,7 @@ pub fn process_struct(
         .map(|v| Field::from(v.clone()))
         .collect::<Result<Vec<Field>>>()?;
     check_fields(&mapped_fields)?;
+    check_unchecked_accounts(&mapped_fields)?;
 
     let final_fields = mapped_fields.iter().map(|v| {
         let name = &v.name;
@@ -56,6 +58,22 @@ pub fn process_struct(
         quote! {
             let #var_name = <#ty as ::fankor::traits::Instruction>::try_from(context, buf, accounts)?;
         }
+    }).collect::<Vec<_>>();
+    let try_from_compact_fn_deserialize = mapped_fields.iter().enumerate().map(|(i, v)| {
+        let var_name = format_ident!("__v{}", i);
+        let ty = v.ty.as_ref().unwrap();
+
+        quote! {
+            let #var_name = <#ty as ::fankor::traits::Instruction>::try_from_compact(context, header, buf, accounts)?;
+        }
+    });
+
+    let exit_method_fields = mapped_fields.iter().map(|v| {
+        let name = &v.name;
+
+        quote! {
+            ::fankor::traits::Instruction::exit(&mut self.#name, context)?;
+        }
     });
 
     let mut pda_methods = Vec::new();
@@ -287,20 +305,23 @@ pub fn process_struct(
 
         for constraint in &v.constraints {
             let condition = &constraint.data;
-            let error = match &constraint.error {
-                Some(v) => v.clone(),
-                None => {
-                    quote! {
+            let check = match &constraint.error {
+                Some(v) => quote! {
+                    ::fankor::traits::AccountConstraint::check_or(#condition, || (#v).into())?;
+                },
+                None => quote! {
+                    ::fankor::traits::AccountConstraint::check(#condition, || {
                         FankorErrorCode::AccountConstraintFailed {
                             account: #name_str,
                             constraint: stringify!(#condition),
                         }
-                    }
-                }
+                        .into()
+                    })?;
+                },
             };
 
             constraints_conditions.push(quote! {{
-                require!(#condition, #error);
+                #check
             }});
         }
 
@@ -347,14 +368,19 @@ pub fn process_struct(
         Ok(result)
     }).collect::<Result<Vec<_>>>()?;
 
-    let fields = item.fields.iter().enumerate().map(|(i, v)| {
-        let name = v.ident.as_ref().unwrap();
-        let var_name = format_ident!("__v{}", i);
+    let fields = item
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let name = v.ident.as_ref().unwrap();
+            let var_name = format_ident!("__v{}", i);
 
-        quote! {
-            #name: #var_name
-        }
-    });
+            quote! {
+                #name: #var_name
+            }
+        })
+        .collect::<Vec<_>>();
 
     // CpiInstruction implementation
     let cpi_name = format_ident!("Cpi{}", name);
@@ -366,8 +392,18 @@ pub fn process_struct(
             pub #name:<#ty as ::fankor::traits::Instruction<'info>>::CPI
         }
     });
-    let cpi_fn_elements = mapped_fields.iter().map(|v| {
+    let cpi_fn_elements = |compact: bool| {
+        mapped_fields.iter().map(|v| {
         let name = &v.name;
+        let call = if compact {
+            quote! {
+                ::fankor::traits::CpiInstruction::serialize_into_instruction_parts_compact(&self.#name, header, writer, metas, infos)?;
+            }
+        } else {
+            quote! {
+                ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas, infos)?;
+            }
+        };
 
         let mut any = false;
         let (writable_let, writable_for) = if let Some(writable) = &v.writable {
@@ -400,7 +436,7 @@ pub fn process_struct(
             quote! {
                 {
                     let from = metas.len();
-                    ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas, infos)?;
+                    #call
                     let to = metas.len();
                     #writable_let
                     #signer_let
@@ -412,11 +448,10 @@ pub fn process_struct(
                 }
             }
         } else {
-            quote! {
-               ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas, infos)?;
-            }
+            call
         }
-    });
+    }).collect::<Vec<_>>()
+    };
 
     // LpiInstruction implementation
     let lpi_name = format_ident!("Lpi{}", name);
@@ -428,8 +463,18 @@ pub fn process_struct(
             pub #name:<#ty as ::fankor::traits::Instruction<'info>>::LPI
         }
     });
-    let lpi_fn_elements = mapped_fields.iter().map(|v| {
+    let lpi_fn_elements = |compact: bool| {
+        mapped_fields.iter().map(|v| {
         let name = &v.name;
+        let call = if compact {
+            quote! {
+                ::fankor::traits::LpiInstruction::serialize_into_instruction_parts_compact(&self.#name, header, writer, metas)?;
+            }
+        } else {
+            quote! {
+                ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas)?;
+            }
+        };
 
         let mut any = false;
         let (writable_let, writable_for) = if let Some(writable) = &v.writable {
@@ -462,7 +507,7 @@ pub fn process_struct(
             quote! {
                 {
                     let from = metas.len();
-                    ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas)?;
+                    #call
                     let to = metas.len();
                     #writable_let
                     #signer_let
@@ -474,13 +519,14 @@ pub fn process_struct(
                 }
             }
         } else {
-            quote! {
-                ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas)?;
-            }
+            call
         }
-    });
+    }).collect::<Vec<_>>()
+    };
 
     // Validations.
+    let caller_checks = arguments.caller_checks();
+    let allow_when_paused_constant = arguments.allow_when_paused_constant();
     let initial_validation = &arguments.initial_validation.map(|v| match v {
         Validation::Implicit => {
             quote! {
@@ -499,6 +545,51 @@ pub fn process_struct(
         Validation::Explicit(v) => v,
     });
 
+    // Layout.
+    let try_from_body = if arguments.compact_layout {
+        compact_layout::try_from_body()
+    } else {
+        quote! {
+            #(#try_from_fn_deserialize)*
+
+            let result = Self {
+                #(#fields,)*
+                #phantom_field_builder
+            };
+
+            // Validate instruction.
+            result.validate(context)?;
+
+            Ok(result)
+        }
+    };
+
+    let cpi_fn_elements_compact = cpi_fn_elements(true);
+    let cpi_fn_body = if arguments.compact_layout {
+        compact_layout::serialize_body(true)
+    } else {
+        let cpi_fn_elements = cpi_fn_elements(false);
+
+        quote! {
+            use ::fankor::prelude::BorshSerialize;
+            #(#cpi_fn_elements)*
+            Ok(())
+        }
+    };
+
+    let lpi_fn_elements_compact = lpi_fn_elements(true);
+    let lpi_fn_body = if arguments.compact_layout {
+        compact_layout::serialize_body(false)
+    } else {
+        let lpi_fn_elements = lpi_fn_elements(false);
+
+        quote! {
+            use ::fankor::prelude::BorshSerialize;
+            #(#lpi_fn_elements)*
+            Ok(())
+        }
+    };
+
     // Result
     let phantom_lifetime = if arguments.phantom && mapped_fields.is_empty() {
         quote! {}
@@ -518,12 +609,32 @@ pub fn process_struct(
             type CPI = #cpi_name #phantom_lifetime;
             type LPI = #lpi_name #phantom_lifetime;
 
+            #allow_when_paused_constant
+
+            #[allow(unused_variables)]
+            fn exit(
+                &mut self,
+                context: &'info FankorContext<'info>,
+            ) -> ::fankor::errors::FankorResult<()> {
+                #(#exit_method_fields)*
+                Ok(())
+            }
+
             fn try_from(
                 context: &'info FankorContext<'info>,
                 buf: &mut &[u8],
                 accounts: &mut &'info [AccountInfo<'info>],
             ) -> ::fankor::errors::FankorResult<Self> {
-                #(#try_from_fn_deserialize)*
+                #try_from_body
+            }
+
+            fn try_from_compact(
+                context: &'info FankorContext<'info>,
+                header: &mut ::fankor::models::CompactLayoutReader,
+                buf: &mut &[u8],
+                accounts: &mut &'info [AccountInfo<'info>],
+            ) -> ::fankor::errors::FankorResult<Self> {
+                #(#try_from_compact_fn_deserialize)*
 
                 let result = Self {
                     #(#fields,)*
@@ -545,6 +656,8 @@ pub fn process_struct(
             ) -> ::fankor::errors::FankorResult<()> {
                 use ::fankor::traits::Instruction;
 
+                #caller_checks
+
                 #initial_validation
 
                 #(#validate_method_fields)*
@@ -570,8 +683,17 @@ pub fn process_struct(
                 metas: &mut Vec<AccountMeta>,
                 infos: &mut Vec<AccountInfo<'info>>,
             ) -> FankorResult<()> {
-                use ::fankor::prelude::BorshSerialize;
-                #(#cpi_fn_elements)*
+                #cpi_fn_body
+            }
+
+            fn serialize_into_instruction_parts_compact<W: std::io::Write>(
+                &self,
+                header: &mut ::fankor::models::CompactLayoutWriter,
+                writer: &mut W,
+                metas: &mut Vec<AccountMeta>,
+                infos: &mut Vec<AccountInfo<'info>>,
+            ) -> FankorResult<()> {
+                #(#cpi_fn_elements_compact)*
                 Ok(())
             }
         }
@@ -588,8 +710,16 @@ pub fn process_struct(
                 writer: &mut W,
                 metas: &mut Vec<::fankor::prelude::solana_program::instruction::AccountMeta>
             ) -> ::fankor::errors::FankorResult<()> {
-                use ::fankor::prelude::BorshSerialize;
-                #(#lpi_fn_elements)*
+                #lpi_fn_body
+            }
+
+            fn serialize_into_instruction_parts_compact<W: std::io::Write>(
+                &self,
+                header: &mut ::fankor::models::CompactLayoutWriter,
+                writer: &mut W,
+                metas: &mut Vec<::fankor::prelude::solana_program::instruction::AccountMeta>
+            ) -> ::fankor::errors::FankorResult<()> {
+                #(#lpi_fn_elements_compact)*
                 Ok(())
             }
         }
@@ -628,7 +758,11 @@ pub fn process_struct(
         ts_types.join(",")
     );
 
-    let ts_metas = metas_fields.join("");
+    let ts_metas = if arguments.compact_layout {
+        compact_layout::ts_metas(metas_fields.join(""))
+    } else {
+        metas_fields.join("")
+    };
     let get_metas_of_replacement_str =
         format!("getMetasOf{}(_r_value_r_,accountMetas, writer);", name_str);
     let test_name = format_ident!("__ts_gen_test__instruction_accounts_{}", name_str);
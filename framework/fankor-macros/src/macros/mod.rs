@@ -7,6 +7,7 @@ pub mod deserialize;
 pub mod enum_discriminants;
 pub mod error;
 pub mod field_offset;
+pub mod flags;
 pub mod instruction;
 pub mod program;
 pub mod serialize;
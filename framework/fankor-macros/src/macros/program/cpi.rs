@@ -5,7 +5,7 @@ use crate::macros::program::programs::Program;
 use crate::Result;
 
 pub fn build_cpi(program: &Program) -> Result<TokenStream> {
-    let methods = program.methods.iter().map(|v| {
+    let methods = program.methods.iter().filter(|v| !v.deprecated).map(|v| {
         let program_name = &program.name;
         let method_name = &v.snake_name;
         let type_name = &v.name;
@@ -21,12 +21,28 @@ pub fn build_cpi(program: &Program) -> Result<TokenStream> {
             (quote! { Ok(()) }, quote! { () })
         };
 
+        // The pausable programs expect their config account at the end,
+        // except for the instructions allowed while paused, that ignore it.
+        let (pause_config_param, pause_config) = if program.pausable.is_some() {
+            (quote! {
+                pause_config: &::fankor::prelude::AccountInfo<'info>,
+            }, quote! {
+                if !<#type_name<'info> as ::fankor::traits::Instruction<'info>>::ALLOW_WHEN_PAUSED {
+                    metas.push(::fankor::prelude::AccountMeta::new_readonly(*pause_config.key, false));
+                    infos.push(pause_config.clone());
+                }
+            })
+        } else {
+            (quote! {}, quote! {})
+        };
+
         quote! {
-            pub fn #method_name<'info>(_program: &::fankor::models::Program<super::#program_name>, accounts: <#type_name<'info> as ::fankor::traits::Instruction<'info>>::CPI, signer_seeds: &[&[&[u8]]]) -> ::fankor::errors::FankorResult<#result_param> {
+            pub fn #method_name<'info>(program: &::fankor::models::Program<super::#program_name>, accounts: <#type_name<'info> as ::fankor::traits::Instruction<'info>>::CPI, #pause_config_param signer_seeds: &[&[&[u8]]]) -> ::fankor::errors::FankorResult<#result_param> {
                 let mut data = vec![#discriminant_name::#type_name.code()];
                 let mut metas = Vec::new();
                 let mut infos = Vec::new();
                 ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(&accounts, &mut data, &mut metas, &mut infos)?;
+                #pause_config
 
                 let instruction = ::fankor::prelude::solana_program::instruction::Instruction {
                     program_id: *<super::#program_name as ::fankor::traits::ProgramType>::address(),
@@ -34,8 +50,7 @@ pub fn build_cpi(program: &Program) -> Result<TokenStream> {
                     data
                 };
 
-                ::fankor::prelude::solana_program::program::invoke_signed(&instruction, &infos, signer_seeds)
-                    .map_or_else(|e| Err(::fankor::errors::Error::ProgramError(e)), |_| Ok(()))?;
+                program.invoke_signed(&instruction, &infos, signer_seeds)?;
 
                 #result
             }
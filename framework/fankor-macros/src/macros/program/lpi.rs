@@ -5,17 +5,32 @@ use crate::macros::program::programs::Program;
 use crate::Result;
 
 pub fn build_lpi(program: &Program) -> Result<TokenStream> {
-    let methods = program.methods.iter().map(|v| {
+    let methods = program.methods.iter().filter(|v| !v.deprecated).map(|v| {
         let program_name = &program.name;
         let method_name = &v.snake_name;
         let type_name = &v.name;
         let discriminant_name = format_ident!("{}Discriminant", program_name);
 
+        // The pausable programs expect their config account at the end,
+        // except for the instructions allowed while paused.
+        let pause_config = match &program.pausable {
+            Some(config) => quote! {
+                if !<#type_name<'info> as ::fankor::traits::Instruction<'info>>::ALLOW_WHEN_PAUSED {
+                    metas.push(::fankor::prelude::AccountMeta::new_readonly(
+                        <#config as ::fankor::traits::PausableConfig>::pda_address(&crate::ID),
+                        false,
+                    ));
+                }
+            },
+            None => quote! {},
+        };
+
         quote! {
             pub fn #method_name<'info>(accounts: <#type_name<'info> as ::fankor::traits::Instruction<'info>>::LPI) -> ::fankor::errors::FankorResult<::fankor::prelude::solana_program::instruction::Instruction> {
                 let mut data = vec![#discriminant_name::#type_name.code()];
                 let mut metas = Vec::new();
                 ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(&accounts, &mut data, &mut metas)?;
+                #pause_config
 
                 Ok(::fankor::prelude::solana_program::instruction::Instruction {
                     program_id: crate::ID,
@@ -26,6 +41,44 @@ pub fn build_lpi(program: &Program) -> Result<TokenStream> {
         }
     });
 
+    let batch_method = if program.batch {
+        let discriminant_name = format_ident!("{}Discriminant", program.name);
+
+        quote! {
+            /// Packs the `instructions` of this program into a single batch
+            /// instruction that executes them in order.
+            pub fn batch(instructions: &[::fankor::prelude::solana_program::instruction::Instruction]) -> ::fankor::errors::FankorResult<::fankor::prelude::solana_program::instruction::Instruction> {
+                let mut writer = ::fankor::models::BatchWriter::new();
+                let mut metas = Vec::new();
+
+                for instruction in instructions {
+                    if instruction.program_id != crate::ID {
+                        return Err(::fankor::errors::FankorErrorCode::DeclaredProgramIdMismatch.into());
+                    }
+
+                    let (discriminant, data) = instruction
+                        .data
+                        .split_first()
+                        .ok_or(::fankor::errors::FankorErrorCode::MissingInstructionDiscriminant)?;
+
+                    writer.write_entry(*discriminant, data, instruction.accounts.len())?;
+                    metas.extend_from_slice(&instruction.accounts);
+                }
+
+                let mut data = vec![#discriminant_name::Batch.code()];
+                writer.write_into(&mut data)?;
+
+                Ok(::fankor::prelude::solana_program::instruction::Instruction {
+                    program_id: crate::ID,
+                    accounts: metas,
+                    data
+                })
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     Ok(quote! {
         pub mod lpi {
             //! Methods for creating this program's instructions off-chain.
@@ -36,6 +89,8 @@ pub fn build_lpi(program: &Program) -> Result<TokenStream> {
             use std::io::Cursor;
 
             #(#methods)*
+
+            #batch_method
         }
     })
 }
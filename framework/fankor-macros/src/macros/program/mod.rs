@@ -1,3 +1,4 @@
+use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::spanned::Spanned;
 use syn::{Error, Item};
@@ -45,20 +46,48 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
                 #variant_name
             }
         })
+        // The batch instruction uses a reserved discriminant so adding new
+        // instructions does not change it.
+        .chain(program.batch.then(|| {
+            quote! {
+                #[discriminant = 255]
+                Batch
+            }
+        }))
         .collect::<Vec<_>>();
 
     let mut discriminant_constants = Vec::new();
     let dispatch_methods = program.methods.iter().map(|v| {
         let variant_name = &v.name;
         let instruction_msg = format!("Instruction: {}", v.name);
+        let pause_check = match &program.pausable {
+            Some(config) => quote! {
+                if !<#variant_name<'info> as ::fankor::traits::Instruction<'info>>::ALLOW_WHEN_PAUSED {
+                    ix_accounts = context.check_not_paused::<#config>(ix_accounts)?;
+                }
+            },
+            None => quote! {},
+        };
 
         discriminant_constants.push(quote! {
             const #variant_name: u8 = #discriminant_name::#variant_name.code();
         });
 
+        // The deprecated instructions keep their discriminant but do not
+        // reference their accounts type, so it can be removed.
+        if v.deprecated {
+            let variant_name_str = variant_name.to_string();
+
+            return quote! {
+                #variant_name => Err(::fankor::errors::FankorErrorCode::InstructionDeprecated {
+                    name: #variant_name_str,
+                }.into())
+            };
+        }
+
         let accounts = if v.boxed {
             quote! {
-                let accounts = parse_accounts::<#variant_name<'info>>(
+                let mut accounts = parse_accounts::<#variant_name<'info>>(
                     context,
                     &mut ix_data,
                     &mut ix_accounts,
@@ -66,13 +95,16 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
             }
         } else {
             quote! {
-                let accounts = <#variant_name<'info> as fankor::traits::Instruction>::try_from(&context, &mut ix_data, &mut ix_accounts)?;
+                let mut accounts = <#variant_name<'info> as fankor::traits::Instruction>::try_from(&context, &mut ix_data, &mut ix_accounts)?;
             }
         };
 
         quote! {
             #variant_name => {
-                ::fankor::prelude::msg!(#instruction_msg);
+                if !::fankor::MINIMAL_LOGS {
+                    ::fankor::prelude::msg!(#instruction_msg);
+                }
+                #pause_check
                 #accounts
 
                 if ix_accounts.len() != 0 {
@@ -81,6 +113,9 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
 
                 let result = accounts.processor(context.clone())?;
 
+                // Exit phase.
+                ::fankor::traits::Instruction::exit(&mut accounts, context)?;
+
                 // Write return data.
                 if type_id_of(&result) != type_id_of(&()) {
                     ::fankor::prelude::solana_program::program::set_return_data(&::fankor::prelude::BorshSerialize::try_to_vec(&result).unwrap());
@@ -95,8 +130,10 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         quote! {
             #[cfg(any(feature = "testable-program"))]
             0 => {
-                ::fankor::prelude::msg!("Testable Instruction");
-                let accounts = parse_accounts::<::fankor::prelude::TestInstruction<'info>>(
+                if !::fankor::MINIMAL_LOGS {
+                    ::fankor::prelude::msg!("Testable Instruction");
+                }
+                let mut accounts = parse_accounts::<::fankor::prelude::TestInstruction<'info>>(
                     context,
                     &mut ix_data,
                     &mut ix_accounts,
@@ -108,6 +145,57 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
 
                 accounts.processor(context.clone())?;
 
+                // Exit phase.
+                ::fankor::traits::Instruction::exit(&mut accounts, context)?;
+
+                Ok(())
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // The batch instruction dispatches every instruction over its own slice
+    // of the accounts sharing the same context.
+    let batch_dispatch_method = if program.batch {
+        discriminant_constants.push(quote! {
+            const Batch: u8 = #discriminant_name::Batch.code();
+        });
+
+        quote! {
+            Batch => {
+                if !::fankor::MINIMAL_LOGS {
+                    ::fankor::prelude::msg!("Instruction: Batch");
+                }
+                let mut reader = ::fankor::models::BatchReader::read(ix_data)?;
+                let mut batch_accounts = ix_accounts;
+
+                while let Some(entry) = reader.next_entry()? {
+                    if batch_accounts.len() < entry.account_count {
+                        return Err(::fankor::errors::FankorErrorCode::NotEnoughAccountKeys.into());
+                    }
+
+                    let (entry_accounts, rest) = batch_accounts.split_at(entry.account_count);
+                    batch_accounts = rest;
+
+                    let result: ::fankor::errors::FankorResult<()> = {
+                        let mut ix_data = entry.data;
+                        let mut ix_accounts = entry_accounts;
+
+                        match entry.discriminant {
+                            #(#dispatch_methods,)*
+                            _ => Err(::fankor::errors::FankorErrorCode::InstructionDiscriminantNotFound.into())
+                        }
+                    };
+
+                    result?;
+                    context.finish_batch_entry()?;
+                }
+
+                if batch_accounts.len() != 0 {
+                    return Err(::fankor::errors::FankorErrorCode::UnusedAccounts.into());
+                }
+
                 Ok(())
             }
         }
@@ -118,7 +206,9 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
     let dispatch_default = if let Some(fallback_method_call) = &program.fallback_method_call {
         quote! {
             _ => {
-                ::fankor::prelude::msg!("Instruction: Fallback");
+                if !::fankor::MINIMAL_LOGS {
+                    ::fankor::prelude::msg!("Instruction: Fallback");
+                }
                 #fallback_method_call
             }
         }
@@ -128,6 +218,41 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         }
     };
 
+    // Hooks around the dispatch of every instruction, including the fallback.
+    let before_hook = match &program.before_hook {
+        Some(before) => quote! {
+            #before(context, sighash, ix_data)?;
+        },
+        None => quote! {},
+    };
+
+    let dispatch = quote! {
+        {
+            let mut ix_data = ix_data;
+            let mut ix_accounts = accounts;
+            match sighash {
+                #testable_dispatch_method
+                #(#dispatch_methods,)*
+                #batch_dispatch_method
+                #dispatch_default
+            }
+        }
+    };
+
+    let dispatch = match &program.after_hook {
+        Some(after) => quote! {
+            let result: ::fankor::errors::FankorResult<()> = #dispatch;
+            result?;
+            #after(context, sighash, ix_data)
+        },
+        None => dispatch,
+    };
+
+    let pausable_instructions = match &program.pausable {
+        Some(config) => build_pausable_instructions(config),
+        None => quote! {},
+    };
+
     let cpi_mod = build_cpi(&program)?;
     let lpi_mod = build_lpi(&program)?;
 
@@ -162,6 +287,9 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
                                     let accounts = unsafe {
                                         std::mem::transmute::<&[::fankor::prelude::AccountInfo], &'info [::fankor::prelude::AccountInfo<'info>]>(accounts)
                                     };
+                                    let data = unsafe {
+                                        std::mem::transmute::<&[u8], &'info [u8]>(data)
+                                    };
 
                                     #program_entry_name(program_id, accounts, data)
                                 },
@@ -194,12 +322,16 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         fn #program_entry_name<'info>(
             program_id: &'info ::fankor::prelude::Pubkey,
             accounts: &'info [::fankor::prelude::AccountInfo<'info>],
-            data: &[u8],
+            data: &'info [u8],
         ) -> ::fankor::prelude::solana_program::entrypoint::ProgramResult {
-            #program_try_entry_name(program_id, accounts, data).map_err(|e| {
+            let result = #program_try_entry_name(program_id, accounts, data).map_err(|e| {
                 e.log();
                 e.into()
-            })
+            });
+
+            crate::__fankor_internal__log_heap_usage();
+
+            result
         }
 
         #[allow(non_snake_case)]
@@ -208,7 +340,7 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         fn #program_try_entry_name<'info>(
             program_id: &'info ::fankor::prelude::Pubkey,
             accounts: &'info [::fankor::prelude::AccountInfo<'info>],
-            data: &[u8],
+            data: &'info [u8],
         ) -> ::fankor::errors::FankorResult<()> {
             if *program_id != crate::ID {
                 return Err(::fankor::errors::FankorErrorCode::DeclaredProgramIdMismatch.into());
@@ -223,9 +355,10 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
 
             // Build context.
             let context = unsafe {
-                ::fankor::models::FankorContext::<'info>::new_unchecked(
+                ::fankor::models::FankorContext::<'info>::new_with_data_unchecked(
                     program_id,
-                    accounts
+                    accounts,
+                    data
                 )
             };
 
@@ -237,13 +370,14 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
 
             #(#discriminant_constants)*
 
-            let mut ix_data = ix_data;
-            let mut ix_accounts = accounts;
-            match sighash {
-                #testable_dispatch_method
-                #(#dispatch_methods,)*
-                #dispatch_default
-            }
+            (|| -> ::fankor::errors::FankorResult<()> {
+                #before_hook
+                #dispatch
+            })()?;
+
+            // Finish the exit phase only if the instruction succeeds, otherwise
+            // the runtime discards its changes anyway.
+            context.exit()
         }
 
         #[allow(dead_code)]
@@ -257,6 +391,8 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
             Ok(Box::new(T::try_from(context, ix_data, ix_accounts)?))
         }
 
+        #pausable_instructions
+
         #[cfg(not(feature = "library"))]
         #cpi_mod
 
@@ -268,17 +404,38 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
     let method_registration = program
         .methods
         .iter()
+        .filter(|v| !v.deprecated)
         .map(|v| {
             let name = &v.name;
             let name_str = name.to_string();
             let discriminant_name_str = discriminant_name.to_string();
 
-            quote! {
-                action_context.add_program_method::<#name<'info>>(#discriminant_name_str, #name_str).unwrap();
+            if program.pausable.is_some() {
+                quote! {
+                    action_context.add_pausable_program_method::<#name<'info>>(
+                        #discriminant_name_str,
+                        #name_str,
+                        !<#name<'info> as ::fankor::traits::Instruction<'info>>::ALLOW_WHEN_PAUSED,
+                    ).unwrap();
+                }
+            } else {
+                quote! {
+                    action_context.add_program_method::<#name<'info>>(#discriminant_name_str, #name_str).unwrap();
+                }
             }
         })
         .collect::<Vec<_>>();
 
+    let batch_registration = if program.batch {
+        let discriminant_name_str = discriminant_name.to_string();
+
+        quote! {
+            action_context.add_batch_program_method(#discriminant_name_str).unwrap();
+        }
+    } else {
+        quote! {}
+    };
+
     let test_name = format_ident!("__ts_gen_test__program_{}", name_str);
     let test_name_str = test_name.to_string();
     let result = quote! {
@@ -300,6 +457,7 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
                     action_context.set_context_name(#name_str).unwrap();
                     action_context.add_constant("PROGRAM_NAME", #name_str).unwrap();
                     #(#method_registration)*
+                    #batch_registration
                 })
             }
         }
@@ -307,3 +465,83 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
 
     Ok(result.into())
 }
+
+/// Builds the instructions to manage the pause config of a pausable program.
+fn build_pausable_instructions(config: &TokenStream) -> TokenStream {
+    quote! {
+        /// Pauses the program. It can only be called by the admin.
+        #[::fankor::prelude::instruction(allow_when_paused)]
+        pub struct Pause<'info> {
+            #[account(signer)]
+            pub admin: ::fankor::models::UncheckedAccount<'info>,
+
+            #[account(writable)]
+            #[account(pda_bytes = ::fankor::traits::PAUSE_CONFIG_SEED.to_vec())]
+            pub config: ::fankor::models::Account<'info, #config>,
+        }
+
+        #[automatically_derived]
+        impl<'info> Pause<'info> {
+            pub fn processor(
+                &mut self,
+                _context: ::fankor::models::FankorContext<'info>,
+            ) -> ::fankor::errors::FankorResult<()> {
+                ::fankor::traits::PausableConfig::check_admin(self.config.data(), self.admin.address())?;
+                ::fankor::traits::PausableConfig::set_paused(self.config.data_mut(), true);
+
+                Ok(())
+            }
+        }
+
+        /// Unpauses the program. It can only be called by the admin.
+        #[::fankor::prelude::instruction(allow_when_paused)]
+        pub struct Unpause<'info> {
+            #[account(signer)]
+            pub admin: ::fankor::models::UncheckedAccount<'info>,
+
+            #[account(writable)]
+            #[account(pda_bytes = ::fankor::traits::PAUSE_CONFIG_SEED.to_vec())]
+            pub config: ::fankor::models::Account<'info, #config>,
+        }
+
+        #[automatically_derived]
+        impl<'info> Unpause<'info> {
+            pub fn processor(
+                &mut self,
+                _context: ::fankor::models::FankorContext<'info>,
+            ) -> ::fankor::errors::FankorResult<()> {
+                ::fankor::traits::PausableConfig::check_admin(self.config.data(), self.admin.address())?;
+                ::fankor::traits::PausableConfig::set_paused(self.config.data_mut(), false);
+
+                Ok(())
+            }
+        }
+
+        /// Replaces the admin of the program. It can only be called by the
+        /// current admin.
+        #[::fankor::prelude::instruction(allow_when_paused)]
+        pub struct TransferAdmin<'info> {
+            pub new_admin: ::fankor::models::Argument<::fankor::prelude::Pubkey>,
+
+            #[account(signer)]
+            pub admin: ::fankor::models::UncheckedAccount<'info>,
+
+            #[account(writable)]
+            #[account(pda_bytes = ::fankor::traits::PAUSE_CONFIG_SEED.to_vec())]
+            pub config: ::fankor::models::Account<'info, #config>,
+        }
+
+        #[automatically_derived]
+        impl<'info> TransferAdmin<'info> {
+            pub fn processor(
+                &mut self,
+                _context: ::fankor::models::FankorContext<'info>,
+            ) -> ::fankor::errors::FankorResult<()> {
+                ::fankor::traits::PausableConfig::check_admin(self.config.data(), self.admin.address())?;
+                ::fankor::traits::PausableConfig::set_admin(self.config.data_mut(), *self.new_admin);
+
+                Ok(())
+            }
+        }
+    }
+}
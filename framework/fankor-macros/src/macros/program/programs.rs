@@ -5,6 +5,7 @@ use syn::spanned::Spanned;
 use syn::{Attribute, Error, Expr, ItemEnum, Meta};
 
 use crate::fnk_syn::FnkMetaArgumentList;
+use crate::macros::enum_discriminants::is_deprecated;
 use crate::Result;
 
 pub struct Program {
@@ -13,6 +14,10 @@ pub struct Program {
     pub methods: Vec<ProgramMethod>,
     pub fallback_method_call: Option<TokenStream>,
     pub testable: bool,
+    pub pausable: Option<TokenStream>,
+    pub batch: bool,
+    pub before_hook: Option<TokenStream>,
+    pub after_hook: Option<TokenStream>,
 
     /// List of attributes to apply to the enum.
     pub attrs: Vec<Attribute>,
@@ -23,6 +28,9 @@ pub struct ProgramMethod {
     pub snake_name: Ident,
     pub return_type: Option<TokenStream>,
     pub boxed: bool,
+    /// Whether the instruction is deprecated. Its discriminant stays reserved
+    /// but it is no longer dispatched nor exposed to the clients.
+    pub deprecated: bool,
     pub attrs: Vec<Attribute>,
 }
 
@@ -40,6 +48,20 @@ impl Program {
 
         let testable = args.pop_plain("testable", true)?;
 
+        let pausable = args
+            .pop("pausable", Some(true), true)?
+            .map(|v| v.value.unwrap().to_token_stream());
+
+        let batch = args.pop_plain("batch", true)?;
+
+        let before_hook = args
+            .pop("before", Some(true), true)?
+            .map(|v| v.value.unwrap().to_token_stream());
+
+        let after_hook = args
+            .pop("after", Some(true), true)?
+            .map(|v| v.value.unwrap().to_token_stream());
+
         args.error_on_unknown()?;
 
         if !item.generics.params.is_empty() || item.generics.where_clause.is_some() {
@@ -68,11 +90,28 @@ impl Program {
             methods: vec![],
             fallback_method_call,
             testable,
+            pausable,
+            batch,
+            before_hook,
+            after_hook,
             attrs: Vec::new(),
         };
 
         program.parse_methods(item)?;
 
+        if program.pausable.is_some() {
+            program.add_pausable_methods()?;
+        }
+
+        if program.batch {
+            if let Some(method) = program.methods.iter().find(|v| v.name == "Batch") {
+                return Err(Error::new(
+                    method.name.span(),
+                    "The Batch instruction is already defined by the batch programs",
+                ));
+            }
+        }
+
         Ok(program)
     }
 
@@ -126,6 +165,15 @@ impl Program {
                 }
             }
 
+            let deprecated = is_deprecated(&attrs);
+
+            if deprecated && (return_type.is_some() || boxed) {
+                return Err(Error::new(
+                    method_name.span(),
+                    "Deprecated instructions cannot have the return_type or boxed attributes",
+                ));
+            }
+
             self.methods.push(ProgramMethod {
                 snake_name: format_ident!(
                     "{}",
@@ -135,10 +183,41 @@ impl Program {
                 name: method_name,
                 return_type,
                 boxed,
+                deprecated,
                 attrs,
             });
         }
 
         Ok(())
     }
+
+    /// Adds the instructions generated for the pausable programs.
+    fn add_pausable_methods(&mut self) -> Result<()> {
+        for (name, snake_name) in [
+            ("Pause", "pause"),
+            ("Unpause", "unpause"),
+            ("TransferAdmin", "transfer_admin"),
+        ] {
+            if let Some(method) = self.methods.iter().find(|v| v.name == name) {
+                return Err(Error::new(
+                    method.name.span(),
+                    format!(
+                        "The {} instruction is already defined by the pausable programs",
+                        name
+                    ),
+                ));
+            }
+
+            self.methods.push(ProgramMethod {
+                name: format_ident!("{}", name),
+                snake_name: format_ident!("{}", snake_name),
+                return_type: None,
+                boxed: false,
+                deprecated: false,
+                attrs: Vec::new(),
+            });
+        }
+
+        Ok(())
+    }
 }
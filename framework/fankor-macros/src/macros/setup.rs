@@ -1,14 +1,109 @@
 use quote::quote;
-use syn::LitStr;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Token};
 
+use crate::fnk_syn::FnkMetaArgumentList;
 use crate::Result;
 
-pub fn processor(pubkey: LitStr) -> Result<proc_macro::TokenStream> {
+pub struct SetupArguments {
+    pub pubkey: LitStr,
+    pub args: FnkMetaArgumentList,
+}
+
+impl Parse for SetupArguments {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pubkey = input.parse::<LitStr>()?;
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        let args = input.parse::<FnkMetaArgumentList>()?;
+
+        Ok(Self { pubkey, args })
+    }
+}
+
+pub fn processor(setup: SetupArguments) -> Result<proc_macro::TokenStream> {
+    let SetupArguments { pubkey, mut args } = setup;
+
+    args.error_on_duplicated()?;
+
+    let heap_size = args.pop_number::<usize>("heap_size", true)?;
+    let log_heap_usage = args.pop_plain("log_heap_usage", true)?;
+
+    args.error_on_unknown()?;
+
+    // The allocator is only replaced if the program disables the default one
+    // of the entrypoint with the custom-heap feature.
+    let allocator = match heap_size {
+        Some(heap_size) => quote! {
+            const _: () = assert!(
+                #heap_size >= ::fankor::prelude::solana_program::entrypoint::HEAP_LENGTH
+                    && #heap_size <= ::fankor::prelude::MAX_HEAP_LENGTH
+                    && #heap_size % 1024 == 0,
+                "The heap size must be a multiple of 1024 between 32KB and 256KB"
+            );
+
+            #[cfg(all(target_os = "solana", not(feature = "no-entrypoint"), feature = "custom-heap"))]
+            #[global_allocator]
+            static __FANKOR_ALLOCATOR: ::fankor::prelude::solana_program::entrypoint::BumpAllocator =
+                ::fankor::prelude::bump_heap_allocator(#heap_size);
+        },
+        None => quote! {},
+    };
+
+    let heap_length = match heap_size {
+        Some(heap_size) => quote! {
+            if cfg!(feature = "custom-heap") {
+                #heap_size
+            } else {
+                ::fankor::prelude::solana_program::entrypoint::HEAP_LENGTH
+            }
+        },
+        None => quote! { ::fankor::prelude::solana_program::entrypoint::HEAP_LENGTH },
+    };
+    let heap_usage_log = if log_heap_usage {
+        quote! {
+            #[cfg(target_os = "solana")]
+            ::fankor::prelude::msg!(
+                "Heap usage: {} of {} bytes",
+                ::fankor::prelude::heap_usage(#heap_length),
+                #heap_length
+            );
+        }
+    } else {
+        quote! {}
+    };
+
+    let ts_heap_size = match heap_size {
+        Some(heap_size) => quote! {
+            /// Registers the heap size of the program.
+            #[test]
+            fn heap_size() {
+                BUILD_CONTEXT.register_action("__ts_gen_test__setup::heap_size", file!(), move |action_context| {
+                    action_context.set_heap_size(#heap_size).unwrap();
+                })
+            }
+        },
+        None => quote! {},
+    };
+
     let result = quote! {
         /// The static program ID.
         #[::fankor::prelude::constant]
         pub const ID: ::fankor::prelude::solana_program::pubkey::Pubkey = ::fankor::prelude::const_pubkey!(#pubkey);
 
+        #allocator
+
+        /// Logs the heap usage at the end of the instructions if enabled.
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        #[inline(always)]
+        pub(crate) fn __fankor_internal__log_heap_usage() {
+            #heap_usage_log
+        }
+
         #[cfg(feature = "ts-gen")]
         pub(crate) mod __ts_gen_test__setup {
             use ::fankor::prelude::ts_gen::BuildContext;
@@ -28,6 +123,17 @@ pub fn processor(pubkey: LitStr) -> Result<proc_macro::TokenStream> {
             fn build() {
                 BUILD_CONTEXT.build();
             }
+
+            /// Registers the errors shared by all programs.
+            #[test]
+            fn fankor_errors() {
+                BUILD_CONTEXT.register_action("__ts_gen_test__setup::fankor_errors", file!(), move |action_context| {
+                    action_context.add_account::<::fankor::errors::FankorErrorCode>().unwrap();
+                    action_context.add_error_table("FankorErrorCodeTable", ::fankor::errors::FankorErrorCode::ERROR_TABLE).unwrap();
+                })
+            }
+
+            #ts_heap_size
         }
     };
 
@@ -1,12 +1,43 @@
 use convert_case::{Case, Converter};
+use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::spanned::Spanned;
-use syn::{Error, Fields, Item};
+use syn::{Error, Fields, Ident, Item};
 
 use crate::fnk_syn::FnkMetaArgumentList;
 use crate::Result;
 
 pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
+    let (result, account_name) = ts_type_gen_impl(&input)?;
+
+    let account_name_str = account_name.to_string();
+    let test_name = format_ident!("__ts_gen_test__account_{}", account_name_str);
+    let test_name_str = test_name.to_string();
+    let result = quote! {
+        #[cfg(feature = "ts-gen")]
+        #[automatically_derived]
+        #[allow(non_snake_case)]
+        mod #test_name {
+            use super::*;
+
+            #result
+
+            #[test]
+            fn build() {
+                 // Register action.
+                crate::__ts_gen_test__setup::BUILD_CONTEXT.register_action(#test_name_str, file!(), move |action_context| {
+                    action_context.add_account::<#account_name>().unwrap();
+                })
+            }
+        }
+    };
+
+    Ok(result.into())
+}
+
+/// Generates the `TsTypeGen` implementation of `input` without registering it
+/// in the build context of the program.
+pub fn ts_type_gen_impl(input: &Item) -> Result<(TokenStream, Ident)> {
     let case_converter = Converter::new().from_case(Case::Snake).to_case(Case::Camel);
 
     // Process input.
@@ -746,27 +777,5 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
         }
     };
 
-    let account_name_str = account_name.to_string();
-    let test_name = format_ident!("__ts_gen_test__account_{}", account_name_str);
-    let test_name_str = test_name.to_string();
-    let result = quote! {
-        #[cfg(feature = "ts-gen")]
-        #[automatically_derived]
-        #[allow(non_snake_case)]
-        mod #test_name {
-            use super::*;
-
-            #result
-
-            #[test]
-            fn build() {
-                 // Register action.
-                crate::__ts_gen_test__setup::BUILD_CONTEXT.register_action(#test_name_str, file!(), move |action_context| {
-                    action_context.add_account::<#account_name>().unwrap();
-                })
-            }
-        }
-    };
-
-    Ok(result.into())
+    Ok((result, account_name.clone()))
 }
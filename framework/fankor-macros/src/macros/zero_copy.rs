@@ -170,13 +170,13 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
 
                 // TODO process generics to add phantom data if needed
                 #visibility struct #zc_name #zc_ty_generics #zc_where_clause {
-                    info: &'info AccountInfo<'info>,
+                    info: ZcSource<'info>,
                     offset: usize,
                 }
 
                 #[automatically_derived]
                 impl #zc_impl_generics ZeroCopyType<'info> for #zc_name #zc_ty_generics #zc_where_clause {
-                    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+                    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
                         Ok((
                             #zc_name {
                                 info,
@@ -520,6 +520,103 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 .reduce(|a, b| a && b)
                 .unwrap_or(true);
 
+            // In-place variant setters.
+            let set_variant_trait = if initial_size == 0 {
+                quote! {}
+            } else {
+                let set_variant_trait_name = format_ident!("{}SetVariant", zc_name);
+                let case_converter = Converter::new()
+                    .from_case(Case::Pascal)
+                    .to_case(Case::Snake);
+
+                let mut set_variant_signatures = Vec::with_capacity(item.variants.len());
+                let set_variant_methods = item
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        let variant_name = &variant.ident;
+                        let method_name = format_ident!(
+                            "set_variant_{}",
+                            case_converter.convert(variant_name.to_string()),
+                            span = variant_name.span()
+                        );
+
+                        let (params, field_names) = match &variant.fields {
+                            Fields::Named(fields) => fields
+                                .named
+                                .iter()
+                                .map(|field| {
+                                    let field_name = field.ident.as_ref().unwrap();
+                                    let field_ty = &field.ty;
+
+                                    (quote! { #field_name: #field_ty }, quote! { #field_name })
+                                })
+                                .unzip::<_, _, Vec<_>, Vec<_>>(),
+                            Fields::Unnamed(fields) => fields
+                                .unnamed
+                                .iter()
+                                .enumerate()
+                                .map(|(i, field)| {
+                                    let field_name = format_ident!("v{}", i);
+                                    let field_ty = &field.ty;
+
+                                    (quote! { #field_name: #field_ty }, quote! { #field_name })
+                                })
+                                .unzip::<_, _, Vec<_>, Vec<_>>(),
+                            Fields::Unit => (Vec::new(), Vec::new()),
+                        };
+
+                        let signature = quote! {
+                            fn #method_name<__U: CopyType<'info>>(&self, guard: &ZcWriteGuard<'info, __U>, #(#params),*) -> FankorResult<()>
+                        };
+
+                        set_variant_signatures.push(quote! {
+                            /// Replaces the value with this variant through `guard` resizing
+                            /// the account only by the difference between both variants.
+                            #signature;
+                        });
+
+                        quote! {
+                            #signature {
+                                // Keep the account discriminant.
+                                let mut new_bytes = {
+                                    let bytes = self.info()
+                                        .try_borrow_data()
+                                        .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock { type_name: std::any::type_name::<Self>() })?;
+
+                                    match bytes.get(self.offset()..self.offset() + #extra_offset) {
+                                        Some(v) => v.to_vec(),
+                                        None => {
+                                            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                                                type_name: std::any::type_name::<Self>(),
+                                            }
+                                            .into())
+                                        }
+                                    }
+                                };
+
+                                new_bytes.push(#discriminants_name::#variant_name.code());
+                                #(::fankor::prelude::borsh::BorshSerialize::serialize(&#field_names, &mut new_bytes)?;)*
+
+                                guard.write_bytes(self, &new_bytes)
+                            }
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                quote! {
+                    /// In-place variant setters for the zero-copy value.
+                    #visibility trait #set_variant_trait_name #zc_impl_generics #zc_where_clause {
+                        #(#set_variant_signatures)*
+                    }
+
+                    #[automatically_derived]
+                    impl #zc_impl_generics #set_variant_trait_name #zc_ty_generics for Zc<'info, #name #ty_generics> #zc_where_clause {
+                        #(#set_variant_methods)*
+                    }
+                }
+            };
+
             if is_all_empty {
                 let new_method = item
                     .variants
@@ -547,7 +644,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                     #[automatically_derived]
                     #[allow(non_upper_case_globals)]
                     impl #zc_impl_generics ZeroCopyType<'info> for #name #ty_generics #where_clause {
-                        fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+                        fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
                             let bytes = info
                                 .try_borrow_data()
                                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock { type_name: std::any::type_name::<Self>() })?;
@@ -588,6 +685,8 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                             Ok(size)
                         }
                     }
+
+                    #set_variant_trait
                 }
             } else {
                 let min_byte_size_body = if are_empty_variants || min_byte_size_method.is_empty() {
@@ -631,7 +730,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                     #[automatically_derived]
                     #[allow(non_upper_case_globals)]
                     impl #zc_impl_generics ZeroCopyType<'info> for #zc_name #zc_ty_generics #zc_where_clause {
-                        fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+                        fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
                             let __offset = offset;
                             let bytes = info
                                 .try_borrow_data()
@@ -673,6 +772,8 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                             Ok(size)
                         }
                     }
+
+                    #set_variant_trait
                 }
             }
         }
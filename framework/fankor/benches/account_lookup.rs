@@ -0,0 +1,59 @@
+use std::rc::Rc;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fankor::models::FankorContext;
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+
+/// The number of distinct accounts of the instruction.
+const DISTINCT_ACCOUNTS: usize = 24;
+
+/// The number of accounts repeated at the end of the instruction.
+const DUPLICATED_ACCOUNTS: usize = 8;
+
+fn account_lookup(c: &mut Criterion) {
+    let owner = Pubkey::new_unique();
+    let mut keys = (0..DISTINCT_ACCOUNTS)
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+    keys.extend_from_within(..DUPLICATED_ACCOUNTS);
+
+    let mut lamports = vec![0u64; keys.len()];
+    let mut data = vec![Vec::<u8>::new(); keys.len()];
+    let accounts = keys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .map(|((key, lamports), data)| {
+            AccountInfo::new(key, false, true, lamports, data, &owner, false, 0)
+        })
+        .collect::<Vec<_>>();
+
+    let context = FankorContext::new_unchecked(&owner, &accounts);
+
+    // Half of the accounts have seeds.
+    for info in accounts.iter().step_by(2) {
+        context.set_seeds_for_account_unchecked(info, Rc::new(info.key.to_bytes().to_vec()));
+    }
+
+    c.bench_function("get_seeds_for_account", |b| {
+        b.iter(|| {
+            for info in &accounts {
+                black_box(context.get_seeds_for_account(black_box(info)));
+            }
+        })
+    });
+
+    // The copies of the account infos are searched by key.
+    let copies = accounts.clone();
+    c.bench_function("get_seeds_for_account (copies)", |b| {
+        b.iter(|| {
+            for info in &copies {
+                black_box(context.get_seeds_for_account(black_box(info)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, account_lookup);
+criterion_main!(benches);
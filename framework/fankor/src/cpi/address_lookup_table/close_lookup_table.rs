@@ -0,0 +1,32 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::models::{AddressLookupTableProgram, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiCloseLookupTable<'info> {
+    pub lookup_table: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub recipient: AccountInfo<'info>,
+}
+
+pub fn close_lookup_table(
+    program: &Program<AddressLookupTableProgram>,
+    accounts: CpiCloseLookupTable,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = solana_address_lookup_table_program::instruction::close_lookup_table(
+        *accounts.lookup_table.key,
+        *accounts.authority.key,
+        *accounts.recipient.key,
+    );
+
+    program.invoke_signed(
+        &ix,
+        &[
+            accounts.lookup_table,
+            accounts.authority,
+            accounts.recipient,
+        ],
+        signer_seeds,
+    )
+}
@@ -0,0 +1,38 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Slot;
+
+use crate::models::{AddressLookupTableProgram, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiCreateLookupTable<'info> {
+    pub lookup_table: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Creates a new lookup table whose address is derived from the `authority`
+/// and the `recent_slot`.
+pub fn create_lookup_table(
+    program: &Program<AddressLookupTableProgram>,
+    accounts: CpiCreateLookupTable,
+    recent_slot: Slot,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let (ix, _) = solana_address_lookup_table_program::instruction::create_lookup_table(
+        *accounts.authority.key,
+        *accounts.payer.key,
+        recent_slot,
+    );
+
+    program.invoke_signed(
+        &ix,
+        &[
+            accounts.lookup_table,
+            accounts.authority,
+            accounts.payer,
+            accounts.system_program,
+        ],
+        signer_seeds,
+    )
+}
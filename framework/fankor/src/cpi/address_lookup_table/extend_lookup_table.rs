@@ -0,0 +1,68 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+
+use crate::models::{AddressLookupTableProgram, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiExtendLookupTable<'info> {
+    pub lookup_table: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+pub fn extend_lookup_table(
+    program: &Program<AddressLookupTableProgram>,
+    accounts: CpiExtendLookupTable,
+    new_addresses: Vec<Pubkey>,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = solana_address_lookup_table_program::instruction::extend_lookup_table(
+        *accounts.lookup_table.key,
+        *accounts.authority.key,
+        Some(*accounts.payer.key),
+        new_addresses,
+    );
+
+    program.invoke_signed(
+        &ix,
+        &[
+            accounts.lookup_table,
+            accounts.authority,
+            accounts.payer,
+            accounts.system_program,
+        ],
+        signer_seeds,
+    )
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub struct CpiExtendPrefundedLookupTable<'info> {
+    pub lookup_table: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+/// Extends a lookup table that already has enough lamports to be rent-exempt
+/// after the extension.
+pub fn extend_prefunded_lookup_table(
+    program: &Program<AddressLookupTableProgram>,
+    accounts: CpiExtendPrefundedLookupTable,
+    new_addresses: Vec<Pubkey>,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = solana_address_lookup_table_program::instruction::extend_lookup_table(
+        *accounts.lookup_table.key,
+        *accounts.authority.key,
+        None,
+        new_addresses,
+    );
+
+    program.invoke_signed(
+        &ix,
+        &[accounts.lookup_table, accounts.authority],
+        signer_seeds,
+    )
+}
@@ -0,0 +1,26 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::models::{AddressLookupTableProgram, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiFreezeLookupTable<'info> {
+    pub lookup_table: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+pub fn freeze_lookup_table(
+    program: &Program<AddressLookupTableProgram>,
+    accounts: CpiFreezeLookupTable,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = solana_address_lookup_table_program::instruction::freeze_lookup_table(
+        *accounts.lookup_table.key,
+        *accounts.authority.key,
+    );
+
+    program.invoke_signed(
+        &ix,
+        &[accounts.lookup_table, accounts.authority],
+        signer_seeds,
+    )
+}
@@ -0,0 +1,11 @@
+pub use close_lookup_table::*;
+pub use create_lookup_table::*;
+pub use deactivate_lookup_table::*;
+pub use extend_lookup_table::*;
+pub use freeze_lookup_table::*;
+
+mod close_lookup_table;
+mod create_lookup_table;
+mod deactivate_lookup_table;
+mod extend_lookup_table;
+mod freeze_lookup_table;
@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{AssociatedToken, Program};
 use crate::prelude::FankorResult;
 
@@ -12,7 +11,7 @@ pub struct CpiCreateAssociatedTokenAccountIdempotent<'info> {
 }
 
 pub fn create_associated_token_account_idempotent(
-    _program: &Program<AssociatedToken>,
+    program: &Program<AssociatedToken>,
     accounts: CpiCreateAssociatedTokenAccountIdempotent,
     signer_seeds: &[&[&[u8]]],
 ) -> FankorResult<()> {
@@ -23,7 +22,7 @@ pub fn create_associated_token_account_idempotent(
         accounts.token_program.key,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[
             accounts.funding_address,
@@ -33,5 +32,4 @@ pub fn create_associated_token_account_idempotent(
         ],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
@@ -19,12 +19,11 @@ macro_rules! impl_cpi_method {
                 $($arg_keys,)*
             ) $($instruction_error_handle)?;
 
-            solana_program::program::invoke_signed(
+            program.invoke_signed(
                 &ix,
                 &[$(accounts.$accounts),*],
                 signer_seeds,
             )
-            .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
         }
     };
     ($program: ident, $cpi_name: ident, $name: ident, $func: expr, accounts: [$($accounts:ident),* $(,)?], args: [$($arg_keys:ident : $arg_types: ty),* $(,)?], account_access_token: * $(, instruction_error_handle: $instruction_error_handle: tt)? $(,)?) => {
@@ -44,12 +43,11 @@ macro_rules! impl_cpi_method {
                 $($arg_keys,)*
             ) $($instruction_error_handle)?;
 
-            solana_program::program::invoke_signed(
+            program.invoke_signed(
                 &ix,
                 &[$(accounts.$accounts),*],
                 signer_seeds,
             )
-            .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
         }
     };
 }
@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Metadata, Program};
 use crate::prelude::FankorResult;
 
@@ -30,7 +29,7 @@ pub fn approve_collection_authority(
         *accounts.mint.key,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[
             accounts.collection_authority_record,
@@ -44,5 +43,4 @@ pub fn approve_collection_authority(
         ],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
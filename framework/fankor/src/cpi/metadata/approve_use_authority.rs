@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Metadata, Program};
 use crate::prelude::FankorResult;
 
@@ -37,7 +36,7 @@ pub fn approve_use_authority(
         number_of_uses,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[
             accounts.use_authority_record,
@@ -54,5 +53,4 @@ pub fn approve_use_authority(
         ],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
@@ -0,0 +1,48 @@
+use mpl_token_metadata::instruction::{BurnArgs, MetadataInstruction};
+use solana_program::account_info::AccountInfo;
+
+use crate::cpi::metadata::MetadataAccountList;
+use crate::models::{Metadata, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiBurn<'info> {
+    pub authority: AccountInfo<'info>,
+    pub collection_metadata: Option<AccountInfo<'info>>,
+    pub metadata: AccountInfo<'info>,
+    pub edition: Option<AccountInfo<'info>>,
+    pub mint: AccountInfo<'info>,
+    pub token: AccountInfo<'info>,
+    pub master_edition: Option<AccountInfo<'info>>,
+    pub master_edition_mint: Option<AccountInfo<'info>>,
+    pub master_edition_token: Option<AccountInfo<'info>>,
+    pub edition_marker: Option<AccountInfo<'info>>,
+    pub token_record: Option<AccountInfo<'info>>,
+    pub system_program: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+pub fn burn<'info>(
+    program: &Program<'info, Metadata>,
+    accounts: CpiBurn<'info>,
+    args: BurnArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let mut list = MetadataAccountList::new(program, 14);
+    list.add(accounts.authority, true, true);
+    list.add_optional(accounts.collection_metadata, true);
+    list.add(accounts.metadata, true, false);
+    list.add_optional(accounts.edition, true);
+    list.add(accounts.mint, true, false);
+    list.add(accounts.token, true, false);
+    list.add_optional(accounts.master_edition, true);
+    list.add_optional(accounts.master_edition_mint, false);
+    list.add_optional(accounts.master_edition_token, false);
+    list.add_optional(accounts.edition_marker, true);
+    list.add_optional(accounts.token_record, true);
+    list.add(accounts.system_program, false, false);
+    list.add(accounts.instructions_sysvar, false, false);
+    list.add(accounts.token_program, false, false);
+
+    list.invoke_signed(program, MetadataInstruction::Burn(args), signer_seeds)
+}
@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Metadata, Program};
 use crate::prelude::FankorResult;
 
@@ -42,6 +41,5 @@ pub fn burn_nft(
         infos.push(collection_metadata);
     }
 
-    solana_program::program::invoke_signed(&ix, &infos, signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &infos, signer_seeds)
 }
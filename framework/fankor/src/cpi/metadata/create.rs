@@ -0,0 +1,40 @@
+use mpl_token_metadata::instruction::{CreateArgs, MetadataInstruction};
+use solana_program::account_info::AccountInfo;
+
+use crate::cpi::metadata::MetadataAccountList;
+use crate::models::{Metadata, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiCreate<'info> {
+    pub metadata: AccountInfo<'info>,
+    pub master_edition: Option<AccountInfo<'info>>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+pub fn create<'info>(
+    program: &Program<'info, Metadata>,
+    accounts: CpiCreate<'info>,
+    args: CreateArgs,
+    initialize_mint: bool,
+    update_authority_as_signer: bool,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let mut list = MetadataAccountList::new(program, 9);
+    list.add(accounts.metadata, true, false);
+    list.add_optional(accounts.master_edition, true);
+    list.add(accounts.mint, true, initialize_mint);
+    list.add(accounts.authority, false, true);
+    list.add(accounts.payer, true, true);
+    list.add(accounts.update_authority, false, update_authority_as_signer);
+    list.add(accounts.system_program, false, false);
+    list.add(accounts.instructions_sysvar, false, false);
+    list.add(accounts.token_program, false, false);
+
+    list.invoke_signed(program, MetadataInstruction::Create(args), signer_seeds)
+}
@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Metadata, Program};
 use crate::prelude::FankorResult;
 
@@ -33,7 +32,7 @@ pub fn create_master_edition_v3(
         max_supply,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[
             accounts.edition,
@@ -48,5 +47,4 @@ pub fn create_master_edition_v3(
         ],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
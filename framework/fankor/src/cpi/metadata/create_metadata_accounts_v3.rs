@@ -1,7 +1,6 @@
 use mpl_token_metadata::state::{Collection, CollectionDetails, Creator, Uses};
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Metadata, Program};
 use crate::prelude::FankorResult;
 
@@ -50,7 +49,7 @@ pub fn create_metadata_accounts_v3(
         collection_details,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[
             accounts.metadata,
@@ -63,5 +62,4 @@ pub fn create_metadata_accounts_v3(
         ],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
@@ -0,0 +1,48 @@
+use mpl_token_metadata::instruction::{DelegateArgs, MetadataInstruction};
+use solana_program::account_info::AccountInfo;
+
+use crate::cpi::metadata::MetadataAccountList;
+use crate::models::{Metadata, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiDelegate<'info> {
+    pub delegate_record: Option<AccountInfo<'info>>,
+    pub delegate: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub master_edition: Option<AccountInfo<'info>>,
+    pub token_record: Option<AccountInfo<'info>>,
+    pub mint: AccountInfo<'info>,
+    pub token: Option<AccountInfo<'info>>,
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub token_program: Option<AccountInfo<'info>>,
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+    pub authorization_rules: Option<AccountInfo<'info>>,
+}
+
+pub fn delegate<'info>(
+    program: &Program<'info, Metadata>,
+    accounts: CpiDelegate<'info>,
+    args: DelegateArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let mut list = MetadataAccountList::new(program, 14);
+    list.add_optional(accounts.delegate_record, true);
+    list.add(accounts.delegate, false, false);
+    list.add(accounts.metadata, true, false);
+    list.add_optional(accounts.master_edition, false);
+    list.add_optional(accounts.token_record, true);
+    list.add(accounts.mint, false, false);
+    list.add_optional(accounts.token, true);
+    list.add(accounts.authority, false, true);
+    list.add(accounts.payer, true, true);
+    list.add(accounts.system_program, false, false);
+    list.add(accounts.instructions_sysvar, false, false);
+    list.add_optional(accounts.token_program, false);
+    list.add_optional(accounts.authorization_rules_program, false);
+    list.add_optional(accounts.authorization_rules, false);
+
+    list.invoke_signed(program, MetadataInstruction::Delegate(args), signer_seeds)
+}
@@ -0,0 +1,50 @@
+use mpl_token_metadata::instruction::{MetadataInstruction, MintArgs};
+use solana_program::account_info::AccountInfo;
+
+use crate::cpi::metadata::MetadataAccountList;
+use crate::models::{Metadata, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiMint<'info> {
+    pub token: AccountInfo<'info>,
+    pub token_owner: Option<AccountInfo<'info>>,
+    pub metadata: AccountInfo<'info>,
+    pub master_edition: Option<AccountInfo<'info>>,
+    pub token_record: Option<AccountInfo<'info>>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub delegate_record: Option<AccountInfo<'info>>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+    pub authorization_rules: Option<AccountInfo<'info>>,
+}
+
+pub fn mint<'info>(
+    program: &Program<'info, Metadata>,
+    accounts: CpiMint<'info>,
+    args: MintArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let mut list = MetadataAccountList::new(program, 15);
+    list.add(accounts.token, true, false);
+    list.add_optional(accounts.token_owner, false);
+    list.add(accounts.metadata, false, false);
+    list.add_optional(accounts.master_edition, true);
+    list.add_optional(accounts.token_record, true);
+    list.add(accounts.mint, true, false);
+    list.add(accounts.authority, false, true);
+    list.add_optional(accounts.delegate_record, false);
+    list.add(accounts.payer, true, true);
+    list.add(accounts.system_program, false, false);
+    list.add(accounts.instructions_sysvar, false, false);
+    list.add(accounts.token_program, false, false);
+    list.add(accounts.associated_token_program, false, false);
+    list.add_optional(accounts.authorization_rules_program, false);
+    list.add_optional(accounts.authorization_rules, false);
+
+    list.invoke_signed(program, MetadataInstruction::Mint(args), signer_seeds)
+}
@@ -1,38 +1,58 @@
+use borsh::BorshSerialize;
+use mpl_token_metadata::instruction::MetadataInstruction;
 use mpl_token_metadata::state::DataV2;
+use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::pubkey::Pubkey;
 
 pub use approve_collection_authority::*;
 pub use approve_use_authority::*;
+pub use burn::*;
 pub use burn_nft::*;
+pub use create::*;
 pub use create_master_edition_v3::*;
 pub use create_metadata_accounts_v3::*;
+pub use delegate::*;
+pub use lock::*;
+pub use mint::*;
+pub use revoke::*;
 pub use revoke_use_authority::*;
 pub use set_and_verify_sized_collection_item::*;
 pub use set_collection_size::*;
 pub use set_token_standard::*;
+pub use transfer::*;
+pub use unlock::*;
 pub use unverify_collection::*;
 pub use unverify_sized_collection_item::*;
+pub use update::*;
 pub use utilize::*;
 pub use verify_collection::*;
 pub use verify_sized_collection_item::*;
 
 use crate::cpi::macros::impl_cpi_method;
-use crate::errors::Error;
 use crate::models::{Metadata, Program};
 use crate::prelude::AccountInfo;
 use crate::prelude::FankorResult;
 
 mod approve_collection_authority;
 mod approve_use_authority;
+mod burn;
 mod burn_nft;
+mod create;
 mod create_master_edition_v3;
 mod create_metadata_accounts_v3;
+mod delegate;
+mod lock;
+mod mint;
+mod revoke;
 mod revoke_use_authority;
 mod set_and_verify_sized_collection_item;
 mod set_collection_size;
 mod set_token_standard;
+mod transfer;
+mod unlock;
 mod unverify_collection;
 mod unverify_sized_collection_item;
+mod update;
 mod utilize;
 mod verify_collection;
 mod verify_sized_collection_item;
@@ -152,3 +172,63 @@ impl_metadata_cpi_method!(
     ],
     args: []
 );
+
+// ----------------------------------------------------------------------------
+// HELPERS --------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Collects the accounts of the Token Metadata unified instructions, which
+/// expect the program itself in place of any missing optional account.
+struct MetadataAccountList<'info> {
+    program: AccountInfo<'info>,
+    metas: Vec<AccountMeta>,
+    infos: Vec<AccountInfo<'info>>,
+}
+
+impl<'info> MetadataAccountList<'info> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    fn new(program: &Program<'info, Metadata>, capacity: usize) -> Self {
+        Self {
+            program: program.info().clone(),
+            metas: Vec::with_capacity(capacity),
+            infos: Vec::with_capacity(capacity),
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    fn add(&mut self, account: AccountInfo<'info>, is_writable: bool, is_signer: bool) {
+        self.metas.push(if is_writable {
+            AccountMeta::new(*account.key, is_signer)
+        } else {
+            AccountMeta::new_readonly(*account.key, is_signer)
+        });
+        self.infos.push(account);
+    }
+
+    fn add_optional(&mut self, account: Option<AccountInfo<'info>>, is_writable: bool) {
+        match account {
+            Some(account) => self.add(account, is_writable, false),
+            None => {
+                let program = self.program.clone();
+                self.add(program, false, false);
+            }
+        }
+    }
+
+    fn invoke_signed(
+        self,
+        program: &Program<'info, Metadata>,
+        data: MetadataInstruction,
+        signer_seeds: &[&[&[u8]]],
+    ) -> FankorResult<()> {
+        let ix = Instruction {
+            program_id: *program.address(),
+            accounts: self.metas,
+            data: data.try_to_vec()?,
+        };
+
+        program.invoke_signed(&ix, &self.infos, signer_seeds)
+    }
+}
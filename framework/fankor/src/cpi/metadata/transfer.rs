@@ -0,0 +1,54 @@
+use mpl_token_metadata::instruction::{MetadataInstruction, TransferArgs};
+use solana_program::account_info::AccountInfo;
+
+use crate::cpi::metadata::MetadataAccountList;
+use crate::models::{Metadata, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiTransfer<'info> {
+    pub token: AccountInfo<'info>,
+    pub token_owner: AccountInfo<'info>,
+    pub destination: AccountInfo<'info>,
+    pub destination_owner: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub edition: Option<AccountInfo<'info>>,
+    pub owner_token_record: Option<AccountInfo<'info>>,
+    pub destination_token_record: Option<AccountInfo<'info>>,
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+    pub authorization_rules: Option<AccountInfo<'info>>,
+}
+
+pub fn transfer<'info>(
+    program: &Program<'info, Metadata>,
+    accounts: CpiTransfer<'info>,
+    args: TransferArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let mut list = MetadataAccountList::new(program, 17);
+    list.add(accounts.token, true, false);
+    list.add(accounts.token_owner, false, false);
+    list.add(accounts.destination, true, false);
+    list.add(accounts.destination_owner, false, false);
+    list.add(accounts.mint, false, false);
+    list.add(accounts.metadata, true, false);
+    list.add_optional(accounts.edition, false);
+    list.add_optional(accounts.owner_token_record, true);
+    list.add_optional(accounts.destination_token_record, true);
+    list.add(accounts.authority, false, true);
+    list.add(accounts.payer, true, true);
+    list.add(accounts.system_program, false, false);
+    list.add(accounts.instructions_sysvar, false, false);
+    list.add(accounts.token_program, false, false);
+    list.add(accounts.associated_token_program, false, false);
+    list.add_optional(accounts.authorization_rules_program, false);
+    list.add_optional(accounts.authorization_rules, false);
+
+    list.invoke_signed(program, MetadataInstruction::Transfer(args), signer_seeds)
+}
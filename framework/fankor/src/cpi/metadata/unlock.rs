@@ -0,0 +1,46 @@
+use mpl_token_metadata::instruction::{MetadataInstruction, UnlockArgs};
+use solana_program::account_info::AccountInfo;
+
+use crate::cpi::metadata::MetadataAccountList;
+use crate::models::{Metadata, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiUnlock<'info> {
+    pub authority: AccountInfo<'info>,
+    pub token_owner: Option<AccountInfo<'info>>,
+    pub token: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub edition: Option<AccountInfo<'info>>,
+    pub token_record: Option<AccountInfo<'info>>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub token_program: Option<AccountInfo<'info>>,
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+    pub authorization_rules: Option<AccountInfo<'info>>,
+}
+
+pub fn unlock<'info>(
+    program: &Program<'info, Metadata>,
+    accounts: CpiUnlock<'info>,
+    args: UnlockArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let mut list = MetadataAccountList::new(program, 13);
+    list.add(accounts.authority, false, true);
+    list.add_optional(accounts.token_owner, false);
+    list.add(accounts.token, true, false);
+    list.add(accounts.mint, false, false);
+    list.add(accounts.metadata, true, false);
+    list.add_optional(accounts.edition, false);
+    list.add_optional(accounts.token_record, true);
+    list.add(accounts.payer, true, true);
+    list.add(accounts.system_program, false, false);
+    list.add(accounts.instructions_sysvar, false, false);
+    list.add_optional(accounts.token_program, false);
+    list.add_optional(accounts.authorization_rules_program, false);
+    list.add_optional(accounts.authorization_rules, false);
+
+    list.invoke_signed(program, MetadataInstruction::Unlock(args), signer_seeds)
+}
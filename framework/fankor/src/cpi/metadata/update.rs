@@ -0,0 +1,42 @@
+use mpl_token_metadata::instruction::{MetadataInstruction, UpdateArgs};
+use solana_program::account_info::AccountInfo;
+
+use crate::cpi::metadata::MetadataAccountList;
+use crate::models::{Metadata, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiUpdate<'info> {
+    pub authority: AccountInfo<'info>,
+    pub delegate_record: Option<AccountInfo<'info>>,
+    pub token: Option<AccountInfo<'info>>,
+    pub mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub edition: Option<AccountInfo<'info>>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+    pub authorization_rules: Option<AccountInfo<'info>>,
+}
+
+pub fn update<'info>(
+    program: &Program<'info, Metadata>,
+    accounts: CpiUpdate<'info>,
+    args: UpdateArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let mut list = MetadataAccountList::new(program, 11);
+    list.add(accounts.authority, false, true);
+    list.add_optional(accounts.delegate_record, false);
+    list.add_optional(accounts.token, false);
+    list.add(accounts.mint, false, false);
+    list.add(accounts.metadata, true, false);
+    list.add_optional(accounts.edition, false);
+    list.add(accounts.payer, true, true);
+    list.add(accounts.system_program, false, false);
+    list.add(accounts.instructions_sysvar, false, false);
+    list.add_optional(accounts.authorization_rules_program, false);
+    list.add_optional(accounts.authorization_rules, false);
+
+    list.invoke_signed(program, MetadataInstruction::Update(args), signer_seeds)
+}
@@ -1,8 +1,12 @@
+#[cfg(feature = "address-lookup-table-program")]
+pub mod address_lookup_table;
 #[cfg(feature = "token-program")]
 pub mod associated_token;
 mod macros;
 #[cfg(feature = "metadata-program")]
 pub mod metadata;
+#[cfg(feature = "stake-program")]
+pub mod stake;
 pub mod system_program;
 #[cfg(feature = "token-program")]
 pub mod token;
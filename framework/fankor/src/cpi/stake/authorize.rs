@@ -0,0 +1,37 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+use solana_program::stake::state::StakeAuthorize;
+
+use crate::models::{Program, Stake};
+use crate::prelude::FankorResult;
+
+pub struct CpiAuthorize<'info> {
+    pub stake: AccountInfo<'info>,
+    pub clock: AccountInfo<'info>,
+    pub authorized: AccountInfo<'info>,
+    pub custodian: Option<AccountInfo<'info>>,
+}
+
+pub fn authorize(
+    program: &Program<Stake>,
+    accounts: CpiAuthorize,
+    new_authorized: &Pubkey,
+    stake_authorize: StakeAuthorize,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = solana_program::stake::instruction::authorize(
+        accounts.stake.key,
+        accounts.authorized.key,
+        new_authorized,
+        stake_authorize,
+        accounts.custodian.as_ref().map(|v| v.key),
+    );
+
+    let mut infos = vec![accounts.stake, accounts.clock, accounts.authorized];
+
+    if let Some(custodian) = accounts.custodian {
+        infos.push(custodian);
+    }
+
+    program.invoke_signed(&ix, &infos, signer_seeds)
+}
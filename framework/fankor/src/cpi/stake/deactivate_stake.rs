@@ -0,0 +1,27 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::models::{Program, Stake};
+use crate::prelude::FankorResult;
+
+pub struct CpiDeactivateStake<'info> {
+    pub stake: AccountInfo<'info>,
+    pub clock: AccountInfo<'info>,
+    pub authorized: AccountInfo<'info>,
+}
+
+pub fn deactivate_stake(
+    program: &Program<Stake>,
+    accounts: CpiDeactivateStake,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = solana_program::stake::instruction::deactivate_stake(
+        accounts.stake.key,
+        accounts.authorized.key,
+    );
+
+    program.invoke_signed(
+        &ix,
+        &[accounts.stake, accounts.clock, accounts.authorized],
+        signer_seeds,
+    )
+}
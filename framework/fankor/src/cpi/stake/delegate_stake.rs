@@ -0,0 +1,38 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::models::{Program, Stake};
+use crate::prelude::FankorResult;
+
+pub struct CpiDelegateStake<'info> {
+    pub stake: AccountInfo<'info>,
+    pub vote: AccountInfo<'info>,
+    pub clock: AccountInfo<'info>,
+    pub stake_history: AccountInfo<'info>,
+    pub stake_config: AccountInfo<'info>,
+    pub authorized: AccountInfo<'info>,
+}
+
+pub fn delegate_stake(
+    program: &Program<Stake>,
+    accounts: CpiDelegateStake,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = solana_program::stake::instruction::delegate_stake(
+        accounts.stake.key,
+        accounts.authorized.key,
+        accounts.vote.key,
+    );
+
+    program.invoke_signed(
+        &ix,
+        &[
+            accounts.stake,
+            accounts.vote,
+            accounts.clock,
+            accounts.stake_history,
+            accounts.stake_config,
+            accounts.authorized,
+        ],
+        signer_seeds,
+    )
+}
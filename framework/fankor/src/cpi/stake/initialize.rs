@@ -0,0 +1,22 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::stake::state::{Authorized, Lockup};
+
+use crate::models::{Program, Stake};
+use crate::prelude::FankorResult;
+
+pub struct CpiInitialize<'info> {
+    pub stake: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+pub fn initialize(
+    program: &Program<Stake>,
+    accounts: CpiInitialize,
+    authorized: &Authorized,
+    lockup: &Lockup,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = solana_program::stake::instruction::initialize(accounts.stake.key, authorized, lockup);
+
+    program.invoke_signed(&ix, &[accounts.stake, accounts.rent], signer_seeds)
+}
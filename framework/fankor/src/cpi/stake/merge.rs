@@ -0,0 +1,38 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::models::{Program, Stake};
+use crate::prelude::FankorResult;
+
+pub struct CpiMerge<'info> {
+    pub destination_stake: AccountInfo<'info>,
+    pub source_stake: AccountInfo<'info>,
+    pub clock: AccountInfo<'info>,
+    pub stake_history: AccountInfo<'info>,
+    pub authorized: AccountInfo<'info>,
+}
+
+pub fn merge(
+    program: &Program<Stake>,
+    accounts: CpiMerge,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let instructions = solana_program::stake::instruction::merge(
+        accounts.destination_stake.key,
+        accounts.source_stake.key,
+        accounts.authorized.key,
+    );
+
+    let infos = [
+        accounts.destination_stake,
+        accounts.source_stake,
+        accounts.clock,
+        accounts.stake_history,
+        accounts.authorized,
+    ];
+
+    for ix in &instructions {
+        program.invoke_signed(ix, &infos, signer_seeds)?;
+    }
+
+    Ok(())
+}
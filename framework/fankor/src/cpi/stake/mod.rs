@@ -0,0 +1,15 @@
+pub use authorize::*;
+pub use deactivate_stake::*;
+pub use delegate_stake::*;
+pub use initialize::*;
+pub use merge::*;
+pub use split::*;
+pub use withdraw::*;
+
+mod authorize;
+mod deactivate_stake;
+mod delegate_stake;
+mod initialize;
+mod merge;
+mod split;
+mod withdraw;
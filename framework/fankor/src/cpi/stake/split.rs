@@ -0,0 +1,43 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::models::{Program, Stake};
+use crate::prelude::FankorResult;
+
+pub struct CpiSplit<'info> {
+    pub stake: AccountInfo<'info>,
+    pub split_stake: AccountInfo<'info>,
+    pub authorized: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Splits `lamports` from `stake` into `split_stake`.
+///
+/// The `split_stake` account is allocated and assigned to the stake program
+/// before the split, so it must be a signer of the transaction or be signed by
+/// `signer_seeds`.
+pub fn split(
+    program: &Program<Stake>,
+    accounts: CpiSplit,
+    lamports: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let instructions = solana_program::stake::instruction::split(
+        accounts.stake.key,
+        accounts.authorized.key,
+        lamports,
+        accounts.split_stake.key,
+    );
+
+    let infos = [
+        accounts.stake,
+        accounts.split_stake,
+        accounts.authorized,
+        accounts.system_program,
+    ];
+
+    for ix in &instructions {
+        program.invoke_signed(ix, &infos, signer_seeds)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::models::{Program, Stake};
+use crate::prelude::FankorResult;
+
+pub struct CpiWithdraw<'info> {
+    pub stake: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub clock: AccountInfo<'info>,
+    pub stake_history: AccountInfo<'info>,
+    pub withdrawer: AccountInfo<'info>,
+    pub custodian: Option<AccountInfo<'info>>,
+}
+
+pub fn withdraw(
+    program: &Program<Stake>,
+    accounts: CpiWithdraw,
+    lamports: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = solana_program::stake::instruction::withdraw(
+        accounts.stake.key,
+        accounts.withdrawer.key,
+        accounts.to.key,
+        lamports,
+        accounts.custodian.as_ref().map(|v| v.key),
+    );
+
+    let mut infos = vec![
+        accounts.stake,
+        accounts.to,
+        accounts.clock,
+        accounts.stake_history,
+        accounts.withdrawer,
+    ];
+
+    if let Some(custodian) = accounts.custodian {
+        infos.push(custodian);
+    }
+
+    program.invoke_signed(&ix, &infos, signer_seeds)
+}
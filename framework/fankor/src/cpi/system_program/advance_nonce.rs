@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Program, System};
 use crate::prelude::FankorResult;
 
@@ -11,7 +10,7 @@ pub struct CpiAdvanceNonceAccount<'info> {
 }
 
 pub fn advance_nonce_account(
-    _program: &Program<System>,
+    program: &Program<System>,
     accounts: CpiAdvanceNonceAccount,
     signer_seeds: &[&[&[u8]]],
 ) -> FankorResult<()> {
@@ -20,7 +19,7 @@ pub fn advance_nonce_account(
         accounts.authorized.key,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[
             accounts.nonce,
@@ -29,5 +28,4 @@ pub fn advance_nonce_account(
         ],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
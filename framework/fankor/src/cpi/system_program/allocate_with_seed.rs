@@ -1,7 +1,6 @@
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::Error;
 use crate::models::{Program, System};
 use crate::prelude::FankorResult;
 
@@ -11,7 +10,7 @@ pub struct CpiAllocateWithSeed<'info> {
 }
 
 pub fn allocate_with_seed(
-    _program: &Program<System>,
+    program: &Program<System>,
     accounts: CpiAllocateWithSeed,
     seed: &str,
     space: u64,
@@ -26,10 +25,9 @@ pub fn allocate_with_seed(
         owner,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[accounts.account_to_allocate, accounts.base],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
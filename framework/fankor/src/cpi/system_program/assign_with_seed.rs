@@ -1,7 +1,6 @@
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::Error;
 use crate::models::{Program, System};
 use crate::prelude::FankorResult;
 
@@ -11,7 +10,7 @@ pub struct CpiAssignWithSeed<'info> {
 }
 
 pub fn assign_with_seed(
-    _program: &Program<System>,
+    program: &Program<System>,
     accounts: CpiAssignWithSeed,
     seed: &str,
     owner: &Pubkey,
@@ -24,10 +23,9 @@ pub fn assign_with_seed(
         owner,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[accounts.account_to_assign, accounts.base],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
@@ -1,7 +1,6 @@
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::Error;
 use crate::models::{Program, System};
 use crate::prelude::FankorResult;
 
@@ -11,7 +10,7 @@ pub struct CpiAuthorizeNonceAccount<'info> {
 }
 
 pub fn authorize_nonce_account(
-    _program: &Program<System>,
+    program: &Program<System>,
     accounts: CpiAuthorizeNonceAccount,
     new_authority: &Pubkey,
     signer_seeds: &[&[&[u8]]],
@@ -22,10 +21,5 @@ pub fn authorize_nonce_account(
         new_authority,
     );
 
-    solana_program::program::invoke_signed(
-        &ix,
-        &[accounts.nonce, accounts.authorized],
-        signer_seeds,
-    )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &[accounts.nonce, accounts.authorized], signer_seeds)
 }
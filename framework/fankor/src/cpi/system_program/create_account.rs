@@ -1,7 +1,6 @@
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::Error;
 use crate::models::{Program, System};
 use crate::prelude::FankorResult;
 
@@ -11,7 +10,7 @@ pub struct CpiCreateAccount<'info> {
 }
 
 pub fn create_account(
-    _program: &Program<System>,
+    program: &Program<System>,
     accounts: CpiCreateAccount,
     lamports: u64,
     space: u64,
@@ -26,6 +25,5 @@ pub fn create_account(
         owner,
     );
 
-    solana_program::program::invoke_signed(&ix, &[accounts.from, accounts.to], signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &[accounts.from, accounts.to], signer_seeds)
 }
@@ -1,7 +1,6 @@
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::Error;
 use crate::models::{Program, System};
 use crate::prelude::FankorResult;
 
@@ -12,7 +11,7 @@ pub struct CpiCreateAccountWithSeed<'info> {
 }
 
 pub fn create_account_with_seed(
-    _program: &Program<System>,
+    program: &Program<System>,
     accounts: CpiCreateAccountWithSeed,
     seed: &str,
     lamports: u64,
@@ -30,10 +29,9 @@ pub fn create_account_with_seed(
         owner,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[accounts.from, accounts.to, accounts.base],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
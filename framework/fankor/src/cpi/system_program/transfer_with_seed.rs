@@ -1,7 +1,6 @@
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::Error;
 use crate::models::{Program, System};
 use crate::prelude::FankorResult;
 
@@ -12,7 +11,7 @@ pub struct CpiTransferWithSeed<'info> {
 }
 
 pub fn transfer_with_seed(
-    _program: &Program<System>,
+    program: &Program<System>,
     accounts: CpiTransferWithSeed,
     from_seed: String,
     from_owner: &Pubkey,
@@ -28,10 +27,9 @@ pub fn transfer_with_seed(
         lamports,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[accounts.from, accounts.base, accounts.to],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Program, System};
 use crate::prelude::FankorResult;
 
@@ -13,7 +12,7 @@ pub struct CpiWithdrawNonceAccount<'info> {
 }
 
 pub fn withdraw_nonce_account(
-    _program: &Program<System>,
+    program: &Program<System>,
     accounts: CpiWithdrawNonceAccount,
     lamports: u64,
     signer_seeds: &[&[&[u8]]],
@@ -25,7 +24,7 @@ pub fn withdraw_nonce_account(
         lamports,
     );
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[
             accounts.nonce,
@@ -36,5 +35,4 @@ pub fn withdraw_nonce_account(
         ],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Program, Token};
 use crate::prelude::FankorResult;
 
@@ -29,7 +28,7 @@ pub fn approve_checked(
         decimals,
     )?;
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[
             accounts.source,
@@ -39,7 +38,6 @@ pub fn approve_checked(
         ],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
 
 // ----------------------------------------------------------------------------
@@ -80,6 +78,5 @@ pub fn approve_checked_multisig(
     infos.push(accounts.authority);
     infos.extend(accounts.signers.into_iter());
 
-    solana_program::program::invoke_signed(&ix, &infos, signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &infos, signer_seeds)
 }
@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Program, Token};
 use crate::prelude::FankorResult;
 
@@ -25,12 +24,11 @@ pub fn burn(
         amount,
     )?;
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[accounts.from, accounts.mint, accounts.authority],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
 
 // ----------------------------------------------------------------------------
@@ -66,6 +64,5 @@ pub fn burn_multisig(
     infos.push(accounts.authority);
     infos.extend(accounts.signers.into_iter());
 
-    solana_program::program::invoke_signed(&ix, &infos, signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &infos, signer_seeds)
 }
@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Program, Token};
 use crate::prelude::FankorResult;
 
@@ -23,12 +22,11 @@ pub fn close_account(
         &[],
     )?;
 
-    solana_program::program::invoke_signed(
+    program.invoke_signed(
         &ix,
         &[accounts.account, accounts.destination, accounts.authority],
         signer_seeds,
     )
-    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
 }
 
 // ----------------------------------------------------------------------------
@@ -62,6 +60,5 @@ pub fn close_account_multisig(
     infos.push(accounts.authority);
     infos.extend(accounts.signers.into_iter());
 
-    solana_program::program::invoke_signed(&ix, &infos, signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &infos, signer_seeds)
 }
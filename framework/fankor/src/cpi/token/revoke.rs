@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Program, Token};
 use crate::prelude::FankorResult;
 
@@ -21,8 +20,7 @@ pub fn revoke(
         &[],
     )?;
 
-    solana_program::program::invoke_signed(&ix, &[accounts.source, accounts.owner], signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &[accounts.source, accounts.owner], signer_seeds)
 }
 
 // ----------------------------------------------------------------------------
@@ -53,6 +51,5 @@ pub fn revoke_multisig(
     infos.push(accounts.owner);
     infos.extend(accounts.signers.into_iter());
 
-    solana_program::program::invoke_signed(&ix, &infos, signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &infos, signer_seeds)
 }
@@ -1,7 +1,6 @@
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::Error;
 use crate::models::{Program, Token2022};
 use crate::prelude::FankorResult;
 
@@ -23,6 +22,5 @@ pub fn initialize_account3(
         owner,
     )?;
 
-    solana_program::program::invoke_signed(&ix, &[accounts.account, accounts.mint], signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &[accounts.account, accounts.mint], signer_seeds)
 }
@@ -1,7 +1,6 @@
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::Error;
 use crate::models::{Program, Token2022};
 use crate::prelude::FankorResult;
 
@@ -25,6 +24,5 @@ pub fn initialize_mint2(
         decimals,
     )?;
 
-    solana_program::program::invoke_signed(&ix, &[accounts.mint], signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &[accounts.mint], signer_seeds)
 }
@@ -2,7 +2,6 @@ use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 use spl_token_2022::instruction::AuthorityType;
 
-use crate::errors::Error;
 use crate::models::{Program, Token2022};
 use crate::prelude::FankorResult;
 
@@ -27,8 +26,7 @@ pub fn set_authority(
         &[],
     )?;
 
-    solana_program::program::invoke_signed(&ix, &[accounts.owned, accounts.owner], signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &[accounts.owned, accounts.owner], signer_seeds)
 }
 
 // ----------------------------------------------------------------------------
@@ -63,6 +61,5 @@ pub fn set_authority_multisig(
     infos.push(accounts.owner);
     infos.extend(accounts.signers.into_iter());
 
-    solana_program::program::invoke_signed(&ix, &infos, signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &infos, signer_seeds)
 }
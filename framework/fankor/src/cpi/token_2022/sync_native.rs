@@ -1,6 +1,5 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::Error;
 use crate::models::{Program, Token2022};
 use crate::prelude::FankorResult;
 
@@ -15,6 +14,5 @@ pub fn sync_native(
 ) -> FankorResult<()> {
     let ix = spl_token_2022::instruction::sync_native(program.address(), accounts.account.key)?;
 
-    solana_program::program::invoke_signed(&ix, &[accounts.account], signer_seeds)
-        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    program.invoke_signed(&ix, &[accounts.account], signer_seeds)
 }
@@ -22,7 +22,7 @@ pub const ERROR_CODE_OFFSET: u32 = 6000;
 ///
 /// The starting point for user-defined errors is defined
 /// by the [ERROR_CODE_OFFSET](crate::error::ERROR_CODE_OFFSET).
-#[error_code(offset = 0, skip_ts_gen)]
+#[error_code(offset = 0, shared)]
 pub enum FankorErrorCode {
     // ------------------------------------------------------------------------
     // General ----------------------------------------------------------------
@@ -75,6 +75,58 @@ pub enum FankorErrorCode {
     #[msg("The seeds of the PDA ({}) are missing", account)]
     MissingPdaSeeds { account: Pubkey },
 
+    /// The instruction can only be called directly from a transaction, not through a CPI
+    #[msg("The instruction can only be called directly from a transaction, not through a CPI")]
+    InstructionNotTopLevel,
+
+    /// The instruction can only be called through a CPI, not directly from a transaction
+    #[msg("The instruction can only be called through a CPI, not directly from a transaction")]
+    InstructionNotCalledViaCpi,
+
+    /// The instruction cannot be called by the caller program
+    #[msg("The instruction cannot be called by the program {}", caller)]
+    InstructionCallerNotAllowed { caller: Pubkey },
+
+    /// The caller of the instruction cannot be determined
+    #[msg("The caller of the instruction cannot be determined")]
+    InstructionCallerUnknown,
+
+    /// The program has been re-entered through a CPI
+    #[msg("The program {} has been re-entered through a CPI", program_id)]
+    ReentrancyDetected { program_id: Pubkey },
+
+    /// The Instructions sysvar account must be provided in the account list
+    #[msg("The Instructions sysvar account must be provided in the account list")]
+    MissingInstructionsSysvar,
+
+    /// The length of a bounded type exceeds its maximum
+    #[msg(
+    "The length ({}) of the bounded type exceeds its maximum ({})",
+    actual,
+    max
+    )]
+    BoundedLengthExceeded { max: usize, actual: usize },
+
+    /// The timestamp has already expired
+    #[msg("The timestamp ({}) has already expired, now: {}", expires_at, now)]
+    TimestampExpired { expires_at: i64, now: i64 },
+
+    /// The program is paused
+    #[msg("The program is paused")]
+    ProgramPaused,
+
+    /// The signer is not the admin of the program
+    #[msg(
+    "The signer ({}) is not the admin of the program ({})",
+    actual,
+    expected
+    )]
+    PauseAdminMismatch { expected: Pubkey, actual: Pubkey },
+
+    /// The instruction is deprecated
+    #[msg("The instruction is deprecated: {}", name)]
+    InstructionDeprecated { name: &'static str },
+
     // ------------------------------------------------------------------------
     // Accounts ---------------------------------------------------------------
     // ------------------------------------------------------------------------
@@ -113,8 +165,8 @@ pub enum FankorErrorCode {
     #[msg("Cannot create a mutable reference to a readonly account: {}", address)]
     MutRefToReadonlyAccount { address: Pubkey },
 
-    /// Cannot create an account from an AccountInfo which has been already marked as closed. If your purpose is to revive the account, please use: FankorContext::revive
-    #[msg("Cannot create an account from an AccountInfo ({}) which has been already marked as closed. If your purpose is to revive the account, please use: FankorContext::revive", address)]
+    /// Cannot create an account from an AccountInfo which has been already closed with a tombstone. If your purpose is to revive the account, please use: FankorContext::revive
+    #[msg("Cannot create an account from an AccountInfo ({}) which has been already closed with a tombstone. If your purpose is to revive the account, please use: FankorContext::revive", address)]
     NewFromClosedAccount { address: Pubkey },
 
     /// The account {} is not rent exempt
@@ -295,6 +347,14 @@ pub enum FankorErrorCode {
     #[msg("The account must be the default one")]
     AccountNotDefault,
 
+    /// The compact layout header does not match the instruction accounts
+    #[msg("The compact layout header does not match the instruction accounts")]
+    InvalidCompactLayoutHeader,
+
+    /// The data of the batch instruction is invalid
+    #[msg("The data of the batch instruction is invalid")]
+    InvalidBatchInstruction,
+
     // ------------------------------------------------------------------------
     // CPI --------------------------------------------------------------------
     // ------------------------------------------------------------------------
@@ -352,6 +412,33 @@ pub enum FankorErrorCode {
     /// Cannot move the specified bytes.
     #[msg("Cannot move the specified bytes")]
     ZeroCopyInvalidMove,
+
+    /// The account is already borrowed mutably in zero-copy mode
+    #[msg("The account {} is already borrowed mutably in zero-copy mode", address)]
+    ZeroCopyAlreadyBorrowedMut { address: Pubkey },
+
+    /// The account is already borrowed in zero-copy mode
+    #[msg("The account {} is already borrowed in zero-copy mode", address)]
+    ZeroCopyAlreadyBorrowed { address: Pubkey },
+
+    /// The zero-copy view has been invalidated or does not belong to the account
+    #[msg(
+    "The zero-copy view has been invalidated or does not belong to the account {}",
+    address
+    )]
+    ZeroCopyInvalidView { address: Pubkey },
+
+    /// The zero-copy argument is not part of the instruction data
+    #[msg("The zero-copy argument is not part of the instruction data")]
+    ZeroCopyArgumentOutsideInstructionData,
+
+    /// The index is out of the bounds of the zero-copy type
+    #[msg("The index {} is out of the bounds of the zero-copy type", index)]
+    ZeroCopyInvalidIndex { index: usize },
+
+    /// A fixed string cannot contain null characters
+    #[msg("A fixed string cannot contain null characters")]
+    FixedStringNullCharacter,
 }
 
 // ----------------------------------------------------------------------------
@@ -395,8 +482,11 @@ impl From<borsh::maybestd::io::Error> for Error {
 
 impl From<&dyn std::error::Error> for Error {
     fn from(e: &dyn std::error::Error) -> Self {
+        const ERROR_NAME_HASH: u32 = error_name_hash("Unknown error");
+
         Error::FankorError(FankorError {
             error_name: "Unknown error".to_string(),
+            error_name_hash: ERROR_NAME_HASH,
             error_msg: e.to_string(),
             error_code_number: 5500,
         })
@@ -407,12 +497,19 @@ impl Error {
     pub fn log(&self) {
         match self {
             Error::ProgramError(program_error) => {
-                msg!(
-                    "ProgramError occurred. Error Name: {:?}. Error Code: {}. Error Message: {}.",
-                    program_error,
-                    u64::from(program_error.clone()),
-                    program_error
-                );
+                if crate::MINIMAL_LOGS {
+                    msg!(
+                        "ProgramError occurred. Error Code: {}.",
+                        u64::from(program_error.clone())
+                    );
+                } else {
+                    msg!(
+                        "ProgramError occurred. Error Name: {:?}. Error Code: {}. Error Message: {}.",
+                        program_error,
+                        u64::from(program_error.clone()),
+                        program_error
+                    );
+                }
             }
             Error::FankorError(fankor_error) => fankor_error.log(),
         }
@@ -423,21 +520,35 @@ impl Error {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// An error of the framework or the program.
+///
+/// With the `minimal-logs` feature the name and message of the errors are
+/// left empty to remove them from the binary, so they must be reconstructed
+/// from the code and the name hash.
 #[derive(Debug)]
 pub struct FankorError {
     pub error_name: String,
+    pub error_name_hash: u32,
     pub error_code_number: u32,
     pub error_msg: String,
 }
 
 impl FankorError {
     pub fn log(&self) {
-        msg!(
-            "FankorError occurred. Error Name: {}. Error Code: {}. Error Message: {}.",
-            self.error_name,
-            self.error_code_number,
-            self.error_msg
-        );
+        if crate::MINIMAL_LOGS {
+            msg!(
+                "FankorError occurred. Error Code: {}. Error Name Hash: {}.",
+                self.error_code_number,
+                self.error_name_hash
+            );
+        } else {
+            msg!(
+                "FankorError occurred. Error Name: {}. Error Code: {}. Error Message: {}.",
+                self.error_name,
+                self.error_code_number,
+                self.error_msg
+            );
+        }
     }
 }
 
@@ -460,6 +571,27 @@ impl Eq for FankorError {}
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Hashes the name of an error to identify it in the logs when the
+/// `minimal-logs` feature is enabled. It is the 32-bit FNV-1a hash of the
+/// name.
+pub const fn error_name_hash(name: &str) -> u32 {
+    let bytes = name.as_bytes();
+    let mut hash = 0x811c_9dc5u32;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+
+    hash
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 impl From<Error> for ProgramError {
     fn from(e: Error) -> ProgramError {
         match e {
@@ -12,3 +12,9 @@ pub mod traits;
 #[cfg(feature = "ts-gen")]
 pub mod ts_gen;
 mod utils;
+
+/// Whether the `minimal-logs` feature is enabled, which removes the
+/// instruction logs and the names and messages of the errors from the
+/// programs. The generated code checks this constant instead of the feature
+/// so the logs depend only on the features of fankor.
+pub const MINIMAL_LOGS: bool = cfg!(feature = "minimal-logs");
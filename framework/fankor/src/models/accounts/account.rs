@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 use std::io::Write;
@@ -9,7 +10,7 @@ use solana_program::rent::Rent;
 use solana_program::system_program;
 use solana_program::sysvar::Sysvar;
 
-use crate::errors::{Error, FankorErrorCode, FankorResult};
+use crate::errors::{FankorErrorCode, FankorResult};
 use crate::models::{FankorContext, FankorContextExitAction, Program, System, ZcAccount};
 use crate::prelude::AccountInfoVerification;
 use crate::traits::{AccountType, CopyType, Instruction, PdaChecker, SingleInstructionAccount};
@@ -23,6 +24,8 @@ pub struct Account<'info, T: AccountType> {
     context: &'info FankorContext<'info>,
     info: &'info AccountInfo<'info>,
     data: Box<T>,
+    /// Whether the data may differ from the stored one.
+    dirty: Cell<bool>,
     dropped: bool,
 }
 
@@ -49,7 +52,7 @@ impl<'info, T: AccountType> Account<'info, T> {
         }
 
         // Check it is not closed.
-        if context.is_account_uninitialized(info) {
+        if context.is_account_closed(info) {
             return Err(FankorErrorCode::NewFromClosedAccount { address: *info.key }.into());
         }
 
@@ -57,6 +60,7 @@ impl<'info, T: AccountType> Account<'info, T> {
             context,
             info,
             data: Box::new(data),
+            dirty: Cell::new(true),
             dropped: false,
         })
     }
@@ -70,6 +74,7 @@ impl<'info, T: AccountType> Account<'info, T> {
             context,
             info,
             data: Box::new(data),
+            dirty: Cell::new(true),
             dropped: false,
         }
     }
@@ -112,7 +117,9 @@ impl<'info, T: AccountType> Account<'info, T> {
         &self.data
     }
 
+    /// Gets a mutable reference to the data, marking the account as dirty.
     pub fn data_mut(&mut self) -> &mut T {
+        self.dirty.set(true);
         &mut self.data
     }
 
@@ -141,6 +148,12 @@ impl<'info, T: AccountType> Account<'info, T> {
         self.info.owner == self.context.program_id()
     }
 
+    /// Whether the data may have been modified since it was read or saved,
+    /// i.e. whether it will be saved at the end of the instruction.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
     // METHODS ----------------------------------------------------------------
 
     /// Reloads the account from storage. This is useful, for example, when
@@ -152,10 +165,17 @@ impl<'info, T: AccountType> Account<'info, T> {
             T::deserialize(&mut data)?
         };
         self.data = Box::new(result);
+        self.dirty.set(false);
 
         Ok(())
     }
 
+    /// Marks the account as dirty to force saving it at the end of the
+    /// instruction even if the data has not been accessed mutably.
+    pub fn mark_dirty(&self) {
+        self.dirty.set(true);
+    }
+
     /// Saves the account changes into the storage. This is useful, for example,
     /// to expose new content before a CPI.
     pub fn save(&self) -> FankorResult<()> {
@@ -175,7 +195,7 @@ impl<'info, T: AccountType> Account<'info, T> {
             .into());
         }
 
-        if self.context.is_account_uninitialized(self.info) {
+        if self.context.is_account_closed(self.info) {
             return Err(FankorErrorCode::AlreadyClosedAccount {
                 address: *self.address(),
                 action: "write",
@@ -188,12 +208,32 @@ impl<'info, T: AccountType> Account<'info, T> {
         let mut writer = ArrayWriter::new(dst);
         self.data.serialize(&mut writer)?;
 
+        self.dirty.set(false);
+
         Ok(())
     }
 
     /// Closes the account and sends the lamports to the `destination_account`.
-    pub fn close(mut self, destination_account: &AccountInfo<'info>) -> FankorResult<()> {
-        close_account(self.info, self.context(), destination_account)?;
+    pub fn close(self, destination_account: &AccountInfo<'info>) -> FankorResult<()> {
+        self._close(destination_account, false)
+    }
+
+    /// Closes the account and sends the lamports to the `destination_account`
+    /// leaving a tombstone in its data, so it cannot be loaded again even if
+    /// it is refunded in the same transaction.
+    pub fn close_with_tombstone(
+        self,
+        destination_account: &AccountInfo<'info>,
+    ) -> FankorResult<()> {
+        self._close(destination_account, true)
+    }
+
+    fn _close(
+        mut self,
+        destination_account: &AccountInfo<'info>,
+        tombstone: bool,
+    ) -> FankorResult<()> {
+        close_account(self.info, self.context(), destination_account, tombstone)?;
 
         // Prevent account to execute the drop actions.
         self.dropped = true;
@@ -229,7 +269,7 @@ impl<'info, T: AccountType> Account<'info, T> {
             .into());
         }
 
-        if self.context.is_account_uninitialized(self.info) {
+        if self.context.is_account_closed(self.info) {
             return Err(FankorErrorCode::AlreadyClosedAccount {
                 address: *self.address(),
                 action: "reallocate",
@@ -281,7 +321,7 @@ impl<'info, T: AccountType> Account<'info, T> {
             .into());
         }
 
-        if self.context.is_account_uninitialized(self.info) {
+        if self.context.is_account_closed(self.info) {
             return Err(FankorErrorCode::AlreadyClosedAccount {
                 address: *self.address(),
                 action: "make rent-exempt",
@@ -323,7 +363,7 @@ impl<'info, T: AccountType> Account<'info, T> {
             .into());
         }
 
-        if self.context.is_account_uninitialized(self.info) {
+        if self.context.is_account_closed(self.info) {
             return Err(FankorErrorCode::AlreadyClosedAccount {
                 address: *self.address(),
                 action: "transmute",
@@ -384,7 +424,7 @@ impl<'info, T: AccountType> Account<'info, T> {
             .into());
         }
 
-        if self.context.is_account_uninitialized(self.info) {
+        if self.context.is_account_closed(self.info) {
             return Err(FankorErrorCode::AlreadyClosedAccount {
                 address: *self.address(),
                 action: "reallocate",
@@ -412,6 +452,25 @@ impl<'info, T: AccountType> Account<'info, T> {
     pub fn close_account_at_exit(
         &self,
         destination_account: &'info AccountInfo<'info>,
+    ) -> FankorResult<()> {
+        self._close_account_at_exit(destination_account, false)
+    }
+
+    /// Closes the account at the end of the instruction sending the lamports to
+    /// the `destination_account` account and leaving a tombstone in its data.
+    ///
+    /// This replaces other exit actions associated with this account.
+    pub fn close_account_with_tombstone_at_exit(
+        &self,
+        destination_account: &'info AccountInfo<'info>,
+    ) -> FankorResult<()> {
+        self._close_account_at_exit(destination_account, true)
+    }
+
+    fn _close_account_at_exit(
+        &self,
+        destination_account: &'info AccountInfo<'info>,
+        tombstone: bool,
     ) -> FankorResult<()> {
         if !self.is_owned_by_program() {
             return Err(FankorErrorCode::AccountNotOwnedByProgram {
@@ -434,6 +493,7 @@ impl<'info, T: AccountType> Account<'info, T> {
             self.info,
             FankorContextExitAction::Close {
                 destination_account,
+                tombstone,
             },
         );
 
@@ -529,7 +589,7 @@ impl<'info, T: AccountType + CopyType<'info>> Account<'info, T> {
             .into());
         }
 
-        if self.context.is_account_uninitialized(self.info) {
+        if self.context.is_account_closed(self.info) {
             return Err(FankorErrorCode::AlreadyClosedAccount {
                 address: *self.address(),
                 action: "make rent-exempt",
@@ -553,6 +613,23 @@ impl<'info, T: AccountType> Instruction<'info> for Account<'info, T> {
         config.verify(self.info)
     }
 
+    fn exit(&mut self, _context: &'info FankorContext<'info>) -> FankorResult<()> {
+        // Ignore already exited accounts.
+        if self.dropped {
+            return Ok(());
+        }
+
+        // Prevent account to execute the drop actions.
+        self.dropped = true;
+
+        // Ignore if not owned by program.
+        if !self.is_owned_by_program() {
+            return Ok(());
+        }
+
+        drop_aux(self)
+    }
+
     #[inline(never)]
     fn try_from(
         context: &'info FankorContext<'info>,
@@ -577,8 +654,14 @@ impl<'info, T: AccountType> Instruction<'info> for Account<'info, T> {
             .into());
         }
 
+        // Check it is not closed.
+        if context.is_account_tombstoned(info) {
+            return Err(FankorErrorCode::NewFromClosedAccount { address: *info.key }.into());
+        }
+
         let mut data: &[u8] = &info.try_borrow_data()?;
         let result = Account::new_unchecked(context, info, T::deserialize(&mut data)?);
+        result.dirty.set(false);
 
         *accounts = &accounts[1..];
         Ok(result)
@@ -620,8 +703,10 @@ impl<'info, T: AccountType> Drop for Account<'info, T> {
             return;
         }
 
+        // Fallback for the accounts not exited by the exit phase of the
+        // instruction, whose error is returned by the context.
         if let Err(e) = drop_aux(self) {
-            crate::macros::panic_error!(e);
+            self.context.set_exit_error(e);
         }
     }
 }
@@ -631,15 +716,18 @@ fn drop_aux<T: AccountType>(account: &mut Account<T>) -> FankorResult<()> {
         None => {
             // Ignore if not writable or non from current program.
             if account.is_writable() && account.is_owned_by_program() {
-                // Write the data.
-                account.save()?;
-
-                // Prevent not rent exempt.
-                if !account.is_rent_exempt() {
-                    return Err(FankorErrorCode::AccountNotRentExempt {
-                        account: *account.address(),
+                // Skip clean accounts because their data is already stored.
+                if account.is_dirty() {
+                    // Write the data.
+                    account.save()?;
+
+                    // Prevent not rent exempt.
+                    if !account.is_rent_exempt() {
+                        return Err(FankorErrorCode::AccountNotRentExempt {
+                            account: *account.address(),
+                        }
+                        .into());
                     }
-                    .into());
                 }
 
                 // Prevent executing this action twice.
@@ -686,8 +774,14 @@ fn drop_aux<T: AccountType>(account: &mut Account<T>) -> FankorResult<()> {
         }
         Some(FankorContextExitAction::Close {
             destination_account,
+            tombstone,
         }) => {
-            close_account(account.info(), account.context(), destination_account)?;
+            close_account(
+                account.info(),
+                account.context(),
+                destination_account,
+                tombstone,
+            )?;
 
             // Prevent executing this action twice.
             account
@@ -698,3 +792,255 @@ fn drop_aux<T: AccountType>(account: &mut Account<T>) -> FankorResult<()> {
 
     Ok(())
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use solana_program::entrypoint::SUCCESS;
+    use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+
+    use crate::tests::create_account_info_for_tests;
+    use crate::utils::close::CLOSED_ACCOUNT_TOMBSTONE;
+
+    use super::*;
+
+    const OWNER: Pubkey = Pubkey::new_from_array([0u8; 32]);
+
+    #[derive(BorshSerialize, BorshDeserialize)]
+    struct TestAccount {
+        value: u32,
+    }
+
+    impl AccountType for TestAccount {
+        fn discriminant() -> u8 {
+            0
+        }
+
+        fn owner() -> &'static Pubkey {
+            &OWNER
+        }
+    }
+
+    /// Provides the Rent sysvar required to check that the saved accounts are
+    /// rent-exempt.
+    struct RentSyscallStubs;
+
+    impl SyscallStubs for RentSyscallStubs {
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe {
+                *(var_addr as *mut Rent) = Rent::default();
+            }
+
+            SUCCESS
+        }
+    }
+
+    fn load<'info>(
+        context: &'info FankorContext<'info>,
+        accounts: &'info [AccountInfo<'info>],
+    ) -> Account<'info, TestAccount> {
+        let mut accounts = accounts;
+        <Account<TestAccount> as Instruction>::try_from(context, &mut &[][..], &mut accounts)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_clean_account_is_not_serialized() {
+        let mut lamports = 1_000_000_000;
+        let mut vector = vec![5, 0, 0, 0];
+        let mut info = create_account_info_for_tests(&mut lamports, &mut vector);
+        info.is_writable = true;
+        let accounts = [info];
+        let context = FankorContext::new_unchecked(&OWNER, &accounts);
+
+        let mut account = load(&context, &accounts);
+        assert!(!account.is_dirty());
+        assert_eq!(account.data().value, 5);
+
+        // A write would restore the loaded value.
+        accounts[0].data.borrow_mut()[0] = 9;
+
+        account.exit(&context).unwrap();
+        assert_eq!(accounts[0].data.borrow()[..], [9, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_data_mut_forces_write() {
+        set_syscall_stubs(Box::new(RentSyscallStubs));
+
+        let mut lamports = 1_000_000_000;
+        let mut vector = vec![5, 0, 0, 0];
+        let mut info = create_account_info_for_tests(&mut lamports, &mut vector);
+        info.is_writable = true;
+        let accounts = [info];
+        let context = FankorContext::new_unchecked(&OWNER, &accounts);
+
+        let mut account = load(&context, &accounts);
+        account.data_mut().value = 7;
+        assert!(account.is_dirty());
+
+        account.exit(&context).unwrap();
+        assert_eq!(accounts[0].data.borrow()[..], [7, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_mark_dirty_forces_write() {
+        set_syscall_stubs(Box::new(RentSyscallStubs));
+
+        let mut lamports = 1_000_000_000;
+        let mut vector = vec![5, 0, 0, 0];
+        let mut info = create_account_info_for_tests(&mut lamports, &mut vector);
+        info.is_writable = true;
+        let accounts = [info];
+        let context = FankorContext::new_unchecked(&OWNER, &accounts);
+
+        let mut account = load(&context, &accounts);
+        accounts[0].data.borrow_mut()[0] = 9;
+        account.mark_dirty();
+        assert!(account.is_dirty());
+
+        account.exit(&context).unwrap();
+        assert_eq!(accounts[0].data.borrow()[..], [5, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_reload_and_save_reset_dirty() {
+        let mut lamports = 1_000_000_000;
+        let mut vector = vec![5, 0, 0, 0];
+        let mut info = create_account_info_for_tests(&mut lamports, &mut vector);
+        info.is_writable = true;
+        let accounts = [info];
+        let context = FankorContext::new_unchecked(&OWNER, &accounts);
+
+        let mut account = load(&context, &accounts);
+        account.data_mut().value = 7;
+        assert!(account.is_dirty());
+
+        account.save().unwrap();
+        assert!(!account.is_dirty());
+        assert_eq!(accounts[0].data.borrow()[..], [7, 0, 0, 0]);
+
+        accounts[0].data.borrow_mut()[0] = 9;
+        account.mark_dirty();
+        assert!(account.is_dirty());
+
+        account.reload().unwrap();
+        assert!(!account.is_dirty());
+        assert_eq!(account.data().value, 9);
+
+        // Nothing left to write.
+        account.exit(&context).unwrap();
+        assert_eq!(accounts[0].data.borrow()[..], [9, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_tombstoned_account_is_rejected() {
+        let mut lamports = 1_000_000_000;
+        let mut vector = vec![0u8; 12];
+        vector[..8].copy_from_slice(&CLOSED_ACCOUNT_TOMBSTONE);
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let accounts = [info];
+        let context = FankorContext::new_unchecked(&OWNER, &accounts);
+
+        let result = <Account<TestAccount> as Instruction>::try_from(
+            &context,
+            &mut &[][..],
+            &mut &accounts[..],
+        );
+        assert_eq!(
+            result.err(),
+            Some(FankorErrorCode::NewFromClosedAccount { address: OWNER }.into())
+        );
+    }
+
+    #[test]
+    fn test_close_with_tombstone() {
+        let mut lamports = 1_000_000_000;
+        let mut vector = vec![5, 0, 0, 0, 0, 0, 0, 0];
+        let mut destination_lamports = 0;
+        let mut destination_vector = vec![];
+        let mut info = create_account_info_for_tests(&mut lamports, &mut vector);
+        info.is_writable = true;
+        let accounts = [
+            info,
+            create_account_info_for_tests(&mut destination_lamports, &mut destination_vector),
+        ];
+        let context = FankorContext::new_unchecked(&OWNER, &accounts);
+
+        let account = load(&context, &accounts);
+        account.close_with_tombstone(&accounts[1]).unwrap();
+
+        assert_eq!(accounts[0].lamports(), 0);
+        assert_eq!(accounts[1].lamports(), 1_000_000_000);
+        assert_eq!(accounts[0].owner, &OWNER);
+        assert_eq!(accounts[0].data.borrow()[..], CLOSED_ACCOUNT_TOMBSTONE);
+
+        // It cannot be loaded again even if it is refunded.
+        **accounts[0].lamports.borrow_mut() = 1_000_000_000;
+
+        let result = <Account<TestAccount> as Instruction>::try_from(
+            &context,
+            &mut &[][..],
+            &mut &accounts[..1],
+        );
+        assert_eq!(
+            result.err(),
+            Some(FankorErrorCode::NewFromClosedAccount { address: OWNER }.into())
+        );
+    }
+
+    #[test]
+    fn test_revive() {
+        set_syscall_stubs(Box::new(RentSyscallStubs));
+
+        let mut lamports = 1_000_000_000;
+        let mut vector = vec![0u8; 8];
+        vector.copy_from_slice(&CLOSED_ACCOUNT_TOMBSTONE);
+        let mut payer_lamports = 1_000_000_000;
+        let mut payer_vector = vec![];
+        let mut program_lamports = 0;
+        let mut program_vector = vec![];
+        let mut info = create_account_info_for_tests(&mut lamports, &mut vector);
+        info.is_writable = true;
+        let mut payer = create_account_info_for_tests(&mut payer_lamports, &mut payer_vector);
+        payer.is_writable = true;
+        let mut program = create_account_info_for_tests(&mut program_lamports, &mut program_vector);
+        program.key = &system_program::ID;
+        program.executable = true;
+        let accounts = [info, payer, program];
+        let context = FankorContext::new_unchecked(&OWNER, &accounts);
+        let system_program = Program::<System>::new(&context, &accounts[2]).unwrap();
+
+        let account = context
+            .revive(
+                &accounts[0],
+                TestAccount { value: 7 },
+                &accounts[1],
+                &system_program,
+            )
+            .unwrap();
+        assert_eq!(account.data().value, 7);
+        assert_eq!(accounts[0].data.borrow()[..4], [7, 0, 0, 0]);
+        assert!(!context.is_account_tombstoned(&accounts[0]));
+
+        // It can be loaded again.
+        let account = load(&context, &accounts[..1]);
+        assert_eq!(account.data().value, 7);
+
+        // Only closed accounts can be revived.
+        let result = context.revive(
+            &accounts[0],
+            TestAccount { value: 8 },
+            &accounts[1],
+            &system_program,
+        );
+        assert_eq!(
+            result.err(),
+            Some(FankorErrorCode::AccountAlreadyInitialized { address: OWNER }.into())
+        );
+    }
+}
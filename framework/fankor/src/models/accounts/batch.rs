@@ -0,0 +1,170 @@
+use std::io::Write;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::FnkUInt;
+
+/// An instruction inside the data of the `Batch` instruction generated by
+/// `#[program(batch)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchEntry<'a> {
+    pub discriminant: u8,
+    pub data: &'a [u8],
+    pub account_count: usize,
+}
+
+/// Reads the instructions of the `Batch` instruction generated by
+/// `#[program(batch)]`.
+///
+/// ## Encoding
+///
+/// - The number of instructions as a [`FnkUInt`].
+/// - For every instruction: its discriminant as a byte, the length of its data
+///   as a [`FnkUInt`] followed by the data, and the number of its accounts as
+///   a [`FnkUInt`].
+#[derive(Debug, Clone)]
+pub struct BatchReader<'a> {
+    buf: &'a [u8],
+    remaining: u64,
+}
+
+impl<'a> BatchReader<'a> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Reads the number of instructions from the beginning of `buf`.
+    pub fn read(buf: &'a [u8]) -> FankorResult<Self> {
+        let mut buf = buf;
+        let remaining = FnkUInt::deserialize(&mut buf)?.0;
+
+        Ok(BatchReader { buf, remaining })
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Reads the next instruction or `None` if all of them have been read.
+    pub fn next_entry(&mut self) -> FankorResult<Option<BatchEntry<'a>>> {
+        if self.remaining == 0 {
+            if !self.buf.is_empty() {
+                return Err(FankorErrorCode::InvalidBatchInstruction.into());
+            }
+
+            return Ok(None);
+        }
+
+        let discriminant = u8::deserialize(&mut self.buf)?;
+        let length = FnkUInt::deserialize(&mut self.buf)?.0 as usize;
+
+        if self.buf.len() < length {
+            return Err(FankorErrorCode::InvalidBatchInstruction.into());
+        }
+
+        let data = &self.buf[..length];
+        self.buf = &self.buf[length..];
+
+        let account_count = FnkUInt::deserialize(&mut self.buf)?.0 as usize;
+        self.remaining -= 1;
+
+        Ok(Some(BatchEntry {
+            discriminant,
+            data,
+            account_count,
+        }))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Collects the instructions of the `Batch` instruction generated by
+/// `#[program(batch)]`. See [`BatchReader`] for the encoding.
+#[derive(Debug, Default, Clone)]
+pub struct BatchWriter {
+    entries: Vec<u8>,
+    entry_count: usize,
+}
+
+impl BatchWriter {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn write_entry(
+        &mut self,
+        discriminant: u8,
+        data: &[u8],
+        account_count: usize,
+    ) -> FankorResult<()> {
+        self.entries.push(discriminant);
+        FnkUInt::new(data.len() as u64).serialize(&mut self.entries)?;
+        self.entries.extend_from_slice(data);
+        FnkUInt::new(account_count as u64).serialize(&mut self.entries)?;
+        self.entry_count += 1;
+
+        Ok(())
+    }
+
+    /// Writes the instructions into `writer`.
+    pub fn write_into<W: Write>(&self, writer: &mut W) -> FankorResult<()> {
+        FnkUInt::new(self.entry_count as u64).serialize(writer)?;
+        writer.write_all(&self.entries)?;
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_write_read() {
+        let mut writer = BatchWriter::new();
+        writer.write_entry(3, &[1, 2], 2).unwrap();
+        writer.write_entry(5, &[], 0).unwrap();
+
+        let mut buffer = Vec::new();
+        writer.write_into(&mut buffer).unwrap();
+
+        assert_eq!(buffer, vec![2, 3, 2, 1, 2, 2, 5, 0, 0]);
+
+        let mut reader = BatchReader::read(&buffer).unwrap();
+        assert_eq!(
+            reader.next_entry().unwrap(),
+            Some(BatchEntry {
+                discriminant: 3,
+                data: &[1, 2],
+                account_count: 2,
+            })
+        );
+        assert_eq!(
+            reader.next_entry().unwrap(),
+            Some(BatchEntry {
+                discriminant: 5,
+                data: &[],
+                account_count: 0,
+            })
+        );
+        assert_eq!(reader.next_entry().unwrap(), None);
+
+        // Trailing data.
+        buffer.push(0);
+        let mut reader = BatchReader::read(&buffer).unwrap();
+        reader.next_entry().unwrap();
+        reader.next_entry().unwrap();
+        assert!(reader.next_entry().is_err());
+
+        // Not enough data.
+        let mut reader = BatchReader::read(&[1, 3, 2, 1]).unwrap();
+        assert!(reader.next_entry().is_err());
+    }
+}
@@ -4,7 +4,7 @@ use solana_program::account_info::AccountInfo;
 use solana_program::instruction::AccountMeta;
 
 use crate::errors::FankorResult;
-use crate::models::FankorContext;
+use crate::models::{CompactLayoutReader, CompactLayoutWriter, FankorContext};
 use crate::traits::{
     AccountInfoVerification, CpiInstruction, Instruction, LpiInstruction, PdaChecker,
     SingleInstructionAccount,
@@ -21,6 +21,10 @@ impl<'info, T: Instruction<'info>> Instruction<'info> for Box<T> {
         T::verify_account_infos(self, config)
     }
 
+    fn exit(&mut self, context: &'info FankorContext<'info>) -> FankorResult<()> {
+        T::exit(self, context)
+    }
+
     #[inline(never)]
     fn try_from(
         context: &'info FankorContext<'info>,
@@ -29,6 +33,18 @@ impl<'info, T: Instruction<'info>> Instruction<'info> for Box<T> {
     ) -> FankorResult<Self> {
         Ok(Box::new(T::try_from(context, buf, accounts)?))
     }
+
+    #[inline(never)]
+    fn try_from_compact(
+        context: &'info FankorContext<'info>,
+        header: &mut CompactLayoutReader,
+        buf: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        Ok(Box::new(T::try_from_compact(
+            context, header, buf, accounts,
+        )?))
+    }
 }
 
 impl<'info, T: SingleInstructionAccount<'info>> SingleInstructionAccount<'info> for Box<T> {
@@ -63,6 +79,16 @@ impl<'info, T: CpiInstruction<'info>> CpiInstruction<'info> for Box<T> {
     ) -> FankorResult<()> {
         T::serialize_into_instruction_parts(self, writer, metas, infos)
     }
+
+    fn serialize_into_instruction_parts_compact<W: Write>(
+        &self,
+        header: &mut CompactLayoutWriter,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+        infos: &mut Vec<AccountInfo<'info>>,
+    ) -> FankorResult<()> {
+        T::serialize_into_instruction_parts_compact(self, header, writer, metas, infos)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -77,4 +103,13 @@ impl<T: LpiInstruction> LpiInstruction for Box<T> {
     ) -> FankorResult<()> {
         T::serialize_into_instruction_parts(self, writer, metas)
     }
+
+    fn serialize_into_instruction_parts_compact<W: Write>(
+        &self,
+        header: &mut CompactLayoutWriter,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+    ) -> FankorResult<()> {
+        T::serialize_into_instruction_parts_compact(self, header, writer, metas)
+    }
 }
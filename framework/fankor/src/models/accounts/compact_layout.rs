@@ -0,0 +1,235 @@
+use std::io::Write;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::FnkUInt;
+
+/// Reads the structural data of an instruction declared with
+/// `#[instruction(compact_layout)]`, i.e. the flags of `Option` and `Either`
+/// and the lengths of `Vec`, from the header that precedes its data.
+///
+/// ## Encoding
+///
+/// - The number of flags as a [`FnkUInt`] followed by the flags packed as a
+///   little-endian bitmask.
+/// - The number of lengths as a [`FnkUInt`] followed by every length as a
+///   [`FnkUInt`].
+#[derive(Debug, Clone)]
+pub struct CompactLayoutReader<'a> {
+    flags: &'a [u8],
+    flag_count: usize,
+    flag_index: usize,
+    lengths: &'a [u8],
+}
+
+impl<'a> CompactLayoutReader<'a> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Reads the header from the beginning of `buf`, leaving the rest of the
+    /// instruction data in it.
+    pub fn read(buf: &mut &'a [u8]) -> FankorResult<Self> {
+        let flag_count = usize::try_from(FnkUInt::deserialize(buf)?.0)
+            .map_err(|_| FankorErrorCode::InvalidCompactLayoutHeader)?;
+
+        if flag_count > buf.len().saturating_mul(8) {
+            return Err(FankorErrorCode::NotEnoughDataToDeserializeInstruction.into());
+        }
+
+        let flag_bytes = (flag_count + 7) / 8;
+        let flags = &buf[..flag_bytes];
+
+        // The padding bits of the last byte must be zero for the encoding to
+        // be canonical.
+        if flag_count % 8 != 0 && flags[flag_bytes - 1] >> (flag_count % 8) != 0 {
+            return Err(FankorErrorCode::InvalidCompactLayoutHeader.into());
+        }
+
+        *buf = &buf[flag_bytes..];
+
+        let length_count = FnkUInt::deserialize(buf)?.0;
+        let lengths_start = *buf;
+
+        for _ in 0..length_count {
+            FnkUInt::deserialize(buf)?;
+        }
+
+        let lengths = &lengths_start[..lengths_start.len() - buf.len()];
+
+        Ok(CompactLayoutReader {
+            flags,
+            flag_count,
+            flag_index: 0,
+            lengths,
+        })
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn read_flag(&mut self) -> FankorResult<bool> {
+        if self.flag_index >= self.flag_count {
+            return Err(FankorErrorCode::InvalidCompactLayoutHeader.into());
+        }
+
+        let byte = self.flags[self.flag_index / 8];
+        let flag = byte & (1 << (self.flag_index % 8)) != 0;
+        self.flag_index += 1;
+
+        Ok(flag)
+    }
+
+    pub fn read_length(&mut self) -> FankorResult<usize> {
+        if self.lengths.is_empty() {
+            return Err(FankorErrorCode::InvalidCompactLayoutHeader.into());
+        }
+
+        let length = FnkUInt::deserialize(&mut self.lengths)?;
+
+        usize::try_from(length.0).map_err(|_| FankorErrorCode::InvalidCompactLayoutHeader.into())
+    }
+
+    /// Checks that all the structural data of the header has been read.
+    pub fn finish(&self) -> FankorResult<()> {
+        if self.flag_index != self.flag_count || !self.lengths.is_empty() {
+            return Err(FankorErrorCode::InvalidCompactLayoutHeader.into());
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Collects the structural data of an instruction declared with
+/// `#[instruction(compact_layout)]` to write it as the header of its data.
+/// See [`CompactLayoutReader`] for the encoding.
+#[derive(Debug, Default, Clone)]
+pub struct CompactLayoutWriter {
+    flags: Vec<u8>,
+    flag_count: usize,
+    lengths: Vec<u8>,
+    length_count: usize,
+}
+
+impl CompactLayoutWriter {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn write_flag(&mut self, flag: bool) {
+        if self.flag_count % 8 == 0 {
+            self.flags.push(0);
+        }
+
+        if flag {
+            *self.flags.last_mut().unwrap() |= 1 << (self.flag_count % 8);
+        }
+
+        self.flag_count += 1;
+    }
+
+    pub fn write_length(&mut self, length: usize) -> FankorResult<()> {
+        FnkUInt::new(length as u64).serialize(&mut self.lengths)?;
+        self.length_count += 1;
+
+        Ok(())
+    }
+
+    /// Writes the header into `writer`.
+    pub fn write_into<W: Write>(&self, writer: &mut W) -> FankorResult<()> {
+        FnkUInt::new(self.flag_count as u64).serialize(writer)?;
+        writer.write_all(&self.flags)?;
+        FnkUInt::new(self.length_count as u64).serialize(writer)?;
+        writer.write_all(&self.lengths)?;
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::errors::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_write_read() {
+        let mut writer = CompactLayoutWriter::new();
+
+        for i in 0..10 {
+            writer.write_flag(i % 3 == 0);
+        }
+
+        writer.write_length(3).unwrap();
+        writer.write_length(200).unwrap();
+
+        let mut buffer = Vec::new();
+        writer.write_into(&mut buffer).unwrap();
+        buffer.extend_from_slice(&[1, 2, 3]);
+
+        assert_eq!(
+            buffer,
+            vec![10, 0b0100_1001, 0b0000_0010, 2, 3, 0x48, 3, 1, 2, 3]
+        );
+
+        let mut buf = buffer.as_slice();
+        let mut reader = CompactLayoutReader::read(&mut buf).unwrap();
+        assert_eq!(buf, &[1, 2, 3]);
+
+        for i in 0..10 {
+            assert_eq!(reader.read_flag().unwrap(), i % 3 == 0);
+        }
+
+        assert!(reader.finish().is_err());
+        assert_eq!(reader.read_length().unwrap(), 3);
+        assert_eq!(reader.read_length().unwrap(), 200);
+        assert!(reader.read_flag().is_err());
+        assert!(reader.read_length().is_err());
+        reader.finish().unwrap();
+    }
+
+    #[test]
+    fn test_read_flag_count_beyond_data() {
+        let mut buffer = Vec::new();
+        FnkUInt::new(u64::MAX).serialize(&mut buffer).unwrap();
+        buffer.extend_from_slice(&[0xFF; 8]);
+
+        let mut buf = buffer.as_slice();
+        assert_eq!(
+            CompactLayoutReader::read(&mut buf).unwrap_err(),
+            Error::from(FankorErrorCode::NotEnoughDataToDeserializeInstruction)
+        );
+
+        // 9 flags need 2 bytes.
+        let mut buf: &[u8] = &[9, 0xFF];
+        assert_eq!(
+            CompactLayoutReader::read(&mut buf).unwrap_err(),
+            Error::from(FankorErrorCode::NotEnoughDataToDeserializeInstruction)
+        );
+    }
+
+    #[test]
+    fn test_read_non_zero_padding() {
+        let mut buf: &[u8] = &[3, 0b0000_0111, 0];
+        assert!(CompactLayoutReader::read(&mut buf).is_ok());
+
+        let mut buf: &[u8] = &[3, 0b0000_1111, 0];
+        assert_eq!(
+            CompactLayoutReader::read(&mut buf).unwrap_err(),
+            Error::from(FankorErrorCode::InvalidCompactLayoutHeader)
+        );
+
+        let mut buf: &[u8] = &[8, 0xFF, 0];
+        assert!(CompactLayoutReader::read(&mut buf).is_ok());
+    }
+}
@@ -7,7 +7,7 @@ use solana_program::account_info::AccountInfo;
 use solana_program::instruction::AccountMeta;
 
 use crate::errors::{FankorErrorCode, FankorResult};
-use crate::models::FankorContext;
+use crate::models::{CompactLayoutReader, CompactLayoutWriter, FankorContext};
 use crate::prelude::PdaChecker;
 use crate::traits::{
     AccountInfoVerification, CpiInstruction, Instruction, SingleInstructionAccount,
@@ -89,6 +89,13 @@ impl<'info, L: Instruction<'info>, R: Instruction<'info>> Instruction<'info> for
         }
     }
 
+    fn exit(&mut self, context: &'info FankorContext<'info>) -> FankorResult<()> {
+        match self {
+            Either::Left(v) => v.exit(context),
+            Either::Right(v) => v.exit(context),
+        }
+    }
+
     #[inline(never)]
     fn try_from(
         context: &'info FankorContext<'info>,
@@ -115,6 +122,22 @@ impl<'info, L: Instruction<'info>, R: Instruction<'info>> Instruction<'info> for
 
         Ok(result)
     }
+
+    #[inline(never)]
+    fn try_from_compact(
+        context: &'info FankorContext<'info>,
+        header: &mut CompactLayoutReader,
+        buf: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        let result = if header.read_flag()? {
+            Either::Right(R::try_from_compact(context, header, buf, accounts)?)
+        } else {
+            Either::Left(L::try_from_compact(context, header, buf, accounts)?)
+        };
+
+        Ok(result)
+    }
 }
 
 impl<'info, L: SingleInstructionAccount<'info>, R: SingleInstructionAccount<'info>>
@@ -190,6 +213,25 @@ impl<'info, L: CpiInstruction<'info>, R: CpiInstruction<'info>> CpiInstruction<'
             }
         }
     }
+
+    fn serialize_into_instruction_parts_compact<W: Write>(
+        &self,
+        header: &mut CompactLayoutWriter,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+        infos: &mut Vec<AccountInfo<'info>>,
+    ) -> FankorResult<()> {
+        match self {
+            Self::Left(v) => {
+                header.write_flag(false);
+                v.serialize_into_instruction_parts_compact(header, writer, metas, infos)
+            }
+            Self::Right(v) => {
+                header.write_flag(true);
+                v.serialize_into_instruction_parts_compact(header, writer, metas, infos)
+            }
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -220,4 +262,22 @@ impl<L: crate::traits::LpiInstruction, R: crate::traits::LpiInstruction>
             }
         }
     }
+
+    fn serialize_into_instruction_parts_compact<W: Write>(
+        &self,
+        header: &mut CompactLayoutWriter,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+    ) -> FankorResult<()> {
+        match self {
+            Self::Left(v) => {
+                header.write_flag(false);
+                v.serialize_into_instruction_parts_compact(header, writer, metas)
+            }
+            Self::Right(v) => {
+                header.write_flag(true);
+                v.serialize_into_instruction_parts_compact(header, writer, metas)
+            }
+        }
+    }
 }
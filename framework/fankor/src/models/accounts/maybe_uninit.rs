@@ -85,6 +85,13 @@ impl<'info, T: Instruction<'info>> Instruction<'info> for MaybeUninitialized<'in
         }
     }
 
+    fn exit(&mut self, context: &'info FankorContext<'info>) -> FankorResult<()> {
+        match self {
+            Self::Init(v) => v.exit(context),
+            Self::Uninit(v) => v.exit(context),
+        }
+    }
+
     #[inline(never)]
     fn try_from(
         context: &'info FankorContext<'info>,
@@ -1,6 +1,8 @@
 pub use account::*;
 pub use argument::*;
+pub use batch::*;
 pub use boxed::*;
+pub use compact_layout::*;
 pub use either::*;
 pub use maybe_uninit::*;
 pub use option::*;
@@ -13,10 +15,14 @@ pub use unchecked_account::*;
 pub use uninitialized::*;
 pub use vector::*;
 pub use zero_copy::*;
+pub use zero_copy_argument::*;
+pub use zero_copy_guards::*;
 
 mod account;
 mod argument;
+mod batch;
 mod boxed;
+mod compact_layout;
 mod either;
 mod maybe_uninit;
 mod option;
@@ -29,3 +35,5 @@ mod unchecked_account;
 mod uninitialized;
 mod vector;
 mod zero_copy;
+mod zero_copy_argument;
+mod zero_copy_guards;
@@ -5,7 +5,7 @@ use solana_program::account_info::AccountInfo;
 use solana_program::instruction::AccountMeta;
 
 use crate::errors::{FankorErrorCode, FankorResult};
-use crate::models::FankorContext;
+use crate::models::{CompactLayoutReader, CompactLayoutWriter, FankorContext};
 use crate::traits::{
     AccountInfoVerification, CpiInstruction, Instruction, LpiInstruction, PdaChecker,
 };
@@ -24,6 +24,13 @@ impl<'info, T: Instruction<'info>> Instruction<'info> for Option<T> {
         }
     }
 
+    fn exit(&mut self, context: &'info FankorContext<'info>) -> FankorResult<()> {
+        match self {
+            Some(account) => account.exit(context),
+            None => Ok(()),
+        }
+    }
+
     #[inline(never)]
     fn try_from(
         context: &'info FankorContext<'info>,
@@ -50,6 +57,22 @@ impl<'info, T: Instruction<'info>> Instruction<'info> for Option<T> {
 
         Ok(result)
     }
+
+    #[inline(never)]
+    fn try_from_compact(
+        context: &'info FankorContext<'info>,
+        header: &mut CompactLayoutReader,
+        buf: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        let result = if header.read_flag()? {
+            Some(T::try_from_compact(context, header, buf, accounts)?)
+        } else {
+            None
+        };
+
+        Ok(result)
+    }
 }
 
 impl<'info, T: PdaChecker<'info>> PdaChecker<'info> for Option<T> {
@@ -77,6 +100,22 @@ impl<'info, T: CpiInstruction<'info>> CpiInstruction<'info> for Option<T> {
 
         Ok(())
     }
+
+    fn serialize_into_instruction_parts_compact<W: Write>(
+        &self,
+        header: &mut CompactLayoutWriter,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+        infos: &mut Vec<AccountInfo<'info>>,
+    ) -> FankorResult<()> {
+        header.write_flag(self.is_some());
+
+        if let Some(v) = self {
+            v.serialize_into_instruction_parts_compact(header, writer, metas, infos)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<T: LpiInstruction> LpiInstruction for Option<T> {
@@ -94,4 +133,19 @@ impl<T: LpiInstruction> LpiInstruction for Option<T> {
 
         Ok(())
     }
+
+    fn serialize_into_instruction_parts_compact<W: Write>(
+        &self,
+        header: &mut CompactLayoutWriter,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+    ) -> FankorResult<()> {
+        header.write_flag(self.is_some());
+
+        if let Some(v) = self {
+            v.serialize_into_instruction_parts_compact(header, writer, metas)?;
+        }
+
+        Ok(())
+    }
 }
@@ -4,9 +4,10 @@ use std::marker::PhantomData;
 
 use solana_program::account_info::AccountInfo;
 use solana_program::clock::Epoch;
+use solana_program::instruction::Instruction as SolanaInstruction;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::{FankorErrorCode, FankorResult};
+use crate::errors::{Error, FankorErrorCode, FankorResult};
 use crate::models::FankorContext;
 use crate::traits::{
     AccountInfoVerification, Instruction, PdaChecker, ProgramType, SingleInstructionAccount,
@@ -17,6 +18,7 @@ use crate::traits::{
 pub struct Program<'info, T: ProgramType> {
     context: &'info FankorContext<'info>,
     info: &'info AccountInfo<'info>,
+    sign_with_pda_seeds: bool,
     _data: PhantomData<T>,
 }
 
@@ -43,6 +45,7 @@ impl<'info, T: ProgramType> Program<'info, T> {
         Ok(Program {
             context,
             info,
+            sign_with_pda_seeds: false,
             _data: PhantomData,
         })
     }
@@ -84,6 +87,46 @@ impl<'info, T: ProgramType> Program<'info, T> {
     pub fn context(&self) -> &'info FankorContext<'info> {
         self.context
     }
+
+    /// Whether the CPIs made through this program are automatically signed
+    /// with the stored seeds of the PDAs of the current program or not.
+    pub fn signs_with_pda_seeds(&self) -> bool {
+        self.sign_with_pda_seeds
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Returns a copy of this program whose CPIs are automatically signed with
+    /// the seeds, stored in the context, of every PDA of the current program
+    /// that the instruction requires as signer.
+    pub fn with_pda_signers(&self) -> Program<'info, T> {
+        Program {
+            context: self.context,
+            info: self.info,
+            sign_with_pda_seeds: true,
+            _data: PhantomData,
+        }
+    }
+
+    /// Invokes the `instruction` against this program signing it with the
+    /// `signer_seeds` and, if enabled, with the stored PDA seeds.
+    pub fn invoke_signed(
+        &self,
+        instruction: &SolanaInstruction,
+        account_infos: &[AccountInfo],
+        signer_seeds: &[&[&[u8]]],
+    ) -> FankorResult<()> {
+        if self.sign_with_pda_seeds {
+            return self.context.invoke_signed_with_pda_seeds(
+                instruction,
+                account_infos,
+                signer_seeds,
+            );
+        }
+
+        solana_program::program::invoke_signed(instruction, account_infos, signer_seeds)
+            .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    }
 }
 
 impl<'info, T: ProgramType> Instruction<'info> for Program<'info, T> {
@@ -91,6 +91,13 @@ for SingleEither<L, R>
         }
     }
 
+    fn exit(&mut self, context: &'info FankorContext<'info>) -> FankorResult<()> {
+        match self {
+            SingleEither::Left(v) => v.exit(context),
+            SingleEither::Right(v) => v.exit(context),
+        }
+    }
+
     #[inline(never)]
     fn try_from(
         context: &'info FankorContext<'info>,
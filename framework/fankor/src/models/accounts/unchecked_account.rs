@@ -94,7 +94,7 @@ impl<'info> UncheckedAccount<'info> {
 
     /// Closes the account and sends the lamports to the `destination_account`.
     pub fn close(self, destination_account: &AccountInfo<'info>) -> FankorResult<()> {
-        close_account(self.info, self.context(), destination_account)
+        close_account(self.info, self.context(), destination_account, false)
     }
 
     /// Reallocates the account to the given `size`. If a `payer` is provided,
@@ -13,8 +13,8 @@ use crate::cpi::system_program::CpiCreateAccount;
 use crate::errors::{FankorErrorCode, FankorResult};
 use crate::models::{Account, FankorContext, Program, System};
 use crate::traits::{
-    AccountInfoVerification, AccountType, CopyType, Instruction, PdaChecker,
-    SingleInstructionAccount,
+    AccountInfoVerification, AccountType, CopyType, Instruction, PausableConfig, PdaChecker,
+    SingleInstructionAccount, PAUSE_CONFIG_SEED,
 };
 
 /// Wrapper for `AccountInfo` to explicitly define an uninitialized account.
@@ -212,6 +212,39 @@ impl<'info> UninitializedAccount<'info> {
 
         Ok(Account::new_unchecked(self.context, self.info, value))
     }
+
+    /// Initializes the pause config of a pausable program with `admin` as its
+    /// admin, transferring the necessary lamports to cover the rent using `payer`
+    /// as the funding account.
+    ///
+    /// The account must be the canonical PDA of the program derived from
+    /// [`PAUSE_CONFIG_SEED`]. The program is responsible for deciding who can
+    /// call the instruction that uses this method, e.g. only its upgrade authority,
+    /// and that instruction must be allowed while paused.
+    pub fn init_pause_config<T: PausableConfig + CopyType<'info>>(
+        self,
+        admin: Pubkey,
+        payer: &AccountInfo<'info>,
+        system_program: &Program<System>,
+    ) -> FankorResult<Account<'info, T>> {
+        let (expected, bump) =
+            Pubkey::find_program_address(&[PAUSE_CONFIG_SEED], self.context.program_id());
+
+        if self.info.key != &expected {
+            return Err(FankorErrorCode::InvalidPda {
+                expected,
+                actual: *self.info.key,
+            }
+            .into());
+        }
+
+        self.init_pda_with_value(
+            T::new(admin, bump),
+            &[PAUSE_CONFIG_SEED, &[bump]],
+            payer,
+            system_program,
+        )
+    }
 }
 
 impl<'info> Instruction<'info> for UninitializedAccount<'info> {
@@ -4,7 +4,7 @@ use solana_program::account_info::AccountInfo;
 use solana_program::instruction::AccountMeta;
 
 use crate::errors::{FankorErrorCode, FankorResult};
-use crate::models::FankorContext;
+use crate::models::{CompactLayoutReader, CompactLayoutWriter, FankorContext};
 use crate::traits::{AccountInfoVerification, CpiInstruction, Instruction, LpiInstruction};
 
 impl<'info, T: Instruction<'info>> Instruction<'info> for Vec<T> {
@@ -22,6 +22,14 @@ impl<'info, T: Instruction<'info>> Instruction<'info> for Vec<T> {
         Ok(())
     }
 
+    fn exit(&mut self, context: &'info FankorContext<'info>) -> FankorResult<()> {
+        for account in self {
+            account.exit(context)?;
+        }
+
+        Ok(())
+    }
+
     #[inline(never)]
     fn try_from(
         context: &'info FankorContext<'info>,
@@ -43,6 +51,23 @@ impl<'info, T: Instruction<'info>> Instruction<'info> for Vec<T> {
 
         Ok(result)
     }
+
+    #[inline(never)]
+    fn try_from_compact(
+        context: &'info FankorContext<'info>,
+        header: &mut CompactLayoutReader,
+        buf: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        let size = header.read_length()?;
+        let mut result = Vec::with_capacity(size.min(accounts.len()));
+
+        for _ in 0..size {
+            result.push(T::try_from_compact(context, header, buf, accounts)?);
+        }
+
+        Ok(result)
+    }
 }
 
 impl<'info, T: CpiInstruction<'info>> CpiInstruction<'info> for Vec<T> {
@@ -65,6 +90,27 @@ impl<'info, T: CpiInstruction<'info>> CpiInstruction<'info> for Vec<T> {
 
         Ok(())
     }
+
+    fn serialize_into_instruction_parts_compact<W: Write>(
+        &self,
+        header: &mut CompactLayoutWriter,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+        infos: &mut Vec<AccountInfo<'info>>,
+    ) -> FankorResult<()> {
+        let length = self.len();
+        if length > u8::MAX as usize {
+            return Err(FankorErrorCode::TooManyAccounts { size: length }.into());
+        }
+
+        header.write_length(length)?;
+
+        for v in self {
+            v.serialize_into_instruction_parts_compact(header, writer, metas, infos)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<T: LpiInstruction> LpiInstruction for Vec<T> {
@@ -86,4 +132,24 @@ impl<T: LpiInstruction> LpiInstruction for Vec<T> {
 
         Ok(())
     }
+
+    fn serialize_into_instruction_parts_compact<W: Write>(
+        &self,
+        header: &mut CompactLayoutWriter,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+    ) -> FankorResult<()> {
+        let length = self.len();
+        if length > u8::MAX as usize {
+            return Err(FankorErrorCode::TooManyAccounts { size: length }.into());
+        }
+
+        header.write_length(length)?;
+
+        for v in self {
+            v.serialize_into_instruction_parts_compact(header, writer, metas)?;
+        }
+
+        Ok(())
+    }
 }
@@ -11,8 +11,10 @@ use solana_program::rent::Rent;
 use solana_program::system_program;
 use solana_program::sysvar::Sysvar;
 
-use crate::errors::{Error, FankorErrorCode, FankorResult};
-use crate::models::{Account, FankorContext, FankorContextExitAction, Program, System, Zc};
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::{
+    Account, FankorContext, FankorContextExitAction, Program, System, Zc, ZcReadGuard, ZcWriteGuard,
+};
 use crate::prelude::CopyType;
 use crate::traits::{
     AccountInfoVerification, AccountType, Instruction, PdaChecker, SingleInstructionAccount,
@@ -52,7 +54,7 @@ impl<'info, T: AccountType + CopyType<'info>> ZcAccount<'info, T> {
         }
 
         // Check it is not closed.
-        if context.is_account_uninitialized(info) {
+        if context.is_account_closed(info) {
             return Err(FankorErrorCode::NewFromClosedAccount { address: *info.key }.into());
         }
 
@@ -123,12 +125,9 @@ impl<'info, T: AccountType + CopyType<'info>> ZcAccount<'info, T> {
         self.info.rent_epoch
     }
 
+    #[deprecated(note = "use `ZcAccount::read` or `ZcAccount::write` instead")]
     pub fn data(&self) -> Zc<'info, T> {
-        Zc {
-            info: self.info,
-            offset: 0,
-            _data: PhantomData,
-        }
+        Zc::new_unchecked(self.info, 0)
     }
 
     pub fn context(&self) -> &'info FankorContext<'info> {
@@ -158,9 +157,63 @@ impl<'info, T: AccountType + CopyType<'info>> ZcAccount<'info, T> {
 
     // METHODS ----------------------------------------------------------------
 
+    /// Borrows the account data in zero-copy mode. It fails if the account is
+    /// already borrowed mutably.
+    pub fn read(&self) -> FankorResult<ZcReadGuard<'info, T>> {
+        ZcReadGuard::new(self.context, self.info)
+    }
+
+    /// Borrows the account data in mutable zero-copy mode. It fails if the
+    /// account is already borrowed.
+    pub fn write(&self) -> FankorResult<ZcWriteGuard<'info, T>> {
+        if !self.is_owned_by_program() {
+            return Err(FankorErrorCode::AccountNotOwnedByProgram {
+                address: *self.address(),
+                action: "write",
+            }
+            .into());
+        }
+
+        if !self.is_writable() {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *self.address(),
+                action: "write",
+            }
+            .into());
+        }
+
+        if self.context.is_account_closed(self.info) {
+            return Err(FankorErrorCode::AlreadyClosedAccount {
+                address: *self.address(),
+                action: "write",
+            }
+            .into());
+        }
+
+        ZcWriteGuard::new(self.context, self.info)
+    }
+
     /// Closes the account and sends the lamports to the `destination_account`.
-    pub fn close(mut self, destination_account: &AccountInfo<'info>) -> FankorResult<()> {
-        close_account(self.info, self.context(), destination_account)?;
+    pub fn close(self, destination_account: &AccountInfo<'info>) -> FankorResult<()> {
+        self._close(destination_account, false)
+    }
+
+    /// Closes the account and sends the lamports to the `destination_account`
+    /// leaving a tombstone in its data, so it cannot be loaded again even if
+    /// it is refunded in the same transaction.
+    pub fn close_with_tombstone(
+        self,
+        destination_account: &AccountInfo<'info>,
+    ) -> FankorResult<()> {
+        self._close(destination_account, true)
+    }
+
+    fn _close(
+        mut self,
+        destination_account: &AccountInfo<'info>,
+        tombstone: bool,
+    ) -> FankorResult<()> {
+        close_account(self.info, self.context(), destination_account, tombstone)?;
 
         // Prevent account to execute the drop actions.
         self.dropped = true;
@@ -192,7 +245,7 @@ impl<'info, T: AccountType + CopyType<'info>> ZcAccount<'info, T> {
             .into());
         }
 
-        if self.context.is_account_uninitialized(self.info) {
+        if self.context.is_account_closed(self.info) {
             return Err(FankorErrorCode::AlreadyClosedAccount {
                 address: *self.address(),
                 action: "realloc",
@@ -244,7 +297,7 @@ impl<'info, T: AccountType + CopyType<'info>> ZcAccount<'info, T> {
             .into());
         }
 
-        if self.context.is_account_uninitialized(self.info) {
+        if self.context.is_account_closed(self.info) {
             return Err(FankorErrorCode::AlreadyClosedAccount {
                 address: *self.address(),
                 action: "make rent-exempt",
@@ -281,7 +334,7 @@ impl<'info, T: AccountType + CopyType<'info>> ZcAccount<'info, T> {
             .into());
         }
 
-        if self.context.is_account_uninitialized(self.info) {
+        if self.context.is_account_closed(self.info) {
             return Err(FankorErrorCode::AlreadyClosedAccount {
                 address: *self.address(),
                 action: "transmute",
@@ -312,7 +365,7 @@ impl<'info, T: AccountType + CopyType<'info>> ZcAccount<'info, T> {
 
     /// Deserializes the zero-copy value and creates a new account.
     pub fn into_account(mut self) -> FankorResult<Account<'info, T>> {
-        let data = self.data().try_value()?;
+        let data = Zc::<T>::new_unchecked(self.info, 0).try_value()?;
         let new_account = Account::new_unchecked(self.context, self.info, data);
 
         // Prevent old account to execute the drop actions.
@@ -371,7 +424,7 @@ impl<'info, T: AccountType + CopyType<'info>> ZcAccount<'info, T> {
             .into());
         }
 
-        if self.context.is_account_uninitialized(self.info) {
+        if self.context.is_account_closed(self.info) {
             return Err(FankorErrorCode::AlreadyClosedAccount {
                 address: *self.address(),
                 action: "make rent-exempt",
@@ -399,6 +452,25 @@ impl<'info, T: AccountType + CopyType<'info>> ZcAccount<'info, T> {
     pub fn close_account_at_exit(
         &self,
         destination_account: &'info AccountInfo<'info>,
+    ) -> FankorResult<()> {
+        self._close_account_at_exit(destination_account, false)
+    }
+
+    /// Closes the account at the end of the instruction sending the lamports to
+    /// the `destination_account` account and leaving a tombstone in its data.
+    ///
+    /// This replaces other exit actions associated with this account.
+    pub fn close_account_with_tombstone_at_exit(
+        &self,
+        destination_account: &'info AccountInfo<'info>,
+    ) -> FankorResult<()> {
+        self._close_account_at_exit(destination_account, true)
+    }
+
+    fn _close_account_at_exit(
+        &self,
+        destination_account: &'info AccountInfo<'info>,
+        tombstone: bool,
     ) -> FankorResult<()> {
         if !self.is_owned_by_program() {
             return Err(FankorErrorCode::AccountNotOwnedByProgram {
@@ -421,6 +493,7 @@ impl<'info, T: AccountType + CopyType<'info>> ZcAccount<'info, T> {
             self.info,
             FankorContextExitAction::Close {
                 destination_account,
+                tombstone,
             },
         );
 
@@ -439,6 +512,23 @@ impl<'info, T: AccountType + CopyType<'info>> Instruction<'info> for ZcAccount<'
         config.verify(self.info)
     }
 
+    fn exit(&mut self, _context: &'info FankorContext<'info>) -> FankorResult<()> {
+        // Ignore already exited accounts.
+        if self.dropped {
+            return Ok(());
+        }
+
+        // Prevent account to execute the drop actions.
+        self.dropped = true;
+
+        // Ignore if not owned by program.
+        if !self.is_owned_by_program() {
+            return Ok(());
+        }
+
+        drop_aux(self)
+    }
+
     #[inline(never)]
     fn try_from(
         context: &'info FankorContext<'info>,
@@ -464,7 +554,7 @@ impl<'info, T: AccountType + CopyType<'info>> Instruction<'info> for ZcAccount<'
         }
 
         // Check it is not closed.
-        if context.is_account_uninitialized(info) {
+        if context.is_account_closed(info) {
             return Err(FankorErrorCode::NewFromClosedAccount { address: *info.key }.into());
         }
 
@@ -525,8 +615,10 @@ impl<'info, T: AccountType + CopyType<'info>> Drop for ZcAccount<'info, T> {
             return;
         }
 
+        // Fallback for the accounts not exited by the exit phase of the
+        // instruction, whose error is returned by the context.
         if let Err(e) = drop_aux(self) {
-            crate::macros::panic_error!(e);
+            self.context.set_exit_error(e);
         }
     }
 }
@@ -571,8 +663,14 @@ fn drop_aux<'info, T: AccountType + CopyType<'info>>(
         }
         Some(FankorContextExitAction::Close {
             destination_account,
+            tombstone,
         }) => {
-            close_account(account.info(), account.context(), destination_account)?;
+            close_account(
+                account.info(),
+                account.context(),
+                destination_account,
+                tombstone,
+            )?;
 
             // Prevent executing this action twice.
             account
@@ -0,0 +1,161 @@
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::{Argument, FankorContext, Zc};
+use crate::traits::{CopyType, Instruction, PdaChecker, ZeroCopyType};
+
+/// An instruction argument read in Zero Copy mode, i.e. borrowing the
+/// instruction data instead of deserializing it.
+///
+/// The data is read-only: any attempt to write it through the zero-copy
+/// values fails.
+pub struct ZcArgument<'info, T: CopyType<'info>> {
+    bytes: &'info [u8],
+    _data: PhantomData<T>,
+}
+
+impl<'info, T: CopyType<'info>> ZcArgument<'info, T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new argument that points to `bytes`.
+    pub fn new(bytes: &'info [u8]) -> ZcArgument<'info, T> {
+        ZcArgument {
+            bytes,
+            _data: PhantomData,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The bytes of the argument.
+    pub fn bytes(&self) -> &'info [u8] {
+        self.bytes
+    }
+
+    pub fn data(&self) -> Zc<'info, T> {
+        Zc::new_unchecked(self.bytes, 0)
+    }
+}
+
+impl<'info, T: CopyType<'info> + BorshDeserialize> ZcArgument<'info, T> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Deserializes the argument.
+    pub fn try_value(&self) -> FankorResult<T> {
+        self.data().try_value()
+    }
+}
+
+impl<'info, T: CopyType<'info> + BorshSerialize + BorshDeserialize> Instruction<'info>
+    for ZcArgument<'info, T>
+{
+    type CPI = Argument<T>;
+    type LPI = Argument<T>;
+
+    #[inline(never)]
+    fn try_from(
+        context: &'info FankorContext<'info>,
+        buf: &mut &[u8],
+        _accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        // Find the argument inside the instruction data to keep its lifetime.
+        let instruction_data = context.instruction_data();
+        let start = (buf.as_ptr() as usize)
+            .checked_sub(instruction_data.as_ptr() as usize)
+            .filter(|start| start + buf.len() <= instruction_data.len())
+            .ok_or(FankorErrorCode::ZeroCopyArgumentOutsideInstructionData)?;
+
+        let size = T::ZeroCopyType::read_byte_size(buf)?;
+
+        if size > buf.len() {
+            return Err(FankorErrorCode::NotEnoughDataToDeserializeInstruction.into());
+        }
+
+        *buf = &buf[size..];
+
+        Ok(ZcArgument::new(&instruction_data[start..start + size]))
+    }
+}
+
+impl<'info, T: CopyType<'info>> PdaChecker<'info> for ZcArgument<'info, T> {
+    fn pda_info(&self) -> Option<&'info AccountInfo<'info>> {
+        None
+    }
+}
+
+impl<'info, T: CopyType<'info>> Debug for ZcArgument<'info, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZcArgument")
+            .field("data", &self.bytes)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::errors::Error;
+    use crate::models::types::FnkString;
+
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        let program_id = Pubkey::default();
+        let data = (
+            7u8,
+            vec![1u32, 2, 3],
+            FnkString::from("abc".to_string()),
+            9u8,
+        )
+            .try_to_vec()
+            .unwrap();
+        let context = FankorContext::new_with_data_unchecked(&program_id, &[], &data);
+        let context: &FankorContext = unsafe { std::mem::transmute(&context) };
+
+        let mut buf = &data[1..];
+        let mut accounts: &[AccountInfo] = &[];
+        let vector =
+            <ZcArgument<Vec<u32>> as Instruction>::try_from(context, &mut buf, &mut accounts)
+                .unwrap();
+        let string =
+            <ZcArgument<FnkString> as Instruction>::try_from(context, &mut buf, &mut accounts)
+                .unwrap();
+        assert_eq!(buf, &[9]);
+
+        let values = vector
+            .data()
+            .zc_value()
+            .unwrap()
+            .iter()
+            .map(|v| v.try_value().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![1, 2, 3]);
+        assert_eq!(string.try_value().unwrap().to_string(), "abc");
+
+        // Writing is not allowed.
+        assert!(vector.data().write_value(&vec![4]).is_err());
+        assert_eq!(vector.try_value().unwrap(), vec![1, 2, 3]);
+
+        // Outside the instruction data.
+        let other = [0u8; 4];
+        let error =
+            <ZcArgument<u32> as Instruction>::try_from(context, &mut &other[..], &mut accounts)
+                .err()
+                .unwrap();
+        assert_eq!(
+            error,
+            Error::from(FankorErrorCode::ZeroCopyArgumentOutsideInstructionData)
+        );
+    }
+}
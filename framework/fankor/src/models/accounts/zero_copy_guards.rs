@@ -0,0 +1,374 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::{FankorContext, Zc};
+use crate::traits::{CopyType, ZeroCopyType};
+
+/// A shared zero-copy borrow of an account. While it is alive, the account
+/// cannot be borrowed mutably by other guards.
+pub struct ZcReadGuard<'info, T: CopyType<'info>> {
+    context: &'info FankorContext<'info>,
+    info: &'info AccountInfo<'info>,
+    _data: PhantomData<T>,
+}
+
+impl<'info, T: CopyType<'info>> ZcReadGuard<'info, T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub(crate) fn new(
+        context: &'info FankorContext<'info>,
+        info: &'info AccountInfo<'info>,
+    ) -> FankorResult<Self> {
+        context.acquire_zc_borrow(info, false)?;
+
+        Ok(ZcReadGuard {
+            context,
+            info,
+            _data: PhantomData,
+        })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    /// The zero-copy value of the account. It cannot outlive the guard.
+    pub fn data(&self) -> ZcRef<'_, 'info, T> {
+        ZcRef::new(Zc::new_unchecked(self.info, 0))
+    }
+}
+
+impl<'info, T: CopyType<'info>> Drop for ZcReadGuard<'info, T> {
+    fn drop(&mut self) {
+        self.context.release_zc_borrow(self.info);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An exclusive zero-copy borrow of an account. The operations that shift bytes
+/// are tracked so every [`ZcView`] of the account is moved accordingly or
+/// invalidated if its value has been removed.
+pub struct ZcWriteGuard<'info, T: CopyType<'info>> {
+    context: &'info FankorContext<'info>,
+    info: &'info AccountInfo<'info>,
+    _data: PhantomData<T>,
+}
+
+impl<'info, T: CopyType<'info>> ZcWriteGuard<'info, T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub(crate) fn new(
+        context: &'info FankorContext<'info>,
+        info: &'info AccountInfo<'info>,
+    ) -> FankorResult<Self> {
+        context.acquire_zc_borrow(info, true)?;
+
+        Ok(ZcWriteGuard {
+            context,
+            info,
+            _data: PhantomData,
+        })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    /// The zero-copy value of the account. It cannot outlive the guard, use
+    /// [`ZcWriteGuard::view`] to keep track of it across the operations of
+    /// the guard.
+    pub fn data(&self) -> ZcRef<'_, 'info, T> {
+        ZcRef::new(Zc::new_unchecked(self.info, 0))
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Starts tracking `zc` so that it survives the operations of this guard.
+    pub fn view<V: CopyType<'info>>(&self, zc: &Zc<'info, V>) -> FankorResult<ZcView<'info, V>> {
+        self.check_zc(zc)?;
+
+        Ok(ZcView {
+            context: self.context,
+            info: self.info,
+            id: self.context.track_zc_view(self.info, zc.offset),
+            _data: PhantomData,
+        })
+    }
+
+    /// Inserts `length` bytes at the position of `zc`, moving it and the rest
+    /// of the bytes forward.
+    pub fn make_space<V: CopyType<'info>>(
+        &self,
+        zc: &Zc<'info, V>,
+        length: usize,
+    ) -> FankorResult<()> {
+        self.check_zc(zc)?;
+        zc.insert_bytes(length)?;
+        self.context
+            .resize_zc_views(self.info, zc.offset, 0, length);
+
+        Ok(())
+    }
+
+    /// Removes `length` bytes at the position of `zc`.
+    pub fn remove_bytes<V: CopyType<'info>>(
+        &self,
+        zc: &Zc<'info, V>,
+        length: usize,
+    ) -> FankorResult<()> {
+        self.check_zc(zc)?;
+        zc.remove_bytes(length)?;
+        self.context
+            .resize_zc_views(self.info, zc.offset, length, 0);
+
+        Ok(())
+    }
+
+    /// Removes the value pointed by `zc`.
+    pub fn remove_value<V: CopyType<'info>>(&self, zc: Zc<'info, V>) -> FankorResult<()> {
+        let size = zc.byte_size()?;
+        self.remove_bytes(&zc, size)
+    }
+
+    /// Overwrites the value pointed by `zc`, resizing the account if the size
+    /// of `value` differs from the previous one.
+    pub fn write_value<V: CopyType<'info> + BorshSerialize>(
+        &self,
+        zc: &Zc<'info, V>,
+        value: &V,
+    ) -> FankorResult<()> {
+        self.check_zc(zc)?;
+
+        let previous_size = self.read_byte_size(zc)?;
+        let new_size = value.byte_size();
+
+        zc.write_value_with_sizes(value, previous_size, new_size)?;
+        self.context
+            .resize_zc_views(self.info, zc.offset, previous_size, new_size);
+
+        Ok(())
+    }
+
+    /// Overwrites the value pointed by `zc` with the serialized `bytes`,
+    /// resizing the account if their size differs from the previous one.
+    /// This is useful to replace a value with another one of a compatible
+    /// type, e.g. an enum with one of its variants.
+    pub fn write_bytes<V: CopyType<'info>>(
+        &self,
+        zc: &Zc<'info, V>,
+        bytes: &[u8],
+    ) -> FankorResult<()> {
+        self.check_zc(zc)?;
+
+        let previous_size = self.read_byte_size(zc)?;
+
+        zc.write_bytes_with_size(bytes, previous_size)?;
+        self.context
+            .resize_zc_views(self.info, zc.offset, previous_size, bytes.len());
+
+        Ok(())
+    }
+
+    fn read_byte_size<V: CopyType<'info>>(&self, zc: &Zc<'info, V>) -> FankorResult<usize> {
+        let bytes =
+            self.info
+                .try_borrow_data()
+                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    type_name: std::any::type_name::<Self>(),
+                })?;
+        let bytes =
+            bytes
+                .get(zc.offset..)
+                .ok_or_else(|| FankorErrorCode::ZeroCopyNotEnoughLength {
+                    type_name: std::any::type_name::<V>(),
+                })?;
+
+        V::ZeroCopyType::read_byte_size(bytes)
+    }
+
+    fn check_zc<V: CopyType<'info>>(&self, zc: &Zc<'info, V>) -> FankorResult<()> {
+        if zc.info.account().map(|info| info.key) != Some(self.info.key) {
+            return Err(FankorErrorCode::ZeroCopyInvalidView {
+                address: *self.info.key,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info, T: CopyType<'info>> Drop for ZcWriteGuard<'info, T> {
+    fn drop(&mut self) {
+        self.context.release_zc_borrow(self.info);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A zero-copy value borrowed from a [`ZcReadGuard`] or a [`ZcWriteGuard`], so
+/// it cannot be used after the guard is released.
+pub struct ZcRef<'a, 'info, V: CopyType<'info>> {
+    zc: Zc<'info, V>,
+    _guard: PhantomData<&'a ()>,
+}
+
+impl<'a, 'info, V: CopyType<'info>> ZcRef<'a, 'info, V> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    fn new(zc: Zc<'info, V>) -> Self {
+        ZcRef {
+            zc,
+            _guard: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'info, V: CopyType<'info>> Deref for ZcRef<'a, 'info, V> {
+    type Target = Zc<'info, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.zc
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A zero-copy value whose offset is tracked by the context, so it is kept
+/// up to date after the operations of a [`ZcWriteGuard`].
+pub struct ZcView<'info, V: CopyType<'info>> {
+    context: &'info FankorContext<'info>,
+    info: &'info AccountInfo<'info>,
+    id: u32,
+    _data: PhantomData<V>,
+}
+
+impl<'info, V: CopyType<'info>> ZcView<'info, V> {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The current offset of the value. It fails if the value has been removed
+    /// or the account has been resized outside a [`ZcWriteGuard`].
+    pub fn offset(&self) -> FankorResult<usize> {
+        self.context.get_zc_view_offset(self.info, self.id)
+    }
+
+    /// The zero-copy value at the current offset.
+    pub fn zc(&self) -> FankorResult<Zc<'info, V>> {
+        Ok(Zc::new_unchecked(self.info, self.offset()?))
+    }
+}
+
+impl<'info, V: CopyType<'info>> Drop for ZcView<'info, V> {
+    fn drop(&mut self) {
+        self.context.untrack_zc_view(self.info, self.id);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::errors::Error;
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_borrow_conflicts() {
+        let mut lamports = 0;
+        let mut vector = vec![1, 2, 3];
+        let accounts = [create_account_info_for_tests(&mut lamports, &mut vector)];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+
+        let read1 = ZcReadGuard::<u8>::new(&context, info).unwrap();
+        let read2 = ZcReadGuard::<u8>::new(&context, info).unwrap();
+        let error = ZcWriteGuard::<u8>::new(&context, info).err().unwrap();
+        assert_eq!(
+            error,
+            Error::from(FankorErrorCode::ZeroCopyAlreadyBorrowed { address: *info.key })
+        );
+
+        drop(read1);
+        drop(read2);
+
+        let write = ZcWriteGuard::<u8>::new(&context, info).unwrap();
+        let error = ZcReadGuard::<u8>::new(&context, info).err().unwrap();
+        assert_eq!(
+            error,
+            Error::from(FankorErrorCode::ZeroCopyAlreadyBorrowedMut { address: *info.key })
+        );
+        assert!(ZcWriteGuard::<u8>::new(&context, info).is_err());
+
+        drop(write);
+        assert!(ZcReadGuard::<u8>::new(&context, info).is_ok());
+    }
+
+    #[test]
+    fn test_views_follow_changes() {
+        let mut lamports = 0;
+        let mut vector = vec![1, 2, 3, 4, 5, 0];
+        let accounts = [create_account_info_for_tests(&mut lamports, &mut vector)];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+
+        let guard = ZcWriteGuard::<u8>::new(&context, info).unwrap();
+        let first = guard.view(&Zc::<u8>::new_unchecked(info, 0)).unwrap();
+        let third = guard.view(&Zc::<u8>::new_unchecked(info, 2)).unwrap();
+        let fifth = guard.view(&Zc::<u8>::new_unchecked(info, 4)).unwrap();
+
+        guard
+            .make_space(&Zc::<u8>::new_unchecked(info, 2), 1)
+            .unwrap();
+        assert_eq!(first.offset().unwrap(), 0);
+        assert_eq!(third.offset().unwrap(), 3);
+        assert_eq!(fifth.offset().unwrap(), 5);
+        assert_eq!(third.zc().unwrap().try_value().unwrap(), 3);
+        assert_eq!(fifth.zc().unwrap().try_value().unwrap(), 5);
+
+        guard.write_value(&third.zc().unwrap(), &7).unwrap();
+        assert_eq!(third.offset().unwrap(), 3);
+        assert_eq!(info.data.borrow()[..], [1, 2, 0, 7, 4, 5]);
+    }
+
+    #[test]
+    fn test_write_data() {
+        let mut lamports = 0;
+        let mut vector = vec![1, 2, 3];
+        let accounts = [create_account_info_for_tests(&mut lamports, &mut vector)];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+
+        let guard = ZcWriteGuard::<u16>::new(&context, info).unwrap();
+        guard.write_value(&guard.data(), &0x0504).unwrap();
+        assert_eq!(guard.data().try_value().unwrap(), 0x0504);
+        assert_eq!(info.data.borrow()[..], [4, 5, 3]);
+
+        drop(guard);
+
+        let guard = ZcReadGuard::<u16>::new(&context, info).unwrap();
+        assert_eq!(guard.data().try_value().unwrap(), 0x0504);
+    }
+}
@@ -1,12 +1,24 @@
 use std::cell::RefCell;
 use std::collections::BTreeMap;
+use std::io::Write;
+use std::mem::size_of;
 use std::rc::Rc;
 
 use solana_program::account_info::AccountInfo;
+use solana_program::instruction::{
+    get_stack_height, AccountMeta, Instruction, TRANSACTION_LEVEL_STACK_HEIGHT,
+};
+use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::instructions::get_instruction_relative;
 
-use crate::errors::{FankorErrorCode, FankorResult};
+use crate::errors::{Error, FankorErrorCode, FankorResult};
+use crate::models::{Account, Program, System};
 use crate::prelude::byte_seeds_to_slices;
+use crate::traits::{AccountType, PausableConfig, PAUSE_CONFIG_SEED};
+use crate::utils::close::is_closed_account_tombstone;
+use crate::utils::realloc::realloc_account_to_size;
+use crate::utils::writers::ArrayWriter;
 
 #[derive(Clone)]
 pub struct FankorContext<'info> {
@@ -16,22 +28,65 @@ pub struct FankorContext<'info> {
     /// The list of all accounts passed to the instruction.
     accounts: &'info [AccountInfo<'info>],
 
+    /// The data of the instruction, including its discriminant.
+    instruction_data: &'info [u8],
+
+    /// The position of the first occurrence of every account, so that the
+    /// duplicated ones share their data.
+    account_indexes: Rc<[u8]>,
+
     /// The reference to the mutable part of the context.
     inner: Rc<RefCell<FankorContextInnerMut<'info>>>,
 }
 
 struct FankorContextInnerMut<'info> {
-    // Data for each account.
+    // Data for each account indexed by the position of its first occurrence.
+    // It is allocated the first time any data is set.
+    account_data: Vec<FankorContextAccountData<'info>>,
+
+    // The tracked zero-copy views of each account.
     // The key is u8 because the maximum number of accounts per transaction is 256.
-    account_data: BTreeMap<u8, FankorContextAccountData<'info>>,
+    zc_views: BTreeMap<u8, FankorContextZcViews>,
+
+    // The identifier of the next tracked zero-copy view.
+    next_zc_view_id: u32,
+
+    // The first error produced by the exit actions of the accounts dropped
+    // before the exit phase.
+    exit_error: Option<Error>,
 }
 
+#[derive(Default)]
 struct FankorContextAccountData<'info> {
     // End action to perform at the end of the instruction.
     exit_action: Option<FankorContextExitAction<'info>>,
 
     // Seeds used to derived the account.
     seeds: Option<Rc<Vec<u8>>>,
+
+    // Whether the seeds derive the account from the current program, i.e.
+    // they can be used to sign CPIs.
+    own_pda: bool,
+
+    // The zero-copy borrow of the account data.
+    zc_borrow: ZcBorrowState,
+}
+
+#[derive(Default)]
+struct FankorContextZcViews {
+    // The current offset of every tracked zero-copy view.
+    views: BTreeMap<u32, usize>,
+
+    // The length of the account data after the last tracked change.
+    data_len: usize,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+enum ZcBorrowState {
+    #[default]
+    Unborrowed,
+    Shared(u32),
+    Mutable,
 }
 
 /// The action to perform at the end of the instruction for a specific account.
@@ -54,9 +109,10 @@ pub enum FankorContextExitAction<'info> {
         system_program: &'info AccountInfo<'info>,
     },
 
-    /// Closes the account.
+    /// Closes the account, optionally leaving a tombstone.
     Close {
         destination_account: &'info AccountInfo<'info>,
+        tombstone: bool,
     },
 }
 
@@ -71,12 +127,31 @@ impl<'info> FankorContext<'info> {
     pub fn new_unchecked(
         program_id: &'info Pubkey,
         accounts: &'info [AccountInfo<'info>],
+    ) -> FankorContext<'info> {
+        Self::new_with_data_unchecked(program_id, accounts, &[])
+    }
+
+    /// Same as [`new_unchecked`](Self::new_unchecked) but also keeping the
+    /// instruction data, which is required by [`ZcArgument`](crate::models::ZcArgument).
+    ///
+    /// # Safety
+    /// The params are not not checked. If you use this method manually, it can cause
+    /// undefined behaviours.
+    pub fn new_with_data_unchecked(
+        program_id: &'info Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        instruction_data: &'info [u8],
     ) -> FankorContext<'info> {
         Self {
             program_id,
             accounts,
+            instruction_data,
+            account_indexes: compute_account_indexes(accounts).into(),
             inner: Rc::new(RefCell::new(FankorContextInnerMut {
-                account_data: Default::default(),
+                account_data: Vec::new(),
+                zc_views: BTreeMap::new(),
+                next_zc_view_id: 0,
+                exit_error: None,
             })),
         }
     }
@@ -91,6 +166,24 @@ impl<'info> FankorContext<'info> {
         self.accounts
     }
 
+    /// The data of the instruction, including its discriminant. It is empty
+    /// if the context has been created without it.
+    pub fn instruction_data(&self) -> &'info [u8] {
+        self.instruction_data
+    }
+
+    /// The current invocation depth of the program. Top-level instructions
+    /// have a height of `TRANSACTION_LEVEL_STACK_HEIGHT` and every nested CPI
+    /// increases it by one.
+    pub fn stack_height(&self) -> usize {
+        get_stack_height()
+    }
+
+    /// Whether the current instruction is being executed through a CPI or not.
+    pub fn is_cpi(&self) -> bool {
+        self.stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT
+    }
+
     // METHODS ----------------------------------------------------------------
 
     /// Gets the corresponding account info for the given account key.
@@ -103,16 +196,147 @@ impl<'info> FankorContext<'info> {
         let index = self.get_index_for_account(account);
         self.inner
             .borrow()
-            .account_data
-            .get(&index)
+            .get_account_data(index)
             .and_then(|v| v.seeds.clone())
     }
 
+    /// Gets the index of the data of `account` in the context.
+    ///
+    /// The references to the account list are resolved from their address
+    /// while the copies of the account infos are searched by key.
     pub(crate) fn get_index_for_account(&self, account: &AccountInfo<'info>) -> u8 {
-        self.accounts
-            .iter()
-            .position(|a| a.key == account.key)
-            .expect("Undefined account") as u8
+        let offset =
+            (account as *const AccountInfo as usize).wrapping_sub(self.accounts.as_ptr() as usize);
+        let position = offset / size_of::<AccountInfo>();
+
+        let position =
+            if position < self.accounts.len() && position * size_of::<AccountInfo>() == offset {
+                position
+            } else {
+                self.accounts
+                    .iter()
+                    .position(|a| std::ptr::eq(a.key, account.key) || a.key == account.key)
+                    .expect("Undefined account")
+            };
+
+        self.account_indexes[position]
+    }
+
+    /// Gets the program id of the top-level instruction that is being executed
+    /// reading it from the Instructions sysvar, that must be included in the
+    /// account list.
+    pub fn get_top_level_program_id(&self) -> FankorResult<Pubkey> {
+        let sysvar_info = self
+            .get_account_from_address(&solana_program::sysvar::instructions::ID)
+            .ok_or(FankorErrorCode::MissingInstructionsSysvar)?;
+
+        let instruction = get_instruction_relative(0, sysvar_info)?;
+
+        Ok(instruction.program_id)
+    }
+
+    /// Checks the current instruction is being executed directly from the
+    /// transaction, i.e. not through a CPI.
+    pub fn check_top_level_call(&self) -> FankorResult<()> {
+        if self.is_cpi() {
+            return Err(FankorErrorCode::InstructionNotTopLevel.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks the current instruction is being executed through a CPI made by
+    /// one of the `allowed_callers` programs.
+    ///
+    /// Only direct CPIs from the top-level instruction can be verified, so
+    /// deeper calls are rejected because their caller cannot be determined.
+    pub fn check_caller_program(&self, allowed_callers: &[&Pubkey]) -> FankorResult<()> {
+        let stack_height = self.stack_height();
+
+        if stack_height <= TRANSACTION_LEVEL_STACK_HEIGHT {
+            return Err(FankorErrorCode::InstructionNotCalledViaCpi.into());
+        }
+
+        if stack_height > TRANSACTION_LEVEL_STACK_HEIGHT + 1 {
+            return Err(FankorErrorCode::InstructionCallerUnknown.into());
+        }
+
+        let caller = self.get_top_level_program_id()?;
+
+        if !allowed_callers.contains(&&caller) {
+            return Err(FankorErrorCode::InstructionCallerNotAllowed { caller }.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks the current program is not being re-entered, i.e. it is not
+    /// executed through a CPI made by the same program.
+    ///
+    /// The runtime already rejects indirect re-entries, e.g. `X -> A -> B -> A`,
+    /// so only a program calling itself must be detected. As only the
+    /// top-level instruction is exposed, deeper calls are rejected because
+    /// their caller cannot be determined, e.g. `X -> A -> A`.
+    pub fn check_no_reentrancy(&self) -> FankorResult<()> {
+        let stack_height = self.stack_height();
+
+        if stack_height <= TRANSACTION_LEVEL_STACK_HEIGHT {
+            return Ok(());
+        }
+
+        if stack_height > TRANSACTION_LEVEL_STACK_HEIGHT + 1 {
+            return Err(FankorErrorCode::InstructionCallerUnknown.into());
+        }
+
+        if self.get_top_level_program_id()? == *self.program_id {
+            return Err(FankorErrorCode::ReentrancyDetected {
+                program_id: *self.program_id,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks the program is not paused reading the config account of a
+    /// pausable program, which must be the last of `accounts`. Returns the
+    /// rest of the accounts.
+    ///
+    /// Note: the config account is verified to be the PDA with the
+    /// [`PAUSE_CONFIG_SEED`] seed and the bump seed stored in it.
+    pub fn check_not_paused<T: PausableConfig>(
+        &'info self,
+        accounts: &'info [AccountInfo<'info>],
+    ) -> FankorResult<&'info [AccountInfo<'info>]> {
+        let (info, rest) = accounts
+            .split_last()
+            .ok_or(FankorErrorCode::NotEnoughAccountKeys)?;
+
+        let config = <Account<'info, T> as crate::traits::Instruction<'info>>::try_from(
+            self,
+            &mut &[][..],
+            &mut std::slice::from_ref(info),
+        )?;
+
+        let expected_address = Pubkey::create_program_address(
+            &[PAUSE_CONFIG_SEED, &[config.data().bump()]],
+            self.program_id,
+        )
+        .map_err(ProgramError::from)?;
+
+        if expected_address != *info.key {
+            return Err(FankorErrorCode::InvalidPda {
+                expected: expected_address,
+                actual: *info.key,
+            }
+            .into());
+        }
+
+        if config.data().is_paused() {
+            return Err(FankorErrorCode::ProgramPaused.into());
+        }
+
+        Ok(rest)
     }
 
     /// Whether the account is uninitialized or not, i.e. it matches all these constraints:
@@ -125,6 +349,80 @@ impl<'info> FankorContext<'info> {
             && account.owner == &solana_program::system_program::ID
     }
 
+    /// Whether the account has been closed with a tombstone, i.e. it is owned
+    /// by the current program and its data is
+    /// [`CLOSED_ACCOUNT_TOMBSTONE`](crate::utils::close::CLOSED_ACCOUNT_TOMBSTONE).
+    ///
+    /// Unlike the uninitialized accounts, they keep being detected if they
+    /// are refunded after being closed.
+    pub fn is_account_tombstoned(&self, account: &AccountInfo<'info>) -> bool {
+        account.owner == self.program_id
+            && account
+                .try_borrow_data()
+                .map(|data| is_closed_account_tombstone(&data))
+                .unwrap_or(false)
+    }
+
+    /// Whether the account has been closed, either uninitialized or tombstoned.
+    pub fn is_account_closed(&self, account: &AccountInfo<'info>) -> bool {
+        self.is_account_uninitialized(account) || self.is_account_tombstoned(account)
+    }
+
+    /// Revives an account closed in this instruction writing `data` into it and
+    /// adding funds from `payer` to make it rent-exempt.
+    ///
+    /// Only the accounts that keep being owned by the program can be revived,
+    /// i.e. those that are going to be closed at exit, whose close is
+    /// cancelled, or those closed with a tombstone. The accounts closed
+    /// without a tombstone belong to the System program, so they must be
+    /// initialized again instead.
+    pub fn revive<T: AccountType>(
+        &'info self,
+        info: &'info AccountInfo<'info>,
+        data: T,
+        payer: &'info AccountInfo<'info>,
+        system_program: &Program<System>,
+    ) -> FankorResult<Account<'info, T>> {
+        if info.owner != self.program_id || info.owner != T::owner() {
+            return Err(FankorErrorCode::AccountNotOwnedByProgram {
+                address: *info.key,
+                action: "revive",
+            }
+            .into());
+        }
+
+        if !info.is_writable {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *info.key,
+                action: "revive",
+            }
+            .into());
+        }
+
+        match self.get_exit_action(info) {
+            Some(FankorContextExitAction::Close { .. }) => self.remove_exit_action(info),
+            _ if self.is_account_tombstoned(info) => {}
+            _ => {
+                return Err(
+                    FankorErrorCode::AccountAlreadyInitialized { address: *info.key }.into(),
+                )
+            }
+        }
+
+        let mut serialized = Vec::with_capacity(info.data_len());
+        data.serialize(&mut serialized)?;
+
+        realloc_account_to_size(serialized.len(), false, info, Some(payer), system_program)?;
+
+        {
+            let mut info_data = info.try_borrow_mut_data()?;
+            let mut writer = ArrayWriter::new(&mut info_data);
+            writer.write_all(&serialized)?;
+        }
+
+        Ok(Account::new_unchecked(self, info, data))
+    }
+
     pub(crate) fn get_exit_action(
         &'info self,
         account: &AccountInfo<'info>,
@@ -132,8 +430,7 @@ impl<'info> FankorContext<'info> {
         let index = self.get_index_for_account(account);
         (*self.inner)
             .borrow()
-            .account_data
-            .get(&index)
+            .get_account_data(index)
             .and_then(|v| v.exit_action.clone())
     }
 
@@ -145,30 +442,132 @@ impl<'info> FankorContext<'info> {
         let index = self.get_index_for_account(account);
         let mut inner = (*self.inner).borrow_mut();
 
-        match inner.account_data.get_mut(&index) {
-            Some(v) => v.exit_action = Some(exit_action),
-            None => {
-                inner.account_data.insert(
-                    index,
-                    FankorContextAccountData {
-                        exit_action: Some(exit_action),
-                        seeds: None,
-                    },
-                );
+        inner
+            .get_account_data_mut(index, self.accounts.len())
+            .exit_action = Some(exit_action);
+    }
+
+    /// Finishes the exit phase of the instruction returning the first error
+    /// produced by the exit actions of the accounts dropped before it, e.g.
+    /// those moved out of the instruction accounts by the processor.
+    ///
+    /// It is called by the program after the exit phase of the instruction
+    /// accounts. See [`Instruction::exit`](crate::traits::Instruction::exit).
+    pub fn exit(&self) -> FankorResult<()> {
+        match (*self.inner).borrow_mut().exit_error.take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Finishes the exit phase of an instruction executed inside a batch and
+    /// forgets the accounts it has processed, so that the next instructions of
+    /// the batch can use them again.
+    pub fn finish_batch_entry(&self) -> FankorResult<()> {
+        self.exit()?;
+
+        let mut inner = (*self.inner).borrow_mut();
+
+        for data in inner.account_data.iter_mut() {
+            if matches!(
+                data.exit_action,
+                Some(FankorContextExitAction::Processed)
+                    | Some(FankorContextExitAction::ProcessedByZeroCopy)
+            ) {
+                data.exit_action = None;
             }
         }
+
+        Ok(())
+    }
+
+    /// Records an error produced by the exit actions of an account while it
+    /// is dropped. Only the first one is kept.
+    pub(crate) fn set_exit_error(&self, error: Error) {
+        let mut inner = (*self.inner).borrow_mut();
+
+        if inner.exit_error.is_none() {
+            inner.exit_error = Some(error);
+        }
     }
 
     pub(crate) fn remove_exit_action(&self, account: &AccountInfo<'info>) {
         let index = self.get_index_for_account(account);
         let mut inner = (*self.inner).borrow_mut();
 
-        if let Some(v) = inner.account_data.get_mut(&index) {
+        if let Some(v) = inner.account_data.get_mut(index as usize) {
             v.exit_action = None;
         }
     }
 
-    /// Sets the seeds associated with an account.
+    /// Gets the seeds, including the bump, of all the accounts that must sign
+    /// the instruction described by `metas` and are canonical PDAs of the
+    /// current program previously checked in this context.
+    pub fn get_pda_signer_seeds(&self, metas: &[AccountMeta]) -> Vec<Rc<Vec<u8>>> {
+        let inner = self.inner.borrow();
+        let mut indexes = Vec::new();
+        let mut result = Vec::new();
+
+        for meta in metas.iter().filter(|v| v.is_signer) {
+            let index = match self.accounts.iter().position(|a| a.key == &meta.pubkey) {
+                Some(v) => self.account_indexes[v],
+                None => continue,
+            };
+
+            if indexes.contains(&index) {
+                continue;
+            }
+
+            indexes.push(index);
+
+            let seeds = inner
+                .get_account_data(index)
+                .filter(|v| v.own_pda)
+                .and_then(|v| v.seeds.clone());
+
+            if let Some(seeds) = seeds {
+                result.push(seeds);
+            }
+        }
+
+        result
+    }
+
+    /// Invokes the `instruction` signing it with the provided `signer_seeds`
+    /// plus the stored seeds of every PDA of the current program that the
+    /// instruction requires as signer.
+    pub fn invoke_signed_with_pda_seeds(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signer_seeds: &[&[&[u8]]],
+    ) -> FankorResult<()> {
+        let pda_seeds = self.get_pda_signer_seeds(&instruction.accounts);
+
+        if pda_seeds.is_empty() {
+            return solana_program::program::invoke_signed(
+                instruction,
+                account_infos,
+                signer_seeds,
+            )
+            .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()));
+        }
+
+        let pda_seeds = pda_seeds
+            .iter()
+            .map(|v| byte_seeds_to_slices(v))
+            .collect::<Vec<_>>();
+
+        let mut all_signer_seeds = Vec::with_capacity(signer_seeds.len() + pda_seeds.len());
+        all_signer_seeds.extend_from_slice(signer_seeds);
+        all_signer_seeds.extend(pda_seeds.iter().map(|v| v.as_slice()));
+
+        solana_program::program::invoke_signed(instruction, account_infos, &all_signer_seeds)
+            .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+    }
+
+    /// Sets the seeds associated with an account. The seeds are considered
+    /// to derive the account from the current program.
     ///
     /// # Safety
     /// This method is intended to be used only by the framework.
@@ -179,19 +578,10 @@ impl<'info> FankorContext<'info> {
     ) {
         let index = self.get_index_for_account(account);
         let mut inner = (*self.inner).borrow_mut();
+        let data = inner.get_account_data_mut(index, self.accounts.len());
 
-        match inner.account_data.get_mut(&index) {
-            Some(v) => v.seeds = Some(seeds),
-            None => {
-                inner.account_data.insert(
-                    index,
-                    FankorContextAccountData {
-                        exit_action: None,
-                        seeds: Some(seeds),
-                    },
-                );
-            }
-        }
+        data.seeds = Some(seeds);
+        data.own_pda = true;
     }
 
     /// Checks whether the given account is a canonical PDA with the given seeds.
@@ -218,8 +608,7 @@ impl<'info> FankorContext<'info> {
         let saved_seeds = self
             .inner
             .borrow()
-            .account_data
-            .get(&index)
+            .get_account_data(index)
             .and_then(|v| v.seeds.clone());
 
         if let Some(saved_seeds) = saved_seeds {
@@ -238,26 +627,353 @@ impl<'info> FankorContext<'info> {
                 expected: expected_address,
                 actual: *account.key,
             }
-                .into());
+            .into());
         }
 
         // Add the seeds to the context.
         seeds.push(bump_seed);
 
+        let own_pda = program_id == self.program_id;
+        let mut inner = (*self.inner).borrow_mut();
+        let data = inner.get_account_data_mut(index, self.accounts.len());
+
+        data.seeds = Some(Rc::new(seeds));
+        data.own_pda = own_pda;
+
+        Ok(())
+    }
+
+    /// Borrows the data of `account` in zero-copy mode. Any number of shared
+    /// borrows can coexist but a mutable one is exclusive.
+    pub(crate) fn acquire_zc_borrow(
+        &self,
+        account: &AccountInfo<'info>,
+        mutable: bool,
+    ) -> FankorResult<()> {
+        let index = self.get_index_for_account(account);
         let mut inner = (*self.inner).borrow_mut();
-        match inner.account_data.get_mut(&index) {
-            Some(v) => v.seeds = Some(Rc::new(seeds)),
-            None => {
-                inner.account_data.insert(
-                    index,
-                    FankorContextAccountData {
-                        exit_action: None,
-                        seeds: Some(Rc::new(seeds)),
-                    },
-                );
+        let data = inner.get_account_data_mut(index, self.accounts.len());
+
+        data.zc_borrow = match (data.zc_borrow, mutable) {
+            (ZcBorrowState::Unborrowed, false) => ZcBorrowState::Shared(1),
+            (ZcBorrowState::Unborrowed, true) => ZcBorrowState::Mutable,
+            (ZcBorrowState::Shared(count), false) => ZcBorrowState::Shared(count + 1),
+            (ZcBorrowState::Shared(_), true) => {
+                return Err(FankorErrorCode::ZeroCopyAlreadyBorrowed {
+                    address: *account.key,
+                }
+                .into());
             }
-        }
+            (ZcBorrowState::Mutable, _) => {
+                return Err(FankorErrorCode::ZeroCopyAlreadyBorrowedMut {
+                    address: *account.key,
+                }
+                .into());
+            }
+        };
 
         Ok(())
     }
+
+    /// Releases a zero-copy borrow previously acquired with `acquire_zc_borrow`.
+    pub(crate) fn release_zc_borrow(&self, account: &AccountInfo<'info>) {
+        let index = self.get_index_for_account(account);
+        let mut inner = (*self.inner).borrow_mut();
+
+        if let Some(data) = inner.account_data.get_mut(index as usize) {
+            data.zc_borrow = match data.zc_borrow {
+                ZcBorrowState::Shared(count) if count > 1 => ZcBorrowState::Shared(count - 1),
+                _ => ZcBorrowState::Unborrowed,
+            };
+        }
+    }
+
+    /// Starts tracking a zero-copy view of `account` placed at `offset`.
+    pub(crate) fn track_zc_view(&self, account: &AccountInfo<'info>, offset: usize) -> u32 {
+        let index = self.get_index_for_account(account);
+        let mut inner = (*self.inner).borrow_mut();
+        let id = inner.next_zc_view_id;
+        inner.next_zc_view_id += 1;
+
+        // Drop the views invalidated by untracked changes.
+        let data = inner.zc_views.entry(index).or_default();
+        if data.data_len != account.data_len() {
+            data.views.clear();
+            data.data_len = account.data_len();
+        }
+
+        data.views.insert(id, offset);
+
+        id
+    }
+
+    /// Stops tracking a zero-copy view.
+    pub(crate) fn untrack_zc_view(&self, account: &AccountInfo<'info>, id: u32) {
+        let index = self.get_index_for_account(account);
+        let mut inner = (*self.inner).borrow_mut();
+
+        if let Some(data) = inner.zc_views.get_mut(&index) {
+            data.views.remove(&id);
+        }
+    }
+
+    /// Gets the current offset of a tracked zero-copy view.
+    ///
+    /// If the length of the account has changed outside the tracked operations,
+    /// all the views are invalidated because their offsets cannot be updated.
+    pub(crate) fn get_zc_view_offset(
+        &self,
+        account: &AccountInfo<'info>,
+        id: u32,
+    ) -> FankorResult<usize> {
+        let index = self.get_index_for_account(account);
+        let mut inner = (*self.inner).borrow_mut();
+        let error = || FankorErrorCode::ZeroCopyInvalidView {
+            address: *account.key,
+        };
+
+        let data = inner.zc_views.get_mut(&index).ok_or_else(error)?;
+
+        if data.data_len != account.data_len() {
+            data.views.clear();
+            return Err(error().into());
+        }
+
+        Ok(*data.views.get(&id).ok_or_else(error)?)
+    }
+
+    /// Updates the tracked zero-copy views of `account` after replacing the
+    /// `previous_size` bytes at `offset` with `new_size` bytes.
+    pub(crate) fn resize_zc_views(
+        &self,
+        account: &AccountInfo<'info>,
+        offset: usize,
+        previous_size: usize,
+        new_size: usize,
+    ) {
+        let index = self.get_index_for_account(account);
+        let mut inner = (*self.inner).borrow_mut();
+
+        if let Some(data) = inner.zc_views.get_mut(&index) {
+            data.views.retain(|_, view| {
+                match resize_zc_view_offset(*view, offset, previous_size, new_size) {
+                    Some(v) => {
+                        *view = v;
+                        true
+                    }
+                    None => false,
+                }
+            });
+
+            data.data_len = account.data_len();
+        }
+    }
+}
+
+impl<'info> FankorContextInnerMut<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    fn get_account_data(&self, index: u8) -> Option<&FankorContextAccountData<'info>> {
+        self.account_data.get(index as usize)
+    }
+
+    fn get_account_data_mut(
+        &mut self,
+        index: u8,
+        account_count: usize,
+    ) -> &mut FankorContextAccountData<'info> {
+        if self.account_data.is_empty() {
+            self.account_data
+                .resize_with(account_count, Default::default);
+        }
+
+        &mut self.account_data[index as usize]
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Computes the position of the first occurrence of every account.
+///
+/// The runtime places the keys of the accounts at increasing addresses and
+/// the duplicated accounts share the key of the first occurrence, so only the
+/// keys that break that order must be searched.
+fn compute_account_indexes(accounts: &[AccountInfo]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(accounts.len());
+    let mut last_key = std::ptr::null::<Pubkey>();
+
+    for (i, account) in accounts.iter().enumerate() {
+        let key = account.key as *const Pubkey;
+
+        if key > last_key {
+            last_key = key;
+            result.push(i as u8);
+        } else {
+            let index = accounts[..i]
+                .iter()
+                .position(|a| a.key == account.key)
+                .map_or(i as u8, |v| result[v]);
+            result.push(index);
+        }
+    }
+
+    result
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Computes the new offset of a view at `view` after replacing the `previous_size`
+/// bytes at `offset` with `new_size` bytes, or `None` if the view was inside the
+/// replaced region. A view at `offset` keeps pointing to the replaced value
+/// unless it has been removed or the region was empty, i.e. new bytes were
+/// inserted before it.
+fn resize_zc_view_offset(
+    view: usize,
+    offset: usize,
+    previous_size: usize,
+    new_size: usize,
+) -> Option<usize> {
+    if view < offset {
+        return Some(view);
+    }
+
+    if previous_size == 0 {
+        return Some(view + new_size);
+    }
+
+    if view == offset && new_size > 0 {
+        return Some(view);
+    }
+
+    if view < offset + previous_size {
+        return None;
+    }
+
+    Some(view - previous_size + new_size)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_account_indexes() {
+        let keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut lamports = [0; 3];
+        let mut data = [[0u8; 1]; 3];
+        let mut lamports = lamports.iter_mut();
+        let mut data = data.iter_mut();
+        let mut infos = keys
+            .iter()
+            .map(|key| {
+                AccountInfo::new(
+                    key,
+                    false,
+                    false,
+                    lamports.next().unwrap(),
+                    data.next().unwrap(),
+                    key,
+                    false,
+                    0,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Duplicated accounts are clones of the first occurrence.
+        infos.insert(2, infos[0].clone());
+        infos.push(infos[1].clone());
+
+        assert_eq!(compute_account_indexes(&infos), vec![0, 1, 0, 3, 1]);
+
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &infos);
+
+        for (info, index) in infos.iter().zip([0, 1, 0, 3, 1]) {
+            assert_eq!(context.get_index_for_account(info), index);
+            assert_eq!(context.get_index_for_account(&info.clone()), index);
+        }
+
+        // Duplicated accounts share their data.
+        context.set_seeds_for_account_unchecked(&infos[4], Rc::new(vec![1, 2]));
+        assert_eq!(
+            context.get_seeds_for_account(&infos[1]),
+            Some(Rc::new(vec![1, 2]))
+        );
+        assert_eq!(context.get_seeds_for_account(&infos[0]), None);
+    }
+
+    #[test]
+    fn test_exit_errors() {
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &[]);
+
+        context.set_exit_error(FankorErrorCode::UnusedAccounts.into());
+        context.set_exit_error(FankorErrorCode::NotEnoughAccountKeys.into());
+
+        assert_eq!(context.exit(), Err(FankorErrorCode::UnusedAccounts.into()));
+        assert_eq!(context.exit(), Ok(()));
+    }
+
+    #[test]
+    fn test_finish_batch_entry() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = [0u8; 1];
+        let infos = [AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &key,
+            false,
+            0,
+        )];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &infos);
+        let context: &FankorContext = unsafe { std::mem::transmute(&context) };
+
+        context.set_exit_action(&infos[0], FankorContextExitAction::Processed);
+        context.set_exit_error(FankorErrorCode::UnusedAccounts.into());
+
+        assert_eq!(
+            context.finish_batch_entry(),
+            Err(FankorErrorCode::UnusedAccounts.into())
+        );
+        assert!(context.get_exit_action(&infos[0]).is_some());
+
+        context.finish_batch_entry().unwrap();
+        assert!(context.get_exit_action(&infos[0]).is_none());
+    }
+
+    #[test]
+    fn test_resize_zc_view_offset() {
+        // Insert bytes.
+        assert_eq!(resize_zc_view_offset(5, 10, 0, 4), Some(5));
+        assert_eq!(resize_zc_view_offset(10, 10, 0, 4), Some(14));
+        assert_eq!(resize_zc_view_offset(20, 10, 0, 4), Some(24));
+
+        // Replace bytes.
+        assert_eq!(resize_zc_view_offset(10, 10, 4, 2), Some(10));
+        assert_eq!(resize_zc_view_offset(12, 10, 4, 2), None);
+        assert_eq!(resize_zc_view_offset(14, 10, 4, 2), Some(12));
+        assert_eq!(resize_zc_view_offset(14, 10, 4, 8), Some(18));
+
+        // Remove bytes.
+        assert_eq!(resize_zc_view_offset(10, 10, 4, 0), None);
+        assert_eq!(resize_zc_view_offset(13, 10, 4, 0), None);
+        assert_eq!(resize_zc_view_offset(14, 10, 4, 0), Some(10));
+    }
 }
@@ -0,0 +1,249 @@
+use std::borrow::Cow;
+use std::cell::Ref;
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+
+use solana_address_lookup_table_program::state::{LookupTableMeta, LOOKUP_TABLE_META_SIZE};
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::{Epoch, Slot};
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::FankorContext;
+use crate::traits::{
+    AccountInfoVerification, Instruction, PdaChecker, ProgramType, SingleInstructionAccount,
+};
+
+#[derive(Debug, Copy, Clone)]
+pub struct AddressLookupTableProgram;
+
+impl ProgramType for AddressLookupTableProgram {
+    fn name() -> &'static str {
+        "AddressLookupTable"
+    }
+
+    fn address() -> &'static Pubkey {
+        &solana_address_lookup_table_program::ID
+    }
+}
+
+impl AddressLookupTableProgram {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn get_pda_seeds<'a>(authority: &'a Pubkey, recent_slot: &'a [u8; 8]) -> [&'a [u8]; 2] {
+        [authority.as_ref(), recent_slot]
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ACCOUNTS -------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An initialized address lookup table whose addresses are read in Zero Copy mode.
+#[derive(Clone)]
+pub struct AddressLookupTable<'info> {
+    context: &'info FankorContext<'info>,
+    info: &'info AccountInfo<'info>,
+}
+
+impl<'info> AddressLookupTable<'info> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new address lookup table account checking its owner and content.
+    pub fn new(
+        context: &'info FankorContext<'info>,
+        info: &'info AccountInfo<'info>,
+    ) -> FankorResult<AddressLookupTable<'info>> {
+        if info.owner != AddressLookupTableProgram::address() {
+            return Err(FankorErrorCode::AccountOwnedByWrongProgram {
+                address: *info.key,
+                expected: *AddressLookupTableProgram::address(),
+                actual: *info.owner,
+            }
+            .into());
+        }
+
+        {
+            let data = info.try_borrow_data()?;
+
+            if solana_address_lookup_table_program::state::AddressLookupTable::deserialize(&data)
+                .is_err()
+            {
+                return Err(FankorErrorCode::AccountNotInitialized { address: *info.key }.into());
+            }
+        }
+
+        Ok(AddressLookupTable { context, info })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn address(&self) -> &'info Pubkey {
+        self.info().key
+    }
+
+    pub fn owner(&self) -> &'info Pubkey {
+        self.info().owner
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.info().is_writable
+    }
+
+    pub fn is_signer(&self) -> bool {
+        self.info().is_signer
+    }
+
+    pub fn is_executable(&self) -> bool {
+        self.info().executable
+    }
+
+    pub fn balance(&self) -> u64 {
+        self.info().lamports()
+    }
+
+    pub fn rent_epoch(&self) -> Epoch {
+        self.info.rent_epoch
+    }
+
+    pub fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    pub fn context(&self) -> &'info FankorContext<'info> {
+        self.context
+    }
+
+    /// The metadata of the lookup table.
+    pub fn meta(&self) -> FankorResult<LookupTableMeta> {
+        let data = self.info.try_borrow_data()?;
+        let table = Self::deserialize(self.info, &data)?;
+
+        Ok(table.meta)
+    }
+
+    /// The authority of the lookup table. `None` means the table is frozen.
+    pub fn authority(&self) -> FankorResult<Option<Pubkey>> {
+        Ok(self.meta()?.authority)
+    }
+
+    /// Whether the lookup table is frozen or not.
+    pub fn is_frozen(&self) -> FankorResult<bool> {
+        Ok(self.authority()?.is_none())
+    }
+
+    /// The slot in which the lookup table was deactivated or `Slot::MAX` if
+    /// it is still active.
+    pub fn deactivation_slot(&self) -> FankorResult<Slot> {
+        Ok(self.meta()?.deactivation_slot)
+    }
+
+    /// The number of addresses stored in the lookup table.
+    pub fn len(&self) -> usize {
+        self.info.data_len().saturating_sub(LOOKUP_TABLE_META_SIZE) / PUBKEY_BYTES
+    }
+
+    /// Whether the lookup table does not contain any address.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the address at `index` without deserializing the rest of the table.
+    pub fn get(&self, index: usize) -> Option<Pubkey> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let data = self.info.try_borrow_data().ok()?;
+        let offset = LOOKUP_TABLE_META_SIZE + index * PUBKEY_BYTES;
+        let bytes: [u8; PUBKEY_BYTES] = data[offset..offset + PUBKEY_BYTES].try_into().ok()?;
+
+        Some(Pubkey::new_from_array(bytes))
+    }
+
+    /// The list of addresses stored in the lookup table, borrowed directly
+    /// from the account data.
+    pub fn addresses(&self) -> FankorResult<Ref<[Pubkey]>> {
+        let data = self.info.try_borrow_data()?;
+
+        Ref::filter_map(data, |data| {
+            match solana_address_lookup_table_program::state::AddressLookupTable::deserialize(data)
+            {
+                Ok(table) => match table.addresses {
+                    Cow::Borrowed(addresses) => Some(addresses),
+                    Cow::Owned(_) => None,
+                },
+                Err(_) => None,
+            }
+        })
+        .map_err(|_| {
+            FankorErrorCode::ZeroCopyCannotDeserialize {
+                type_name: "AddressLookupTable",
+            }
+            .into()
+        })
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn deserialize<'a>(
+        info: &AccountInfo,
+        data: &'a [u8],
+    ) -> FankorResult<solana_address_lookup_table_program::state::AddressLookupTable<'a>> {
+        solana_address_lookup_table_program::state::AddressLookupTable::deserialize(data)
+            .map_err(|_| FankorErrorCode::AccountNotInitialized { address: *info.key }.into())
+    }
+}
+
+impl<'info> Instruction<'info> for AddressLookupTable<'info> {
+    type CPI = AccountInfo<'info>;
+    type LPI = Pubkey;
+
+    fn verify_account_infos<'a>(
+        &self,
+        config: &mut AccountInfoVerification<'a, 'info>,
+    ) -> FankorResult<()> {
+        config.verify(self.info)
+    }
+
+    #[inline(never)]
+    fn try_from(
+        context: &'info FankorContext<'info>,
+        _buf: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        if accounts.is_empty() {
+            return Err(FankorErrorCode::NotEnoughAccountKeys.into());
+        }
+
+        let info = &accounts[0];
+        let result = AddressLookupTable::new(context, info)?;
+
+        *accounts = &accounts[1..];
+        Ok(result)
+    }
+}
+
+impl<'info> SingleInstructionAccount<'info> for AddressLookupTable<'info> {
+    fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    fn context(&self) -> &'info FankorContext<'info> {
+        self.context
+    }
+}
+
+impl<'info> PdaChecker<'info> for AddressLookupTable<'info> {
+    fn pda_info(&self) -> Option<&'info AccountInfo<'info>> {
+        Some(self.info)
+    }
+}
+
+impl<'info> Debug for AddressLookupTable<'info> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AddressLookupTable")
+            .field("info", &self.info)
+            .finish()
+    }
+}
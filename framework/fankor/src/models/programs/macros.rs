@@ -126,6 +126,71 @@ macro_rules! impl_account {
         }
     };
 
+    (stake: $name: ident, $ty: ty, $owner: expr $(,)?) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name($ty);
+
+        impl $name {
+            pub fn new(data: $ty) -> Self {
+                Self(data)
+            }
+        }
+
+        impl crate::traits::AccountType for $name {
+            fn discriminant() -> u8 {
+                0
+            }
+
+            fn owner() -> &'static Pubkey {
+                $owner
+            }
+        }
+
+        // Stake accounts are encoded with bincode instead of borsh.
+        #[cfg(any(feature = "test-utils", test))]
+        impl BorshSerialize for $name {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                let mut buf = vec![0u8; <$ty>::size_of()];
+                bincode::serialize_into(&mut buf[..], &self.0)
+                    .map_err(|e| std::io::Error::new(ErrorKind::Other, e))?;
+
+                writer.write_all(&buf)?;
+
+                Ok(())
+            }
+        }
+
+        #[cfg(not(any(feature = "test-utils", test)))]
+        impl BorshSerialize for $name {
+            fn serialize<W: Write>(&self, _writer: &mut W) -> std::io::Result<()> {
+                unreachable!("Cannot write accounts that does not belong to the current program")
+            }
+        }
+
+        impl BorshDeserialize for $name {
+            fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+                let data = buf.get(..<$ty>::size_of()).ok_or_else(|| {
+                    std::io::Error::new(ErrorKind::InvalidData, "Unexpected length of input")
+                })?;
+                let result = bincode::deserialize::<$ty>(data)
+                    .map($name)
+                    .map_err(|e| std::io::Error::new(ErrorKind::Other, e))?;
+
+                *buf = &buf[<$ty>::size_of()..];
+
+                Ok(result)
+            }
+        }
+
+        impl Deref for $name {
+            type Target = $ty;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+    };
+
     (meta: $name: ident, $ty: ty, $owner: expr $(,)?) => {
         #[derive(Debug, Clone, Eq, PartialEq)]
         pub struct $name($ty);
@@ -4,7 +4,7 @@ use std::ops::Deref;
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_token_metadata::state::{
     Collection, CollectionDetails, Creator, TokenMetadataAccount, Uses, BURN, COLLECTION_AUTHORITY,
-    EDITION, PREFIX, USER,
+    EDITION, PREFIX, TOKEN_RECORD_SEED, USER,
 };
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
@@ -85,6 +85,34 @@ impl Metadata {
         ]
     }
 
+    pub fn get_token_record_pda_seeds<'a>(mint: &'a Pubkey, token: &'a Pubkey) -> [&'a [u8]; 5] {
+        [
+            PREFIX.as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            mint.as_ref(),
+            TOKEN_RECORD_SEED.as_bytes(),
+            token.as_ref(),
+        ]
+    }
+
+    /// The `role` is the string representation of the delegate role, i.e.
+    /// `MetadataDelegateRole::to_string()`.
+    pub fn get_metadata_delegate_record_pda_seeds<'a>(
+        mint: &'a Pubkey,
+        role: &'a str,
+        update_authority: &'a Pubkey,
+        delegate: &'a Pubkey,
+    ) -> [&'a [u8]; 6] {
+        [
+            PREFIX.as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            mint.as_ref(),
+            role.as_bytes(),
+            update_authority.as_ref(),
+            delegate.as_ref(),
+        ]
+    }
+
     pub fn get_program_as_burner_pda_seeds<'a>() -> [&'a [u8]; 3] {
         [
             PREFIX.as_bytes(),
@@ -152,6 +180,18 @@ impl_account!(
     &mpl_token_metadata::ID,
 );
 
+impl_account!(
+    meta: TokenRecord,
+    mpl_token_metadata::state::TokenRecord,
+    &mpl_token_metadata::ID,
+);
+
+impl_account!(
+    meta: MetadataDelegateRecord,
+    mpl_token_metadata::state::MetadataDelegateRecord,
+    &mpl_token_metadata::ID,
+);
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
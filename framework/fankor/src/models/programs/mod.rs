@@ -1,13 +1,19 @@
+#[cfg(feature = "address-lookup-table-program")]
+pub use address_lookup_table::*;
 #[cfg(any(feature = "spl-associated-token-account"))]
 pub use associated_token::*;
 #[cfg(feature = "metadata-program")]
 pub use metadata::*;
+#[cfg(feature = "stake-program")]
+pub use stake::*;
 pub use system_program::*;
 #[cfg(feature = "token-program")]
 pub use token::*;
 #[cfg(feature = "token-program-2022")]
 pub use token_2022::*;
 
+#[cfg(feature = "address-lookup-table-program")]
+mod address_lookup_table;
 mod system_program;
 #[cfg(feature = "token-program")]
 mod token;
@@ -16,6 +22,9 @@ mod macros;
 #[cfg(feature = "metadata-program")]
 mod metadata;
 
+#[cfg(feature = "stake-program")]
+mod stake;
+
 #[cfg(feature = "token-program")]
 mod associated_token;
 
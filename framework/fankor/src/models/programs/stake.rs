@@ -0,0 +1,79 @@
+use std::io::{ErrorKind, Write};
+use std::ops::Deref;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::models::programs::macros::impl_account;
+use crate::traits::ProgramType;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Stake;
+
+impl ProgramType for Stake {
+    fn name() -> &'static str {
+        "Stake"
+    }
+
+    fn address() -> &'static Pubkey {
+        &solana_program::stake::program::ID
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ACCOUNTS -------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl_account!(
+    stake: StakeAccount,
+    solana_program::stake::state::StakeState,
+    &solana_program::stake::program::ID,
+);
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::stake::state::{
+        Authorized, Delegation, Lockup, Meta, Stake as StakeData, StakeState,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_stake_account() {
+        let state = StakeState::Stake(
+            Meta {
+                rent_exempt_reserve: 2282880,
+                authorized: Authorized::auto(&Pubkey::new_unique()),
+                lockup: Lockup::default(),
+            },
+            StakeData {
+                delegation: Delegation::new(&Pubkey::new_unique(), 1_000_000_000, 10),
+                credits_observed: 5,
+            },
+        );
+
+        // Stake accounts are created by the runtime with bincode.
+        let mut data = vec![0u8; StakeState::size_of()];
+        bincode::serialize_into(&mut data[..], &state).unwrap();
+        assert_eq!(&data[..4], &[2, 0, 0, 0], "Invalid discriminant");
+
+        let mut buf = data.as_slice();
+        let account = StakeAccount::deserialize(&mut buf).expect("Cannot deserialize");
+
+        assert_eq!(*account, state, "Invalid state");
+        assert!(buf.is_empty(), "The account must be fully read");
+        assert_eq!(account.try_to_vec().unwrap(), data, "Invalid serialization");
+    }
+
+    #[test]
+    fn test_deserialize_stake_account_invalid_length() {
+        let data = vec![0u8; StakeState::size_of() - 1];
+        let mut buf = data.as_slice();
+
+        StakeAccount::deserialize(&mut buf).expect_err("The data must be rejected");
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt::Debug;
+use std::io::{ErrorKind, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// A set of `N * 8` bits stored in exactly `N` bytes. Bit `i` is stored in the
+/// byte `i / 8` at position `i % 8`, i.e. the bytes are a little-endian integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FnkBitSet<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> FnkBitSet<N> {
+    pub const CAPACITY: usize = N * 8;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(inner: [u8; N]) -> Self {
+        Self(inner)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The number of bits that can be stored in the set.
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+
+    /// The number of bits set.
+    pub fn count_ones(&self) -> usize {
+        self.0.iter().map(|v| v.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|v| *v == 0)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Checks whether the bit at `index` is set.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn test(&self, index: usize) -> bool {
+        let (byte, mask) = bit_position(index);
+        self.0[byte] & mask != 0
+    }
+
+    /// Sets the bit at `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn set(&mut self, index: usize) {
+        let (byte, mask) = bit_position(index);
+        self.0[byte] |= mask;
+    }
+
+    /// Clears the bit at `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn clear(&mut self, index: usize) {
+        let (byte, mask) = bit_position(index);
+        self.0[byte] &= !mask;
+    }
+
+    /// Toggles the bit at `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn toggle(&mut self, index: usize) {
+        let (byte, mask) = bit_position(index);
+        self.0[byte] ^= mask;
+    }
+
+    /// Clears all the bits.
+    pub fn clear_all(&mut self) {
+        self.0 = [0; N];
+    }
+
+    /// Iterates over the indexes of the bits that are set.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..Self::CAPACITY).filter(|i| self.test(*i))
+    }
+
+    pub fn into_inner(self) -> [u8; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> Default for FnkBitSet<N> {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> Debug for FnkBitSet<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter_ones()).finish()
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for FnkBitSet<N> {
+    fn from(v: [u8; N]) -> Self {
+        Self(v)
+    }
+}
+
+impl<const N: usize> From<FnkBitSet<N>> for [u8; N] {
+    fn from(v: FnkBitSet<N>) -> Self {
+        v.0
+    }
+}
+
+impl<const N: usize> BorshSerialize for FnkBitSet<N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FnkBitSet<N> {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.len() < N {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "Unexpected length of input",
+            ));
+        }
+
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(&buf[..N]);
+
+        *buf = &buf[N..];
+        Ok(Self(bytes))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Gets the byte and the mask of a bit.
+pub(crate) fn bit_position(index: usize) -> (usize, u8) {
+    (index / 8, 1 << (index % 8))
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_set_clear_test() {
+        let mut set = FnkBitSet::<2>::default();
+
+        set.set(0);
+        set.set(9);
+        set.set(15);
+        assert!(set.test(0));
+        assert!(set.test(9));
+        assert!(!set.test(1));
+        assert_eq!(set.count_ones(), 3);
+        assert_eq!(set.0, [0b0000_0001, 0b1000_0010]);
+
+        set.clear(9);
+        set.toggle(1);
+        assert_eq!(set.iter_ones().collect::<Vec<_>>(), vec![0, 1, 15]);
+
+        set.clear_all();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let mut set = FnkBitSet::<3>::default();
+        set.set(20);
+
+        let buffer = set.try_to_vec().unwrap();
+        assert_eq!(buffer, vec![0, 0, 0b0001_0000]);
+
+        let mut de_buf = buffer.as_slice();
+        let deserialized = FnkBitSet::<3>::deserialize(&mut de_buf).unwrap();
+
+        assert_eq!(deserialized, set);
+        assert!(de_buf.is_empty(), "Buffer not empty");
+
+        let mut de_buf: &[u8] = &[0, 0];
+        assert!(FnkBitSet::<3>::deserialize(&mut de_buf).is_err());
+    }
+}
@@ -0,0 +1,380 @@
+use std::fmt::Display;
+use std::io::Write;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::models::types::FnkInt;
+
+/// Rounding mode applied when an operation over decimals cannot be represented
+/// exactly with the target scale.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FnkRounding {
+    /// Rounds towards zero.
+    Down,
+    /// Rounds away from zero.
+    Up,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+    /// Rounds to the nearest value, ties away from zero.
+    HalfUp,
+    /// Rounds to the nearest value, ties to the even neighbour.
+    HalfEven,
+}
+
+/// Fixed-point decimal number with `SCALE` fractional digits. The value is
+/// stored as a signed mantissa, i.e. `value = mantissa / 10^SCALE`, so every
+/// operation is deterministic.
+///
+/// `SCALE` must be in range [0, 18] to fit `10^SCALE` in an `i64`, otherwise
+/// the constructors fail to compile.
+///
+/// ## Encoding
+///
+/// The mantissa is encoded as a [`FnkInt`]. The scale is not part of the encoded
+/// form, so it must match between the writer and the reader.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FnkDecimal<const SCALE: u8>(pub i64);
+
+impl<const SCALE: u8> FnkDecimal<SCALE> {
+    /// Evaluated by every constructor so an invalid scale fails to compile.
+    const SCALE_CHECK: () = assert!(SCALE <= 18, "The scale must be in range [0, 18]");
+    const FACTOR: i64 = {
+        let () = Self::SCALE_CHECK;
+        10i64.pow(SCALE as u32)
+    };
+
+    pub const ZERO: Self = Self::from_mantissa(0);
+    pub const ONE: Self = Self::from_mantissa(Self::FACTOR);
+    pub const MIN: Self = Self::from_mantissa(i64::MIN);
+    pub const MAX: Self = Self::from_mantissa(i64::MAX);
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new decimal from its raw mantissa.
+    pub const fn from_mantissa(mantissa: i64) -> Self {
+        let () = Self::SCALE_CHECK;
+        Self(mantissa)
+    }
+
+    /// Creates a new decimal from an integer value.
+    pub fn from_integer(value: i64) -> Option<Self> {
+        value.checked_mul(Self::FACTOR).map(Self::from_mantissa)
+    }
+
+    /// Creates a new decimal from the fraction `numerator / denominator`.
+    pub fn from_fraction(numerator: i64, denominator: i64, rounding: FnkRounding) -> Option<Self> {
+        let numerator = numerator as i128 * Self::FACTOR as i128;
+        let value = div_rounded(numerator, denominator as i128, rounding)?;
+
+        i64::try_from(value).ok().map(Self)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn mantissa(&self) -> i64 {
+        self.0
+    }
+
+    pub fn scale(&self) -> u8 {
+        SCALE
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    pub fn checked_mul(&self, other: Self, rounding: FnkRounding) -> Option<Self> {
+        let value = div_rounded(
+            self.0 as i128 * other.0 as i128,
+            Self::FACTOR as i128,
+            rounding,
+        )?;
+
+        i64::try_from(value).ok().map(Self)
+    }
+
+    pub fn checked_div(&self, other: Self, rounding: FnkRounding) -> Option<Self> {
+        let value = div_rounded(
+            self.0 as i128 * Self::FACTOR as i128,
+            other.0 as i128,
+            rounding,
+        )?;
+
+        i64::try_from(value).ok().map(Self)
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+
+    pub fn checked_abs(&self) -> Option<Self> {
+        self.0.checked_abs().map(Self)
+    }
+
+    /// Rounds the decimal to an integer.
+    pub fn to_integer(&self, rounding: FnkRounding) -> i64 {
+        // Cannot fail because the factor is always greater than 0.
+        div_rounded(self.0 as i128, Self::FACTOR as i128, rounding).unwrap() as i64
+    }
+
+    /// Converts the decimal to another scale, rounding if the new scale is lower.
+    pub fn rescale<const NEW_SCALE: u8>(
+        &self,
+        rounding: FnkRounding,
+    ) -> Option<FnkDecimal<NEW_SCALE>> {
+        let value = if NEW_SCALE >= SCALE {
+            let factor = 10i128.checked_pow((NEW_SCALE - SCALE) as u32)?;
+            (self.0 as i128).checked_mul(factor)?
+        } else {
+            let factor = 10i128.checked_pow((SCALE - NEW_SCALE) as u32)?;
+            div_rounded(self.0 as i128, factor, rounding)?
+        };
+
+        i64::try_from(value).ok().map(FnkDecimal::from_mantissa)
+    }
+}
+
+impl<const SCALE: u8> Default for FnkDecimal<SCALE> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const SCALE: u8> Display for FnkDecimal<SCALE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let abs = self.0.unsigned_abs();
+
+        if SCALE == 0 {
+            return write!(f, "{}{}", sign, abs);
+        }
+
+        let factor = Self::FACTOR as u64;
+        write!(
+            f,
+            "{}{}.{:0width$}",
+            sign,
+            abs / factor,
+            abs % factor,
+            width = SCALE as usize
+        )
+    }
+}
+
+impl<const SCALE: u8> BorshSerialize for FnkDecimal<SCALE> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        FnkInt::new(self.0).serialize(writer)
+    }
+}
+
+impl<const SCALE: u8> BorshDeserialize for FnkDecimal<SCALE> {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let mantissa = FnkInt::deserialize(buf)?;
+        Ok(Self::from_mantissa(mantissa.0))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Computes `numerator / denominator` applying the rounding mode to the remainder.
+fn div_rounded(numerator: i128, denominator: i128, rounding: FnkRounding) -> Option<i128> {
+    if denominator == 0 {
+        return None;
+    }
+
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator % denominator;
+
+    if remainder == 0 {
+        return Some(quotient);
+    }
+
+    let is_positive = (numerator < 0) == (denominator < 0);
+    let away_from_zero = if is_positive {
+        quotient + 1
+    } else {
+        quotient - 1
+    };
+
+    let result = match rounding {
+        FnkRounding::Down => quotient,
+        FnkRounding::Up => away_from_zero,
+        FnkRounding::Floor => {
+            if is_positive {
+                quotient
+            } else {
+                away_from_zero
+            }
+        }
+        FnkRounding::Ceil => {
+            if is_positive {
+                away_from_zero
+            } else {
+                quotient
+            }
+        }
+        FnkRounding::HalfUp | FnkRounding::HalfEven => {
+            let double_remainder = remainder.unsigned_abs() * 2;
+            let denominator = denominator.unsigned_abs();
+
+            if double_remainder > denominator
+                || (double_remainder == denominator
+                    && (rounding == FnkRounding::HalfUp || quotient % 2 != 0))
+            {
+                away_from_zero
+            } else {
+                quotient
+            }
+        }
+    };
+
+    Some(result)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Decimal = FnkDecimal<2>;
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(Decimal::from_integer(3), Some(Decimal::from_mantissa(300)));
+        assert_eq!(Decimal::from_integer(i64::MAX), None);
+        assert_eq!(
+            Decimal::from_fraction(1, 3, FnkRounding::HalfUp),
+            Some(Decimal::from_mantissa(33))
+        );
+        assert_eq!(
+            Decimal::from_fraction(2, 3, FnkRounding::Down),
+            Some(Decimal::from_mantissa(66))
+        );
+        assert_eq!(Decimal::from_fraction(1, 0, FnkRounding::Down), None);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Decimal::from_mantissa(150); // 1.50
+        let b = Decimal::from_mantissa(-25); // -0.25
+
+        assert_eq!(a.checked_add(b), Some(Decimal::from_mantissa(125)));
+        assert_eq!(a.checked_sub(b), Some(Decimal::from_mantissa(175)));
+        assert_eq!(
+            a.checked_mul(b, FnkRounding::Down),
+            Some(Decimal::from_mantissa(-37))
+        );
+        assert_eq!(
+            a.checked_mul(b, FnkRounding::Floor),
+            Some(Decimal::from_mantissa(-38))
+        );
+        assert_eq!(
+            a.checked_div(b, FnkRounding::Down),
+            Some(Decimal::from_mantissa(-600))
+        );
+        assert_eq!(a.checked_div(Decimal::ZERO, FnkRounding::Down), None);
+        assert_eq!(Decimal::MAX.checked_add(Decimal::ONE), None);
+        assert_eq!(Decimal::MIN.checked_neg(), None);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let cases = [
+            // value, down, up, floor, ceil, half up, half even
+            (250, 2, 3, 2, 3, 3, 2),
+            (350, 3, 4, 3, 4, 4, 4),
+            (251, 2, 3, 2, 3, 3, 3),
+            (249, 2, 3, 2, 3, 2, 2),
+            (-250, -2, -3, -3, -2, -3, -2),
+            (-251, -2, -3, -3, -2, -3, -3),
+            (300, 3, 3, 3, 3, 3, 3),
+        ];
+
+        for (value, down, up, floor, ceil, half_up, half_even) in cases {
+            let decimal = Decimal::from_mantissa(value);
+            assert_eq!(decimal.to_integer(FnkRounding::Down), down, "{}", value);
+            assert_eq!(decimal.to_integer(FnkRounding::Up), up, "{}", value);
+            assert_eq!(decimal.to_integer(FnkRounding::Floor), floor, "{}", value);
+            assert_eq!(decimal.to_integer(FnkRounding::Ceil), ceil, "{}", value);
+            assert_eq!(
+                decimal.to_integer(FnkRounding::HalfUp),
+                half_up,
+                "{}",
+                value
+            );
+            assert_eq!(
+                decimal.to_integer(FnkRounding::HalfEven),
+                half_even,
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_rescale() {
+        let decimal = Decimal::from_mantissa(-1255);
+
+        assert_eq!(
+            decimal.rescale::<4>(FnkRounding::Down),
+            Some(FnkDecimal::<4>::from_mantissa(-125500))
+        );
+        assert_eq!(
+            decimal.rescale::<1>(FnkRounding::HalfEven),
+            Some(FnkDecimal::<1>::from_mantissa(-126))
+        );
+        assert_eq!(Decimal::MAX.rescale::<3>(FnkRounding::Down), None);
+        assert_eq!(Decimal::MAX.rescale::<18>(FnkRounding::Down), None);
+        assert_eq!(Decimal::MIN.rescale::<18>(FnkRounding::Down), None);
+        assert_eq!(
+            FnkDecimal::<18>::MAX.rescale::<0>(FnkRounding::Down),
+            Some(FnkDecimal::<0>::from_mantissa(9))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Decimal::from_mantissa(0).to_string(), "0.00");
+        assert_eq!(Decimal::from_mantissa(5).to_string(), "0.05");
+        assert_eq!(Decimal::from_mantissa(-1234).to_string(), "-12.34");
+        assert_eq!(FnkDecimal::<0>::from_mantissa(-7).to_string(), "-7");
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        for mantissa in [0, 1, -1, 8191, -8192, i64::MAX, i64::MIN] {
+            let decimal = Decimal::from_mantissa(mantissa);
+            let buffer = decimal.try_to_vec().unwrap();
+
+            assert_eq!(buffer, FnkInt::new(mantissa).try_to_vec().unwrap());
+
+            let mut de_buf = buffer.as_slice();
+            let deserialized = Decimal::deserialize(&mut de_buf).unwrap();
+
+            assert_eq!(deserialized, decimal);
+            assert!(de_buf.is_empty(), "Buffer not empty for {}", mantissa);
+        }
+    }
+}
@@ -8,6 +8,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 const FLAG_ENCODING_LIMIT: u64 = 1 << 13;
 // 2^13
 const MIN_I64_ABS: u64 = i64::MIN.unsigned_abs();
+const MIN_I128_ABS: u128 = i128::MIN.unsigned_abs();
 
 /// Wrapper over a signed number that serializes to a variable-length form.
 ///
@@ -421,6 +422,267 @@ impl BorshDeserialize for FnkInt {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Wrapper over a 128-bit signed number that serializes to a variable-length form.
+///
+/// ## Encoding
+///
+/// The encoding is the same as [`FnkInt`] but the length encoding can represent up to 16 bytes,
+/// so any number that fits in 64 bits is encoded exactly as a [`FnkInt`].
+///
+/// ### Length encoding
+///
+/// The length must be in range [0, 14] which actually represents the range [2, 16], other values
+/// are forbidden.
+///
+/// ```none
+/// 1S00 ssss + ssss bytes
+///  \_ sign bit
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FnkInt128(pub i128);
+
+impl FnkInt128 {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(inner: i128) -> Self {
+        Self(inner)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn get_i64(&self) -> Option<i64> {
+        i64::try_from(self.0).ok()
+    }
+
+    pub fn get_u128(&self) -> Option<u128> {
+        u128::try_from(self.0).ok()
+    }
+
+    pub fn get_i128(&self) -> i128 {
+        self.0
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn into_inner(self) -> i128 {
+        self.0
+    }
+}
+
+impl AsRef<i128> for FnkInt128 {
+    fn as_ref(&self) -> &i128 {
+        &self.0
+    }
+}
+
+impl Deref for FnkInt128 {
+    type Target = i128;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for FnkInt128 {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Display for FnkInt128 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<i8> for FnkInt128 {
+    fn from(v: i8) -> Self {
+        Self(v as i128)
+    }
+}
+
+impl From<i16> for FnkInt128 {
+    fn from(v: i16) -> Self {
+        Self(v as i128)
+    }
+}
+
+impl From<i32> for FnkInt128 {
+    fn from(v: i32) -> Self {
+        Self(v as i128)
+    }
+}
+
+impl From<i64> for FnkInt128 {
+    fn from(v: i64) -> Self {
+        Self(v as i128)
+    }
+}
+
+impl From<i128> for FnkInt128 {
+    fn from(v: i128) -> Self {
+        Self(v)
+    }
+}
+
+impl From<isize> for FnkInt128 {
+    fn from(v: isize) -> Self {
+        Self(v as i128)
+    }
+}
+
+impl From<u8> for FnkInt128 {
+    fn from(v: u8) -> Self {
+        Self(v as i128)
+    }
+}
+
+impl From<u16> for FnkInt128 {
+    fn from(v: u16) -> Self {
+        Self(v as i128)
+    }
+}
+
+impl From<u32> for FnkInt128 {
+    fn from(v: u32) -> Self {
+        Self(v as i128)
+    }
+}
+
+impl From<u64> for FnkInt128 {
+    fn from(v: u64) -> Self {
+        Self(v as i128)
+    }
+}
+
+impl From<usize> for FnkInt128 {
+    fn from(v: usize) -> Self {
+        Self(v as i128)
+    }
+}
+
+impl From<FnkInt> for FnkInt128 {
+    fn from(v: FnkInt) -> Self {
+        Self(v.0 as i128)
+    }
+}
+
+impl TryFrom<u128> for FnkInt128 {
+    type Error = ();
+
+    fn try_from(v: u128) -> Result<Self, Self::Error> {
+        i128::try_from(v).map(Self).map_err(|_| ())
+    }
+}
+
+impl BorshSerialize for FnkInt128 {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // Numbers that fit in 64 bits are encoded as a FnkInt.
+        if let Ok(number) = i64::try_from(self.0) {
+            return FnkInt(number).serialize(writer);
+        }
+
+        // Length encoding.
+        let number = self.0.unsigned_abs();
+        let mut byte_length = 16;
+        let bytes = number.to_le_bytes();
+
+        for i in (1..16).rev() {
+            if bytes[i] != 0 {
+                break;
+            }
+
+            byte_length -= 1;
+        }
+
+        debug_assert!((8i32..=16).contains(&byte_length), "Invalid byte length");
+
+        let bytes = &bytes.as_slice()[..byte_length as usize];
+        let mut byte_length = (byte_length - 2) as u8 | 0x80;
+
+        // Include sign bit.
+        if self.0 < 0 {
+            byte_length |= 0x40;
+        }
+
+        writer.write_all(&[byte_length])?;
+        writer.write_all(bytes)?;
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for FnkInt128 {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.is_empty() {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "Unexpected length of input",
+            ));
+        }
+
+        let first_byte = buf[0];
+        if first_byte & 0x80 == 0 {
+            // Flag encoding.
+            let number = FnkInt::deserialize(buf)?;
+            Ok(Self(number.0 as i128))
+        } else {
+            // Length encoding.
+            let byte_length = first_byte & 0x3F;
+
+            if byte_length >= 15 {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidInput,
+                    "Incorrect FnkInt128 length",
+                ));
+            }
+
+            let byte_length = byte_length as usize + 2;
+
+            if buf.len() < byte_length + 1 {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unexpected length of input",
+                ));
+            }
+
+            let mut number = 0;
+            let mut offset = 0;
+
+            for i in 0..byte_length {
+                number |= (buf[i + 1] as u128) << offset;
+                offset += 8;
+            }
+
+            *buf = &buf[byte_length + 1..];
+
+            let number = if first_byte & 0x40 == 0 {
+                i128::try_from(number).map_err(|_| {
+                    std::io::Error::new(ErrorKind::InvalidInput, "Incorrect FnkInt128 value")
+                })?
+            } else {
+                match number.cmp(&MIN_I128_ABS) {
+                    Ordering::Less => -(number as i128),
+                    Ordering::Equal => i128::MIN,
+                    Ordering::Greater => {
+                        return Err(std::io::Error::new(
+                            ErrorKind::InvalidInput,
+                            "Incorrect FnkInt128 value",
+                        ));
+                    }
+                }
+            };
+
+            Ok(Self(number))
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
@@ -731,4 +993,54 @@ mod test {
             assert!(de_buf.is_empty(), "Buffer not empty for {}", number);
         }
     }
+
+    #[test]
+    fn test_128_serialize_as_64() {
+        for number in [
+            0i64,
+            -1,
+            (1 << 13) - 1,
+            -(1 << 13),
+            i32::MIN as i64,
+            i64::MAX,
+            i64::MIN,
+        ] {
+            let expected = FnkInt::from(number).try_to_vec().unwrap();
+            let fnk_number = FnkInt128::from(number);
+            let buffer = fnk_number.try_to_vec().unwrap();
+
+            assert_eq!(buffer, expected, "Incorrect result for {}", number);
+            assert_eq!(fnk_number.byte_size(), buffer.len());
+        }
+    }
+
+    #[test]
+    fn test_128_deserialize() {
+        for number in [
+            0i128,
+            -1,
+            i64::MAX as i128 + 1,
+            i64::MIN as i128 - 1,
+            1 << 100,
+            -(1 << 100),
+            i128::MAX,
+            i128::MIN + 1,
+            i128::MIN,
+        ] {
+            let fnk_number = FnkInt128::from(number);
+            let buffer = fnk_number.try_to_vec().unwrap();
+            assert_eq!(fnk_number.byte_size(), buffer.len());
+
+            let mut de_buf = buffer.as_slice();
+            let deserialized = FnkInt128::deserialize(&mut de_buf)
+                .unwrap_or_else(|_| panic!("Failed to deserialize for {}", number));
+
+            assert_eq!(deserialized.get_i128(), number);
+            assert!(de_buf.is_empty(), "Buffer not empty for {}", number);
+        }
+
+        // Incorrect length.
+        let mut de_buf = [0x8Fu8; 20].as_slice();
+        assert!(FnkInt128::deserialize(&mut de_buf).is_err());
+    }
 }
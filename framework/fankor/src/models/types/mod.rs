@@ -1,23 +1,29 @@
 pub use arrays::*;
 pub use binary_map::*;
 pub use binary_set::*;
+pub use bitset::*;
+pub use decimals::*;
 pub use extensions::*;
 pub use integers::*;
 pub use maps::*;
 pub use ranges::*;
 pub use sets::*;
 pub use strings::*;
+pub use time::*;
 pub use unsigned::*;
 pub use vectors::*;
 
 mod arrays;
 mod binary_map;
 mod binary_set;
+mod bitset;
+mod decimals;
 mod extensions;
 mod integers;
 mod maps;
 mod ranges;
 mod sets;
 mod strings;
+mod time;
 mod unsigned;
 mod vectors;
@@ -3,8 +3,8 @@ use std::ops::RangeInclusive;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
-use crate::models::types::unsigned::FnkUInt;
-use crate::prelude::FnkInt;
+use crate::models::types::unsigned::{FnkUInt, FnkUInt128};
+use crate::prelude::{FnkInt, FnkInt128};
 
 /// Custom range impl over two `FnkUInt` points that serializes as point + length.
 /// The range is inclusive and the length can be:
@@ -175,6 +175,169 @@ impl BorshDeserialize for FnkRange {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Same as [`FnkURange`] but over two `FnkUInt128` points.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FnkURange128 {
+    from: FnkUInt128,
+    to: FnkUInt128,
+}
+
+impl FnkURange128 {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(from: FnkUInt128, to: FnkUInt128) -> Self {
+        assert!(from.0 <= to.0, "{}: start > end", stringify!(FnkURange128));
+
+        Self { from, to }
+    }
+
+    pub fn new_unbounded(from: FnkUInt128) -> Self {
+        Self {
+            from,
+            to: FnkUInt128::from(u128::MAX),
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn from(&self) -> FnkUInt128 {
+        self.from
+    }
+
+    pub fn to(&self) -> FnkUInt128 {
+        self.to
+    }
+
+    pub(crate) fn point_and_length(&self) -> (FnkUInt128, FnkInt128) {
+        let point = self.from;
+        let distance_to_end = u128::MAX - self.to.0;
+
+        // Shortcut for unbounded ranges.
+        if distance_to_end == 0 {
+            let length = FnkInt128::from(0);
+
+            return (point, length);
+        }
+
+        let distance_to_start = self.to.0 - self.from.0 + 1;
+
+        let length = if distance_to_end <= distance_to_start {
+            FnkInt128::from(
+                i128::try_from(distance_to_end)
+                    .map(|v| -v)
+                    .unwrap_or(i128::MIN),
+            )
+        } else {
+            FnkInt128::from(i128::try_from(distance_to_start).unwrap())
+        };
+
+        (point, length)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn to_range(&self) -> RangeInclusive<u128> {
+        self.from.0..=self.to.0
+    }
+}
+
+impl BorshSerialize for FnkURange128 {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let (point, length) = self.point_and_length();
+
+        point.serialize(writer)?;
+        length.serialize(writer)?;
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for FnkURange128 {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let point = FnkUInt128::deserialize(buf)?;
+        let length = FnkInt128::deserialize(buf)?;
+
+        let to = if length.0 <= 0 {
+            u128::MAX - length.0.unsigned_abs()
+        } else {
+            point.0 + length.0 as u128 - 1
+        };
+
+        Ok(Self {
+            from: point,
+            to: FnkUInt128::from(to),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Custom range impl over `FnkInt128`. The range is inclusive.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FnkRange128 {
+    from: FnkInt128,
+    to: FnkInt128,
+}
+
+impl FnkRange128 {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(from: FnkInt128, to: FnkInt128) -> Self {
+        assert!(from.0 <= to.0, "{}: start > end", stringify!(FnkRange128));
+
+        Self { from, to }
+    }
+
+    pub fn new_unbounded(from: FnkInt128) -> Self {
+        Self {
+            from,
+            to: FnkInt128::from(i128::MAX),
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn from(&self) -> FnkInt128 {
+        self.from
+    }
+
+    pub fn to(&self) -> FnkInt128 {
+        self.to
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn to_range(&self) -> RangeInclusive<i128> {
+        self.from.0..=self.to.0
+    }
+}
+
+impl BorshSerialize for FnkRange128 {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.from.serialize(writer)?;
+        self.to.serialize(writer)?;
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for FnkRange128 {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let from = FnkInt128::deserialize(buf)?;
+        let to = FnkInt128::deserialize(buf)?;
+
+        Ok(Self { from, to })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
@@ -315,4 +478,50 @@ mod test {
 
         assert_eq!(de_range, range);
     }
+
+    #[test]
+    fn test_serialize_unsigned_range_128() {
+        for i in [
+            0,
+            1,
+            u64::MAX as u128,
+            u128::MAX / 3,
+            u128::MAX / 2,
+            u128::MAX / 2 + 1,
+            u128::MAX - 1,
+            u128::MAX,
+        ] {
+            let mut buffer = Vec::new();
+            let mut cursor = Cursor::new(&mut buffer);
+            let range = FnkURange128::new(FnkUInt128::new(0), FnkUInt128::new(i));
+            range
+                .serialize(&mut cursor)
+                .unwrap_or_else(|_| panic!("Failed to serialize for {}", i));
+
+            let de_range = FnkURange128::deserialize(&mut &buffer[..])
+                .unwrap_or_else(|_| panic!("Failed to deserialize for {}", i));
+
+            assert_eq!(de_range, range);
+        }
+    }
+
+    #[test]
+    fn test_serialize_signed_range_128() {
+        for (from, to) in [
+            (i128::MIN, i128::MAX),
+            (i128::MIN, 0),
+            (0, i128::MAX),
+            (i64::MIN as i128 - 1, i64::MAX as i128 + 1),
+        ] {
+            let mut buffer = Vec::new();
+            let mut cursor = Cursor::new(&mut buffer);
+            let range = FnkRange128::new(FnkInt128::new(from), FnkInt128::new(to));
+            range.serialize(&mut cursor).expect("Failed to serialize");
+
+            let de_range =
+                FnkRange128::deserialize(&mut &buffer[..]).expect("Failed to deserialize");
+
+            assert_eq!(de_range, range);
+        }
+    }
 }
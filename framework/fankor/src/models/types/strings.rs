@@ -1,10 +1,11 @@
 use std::borrow::Cow;
-use std::fmt::Display;
+use std::fmt::{Debug, Display};
 use std::io::{ErrorKind, Write};
 use std::ops::{Deref, DerefMut};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
+use crate::errors::{FankorErrorCode, FankorResult};
 use crate::models::types::unsigned::FnkUInt;
 
 /// Wrapper over `String` that serializes the length into a `FnkUInt`.
@@ -129,6 +130,326 @@ impl<'a> BorshDeserialize for FnkString<'a> {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// A `FnkString` whose length in bytes cannot exceed `N`. The bound is checked
+/// on every mutation and when it is deserialized.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FnkBoundedString<'a, const N: usize>(FnkString<'a>);
+
+impl<'a, const N: usize> FnkBoundedString<'a, N> {
+    pub const MAX_LENGTH: usize = N;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(inner: Cow<'a, str>) -> FankorResult<Self> {
+        check_bounded_length(inner.len(), N)?;
+
+        Ok(Self(FnkString::new(inner)))
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.0.into_inner()
+    }
+
+    /// Replaces the content of the string.
+    pub fn set(&mut self, value: Cow<'a, str>) -> FankorResult<()> {
+        check_bounded_length(value.len(), N)?;
+
+        self.0 = FnkString::new(value);
+
+        Ok(())
+    }
+
+    pub fn push(&mut self, ch: char) -> FankorResult<()> {
+        check_bounded_length(self.0.len() + ch.len_utf8(), N)?;
+
+        self.0.to_mut().push(ch);
+
+        Ok(())
+    }
+
+    pub fn push_str(&mut self, value: &str) -> FankorResult<()> {
+        check_bounded_length(self.0.len() + value.len(), N)?;
+
+        self.0.to_mut().push_str(value);
+
+        Ok(())
+    }
+
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.0.len() {
+            self.0.to_mut().truncate(new_len);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = FnkString::default();
+    }
+}
+
+impl<'a, const N: usize> AsRef<Cow<'a, str>> for FnkBoundedString<'a, N> {
+    fn as_ref(&self) -> &Cow<'a, str> {
+        &self.0
+    }
+}
+
+impl<'a, const N: usize> Deref for FnkBoundedString<'a, N> {
+    type Target = Cow<'a, str>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a, const N: usize> Display for FnkBoundedString<'a, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a str> for FnkBoundedString<'a, N> {
+    type Error = crate::errors::Error;
+
+    fn try_from(v: &'a str) -> Result<Self, Self::Error> {
+        Self::new(Cow::Borrowed(v))
+    }
+}
+
+impl<'a, const N: usize> TryFrom<String> for FnkBoundedString<'a, N> {
+    type Error = crate::errors::Error;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        Self::new(Cow::Owned(v))
+    }
+}
+
+impl<'a, const N: usize> TryFrom<FnkString<'a>> for FnkBoundedString<'a, N> {
+    type Error = crate::errors::Error;
+
+    fn try_from(v: FnkString<'a>) -> Result<Self, Self::Error> {
+        Self::new(v.0)
+    }
+}
+
+impl<'a, const N: usize> From<FnkBoundedString<'a, N>> for FnkString<'a> {
+    fn from(v: FnkBoundedString<'a, N>) -> Self {
+        v.0
+    }
+}
+
+impl<'a, const N: usize> From<FnkBoundedString<'a, N>> for String {
+    fn from(v: FnkBoundedString<'a, N>) -> Self {
+        v.0.into()
+    }
+}
+
+impl<'a, const N: usize> BorshSerialize for FnkBoundedString<'a, N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<'a, const N: usize> BorshDeserialize for FnkBoundedString<'a, N> {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        // Check the length before reading the content.
+        let mut length_buf = *buf;
+        let length = FnkUInt::deserialize(&mut length_buf)?;
+
+        if length.0 > N as u64 {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Bounded length exceeded",
+            ));
+        }
+
+        Ok(Self(FnkString::deserialize(buf)?))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A string stored in exactly `N` bytes, padded with zeros at the end. Because
+/// its size never changes, it can be updated in place without moving the
+/// bytes that follow it.
+///
+/// Null characters are indistinguishable from the padding, so they are
+/// rejected.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FnkFixedString<const N: usize>([u8; N]);
+
+impl<const N: usize> FnkFixedString<N> {
+    pub const CAPACITY: usize = N;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(value: &str) -> FankorResult<Self> {
+        let mut result = Self::default();
+        result.set(value)?;
+
+        Ok(result)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The length of the string without the padding.
+    pub fn len(&self) -> usize {
+        fixed_string_length(&self.0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0[..self.len()]
+    }
+
+    pub fn as_str(&self) -> &str {
+        // The content is always validated before storing it.
+        unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Replaces the content of the string.
+    pub fn set(&mut self, value: &str) -> FankorResult<()> {
+        let bytes = value.as_bytes();
+        check_bounded_length(bytes.len(), N)?;
+        check_no_null_characters(bytes)?;
+
+        self.0[..bytes.len()].copy_from_slice(bytes);
+        self.0[bytes.len()..].fill(0);
+
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> [u8; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> Default for FnkFixedString<N> {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> AsRef<str> for FnkFixedString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Deref for FnkFixedString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Debug for FnkFixedString<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FnkFixedString")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl<const N: usize> Display for FnkFixedString<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for FnkFixedString<N> {
+    type Error = crate::errors::Error;
+
+    fn try_from(v: &str) -> Result<Self, Self::Error> {
+        Self::new(v)
+    }
+}
+
+impl<const N: usize> From<FnkFixedString<N>> for String {
+    fn from(v: FnkFixedString<N>) -> Self {
+        v.as_str().to_string()
+    }
+}
+
+impl<const N: usize> BorshSerialize for FnkFixedString<N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FnkFixedString<N> {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.len() < N {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "Unexpected length of input",
+            ));
+        }
+
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(&buf[..N]);
+
+        let length = fixed_string_length(&bytes);
+        if bytes[..length].contains(&0) {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Fixed strings cannot contain null characters",
+            ));
+        }
+
+        std::str::from_utf8(&bytes[..length]).map_err(|err| {
+            let msg = err.to_string();
+            std::io::Error::new(ErrorKind::InvalidData, msg)
+        })?;
+
+        *buf = &buf[N..];
+        Ok(Self(bytes))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub(crate) fn check_bounded_length(length: usize, max: usize) -> FankorResult<()> {
+    if length > max {
+        return Err(FankorErrorCode::BoundedLengthExceeded {
+            max,
+            actual: length,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_no_null_characters(bytes: &[u8]) -> FankorResult<()> {
+    if bytes.contains(&0) {
+        return Err(FankorErrorCode::FixedStringNullCharacter.into());
+    }
+
+    Ok(())
+}
+
+/// Gets the length of a zero-padded string.
+pub(crate) fn fixed_string_length(bytes: &[u8]) -> usize {
+    bytes.iter().rposition(|v| *v != 0).map_or(0, |i| i + 1)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
@@ -157,4 +478,88 @@ mod test {
             assert!(de_buf.is_empty(), "Buffer not empty for {:?}", text);
         }
     }
+
+    #[test]
+    fn test_bounded_mutations() {
+        let mut text = FnkBoundedString::<5>::try_from("abc").unwrap();
+
+        text.push_str("de").unwrap();
+        assert_eq!(text.as_ref(), "abcde");
+
+        assert!(text.push('f').is_err());
+        assert!(text.set(Cow::Borrowed("abcdef")).is_err());
+        assert_eq!(text.as_ref(), "abcde");
+
+        text.truncate(2);
+        text.push('z').unwrap();
+        assert_eq!(text.as_ref(), "abz");
+
+        assert!(FnkBoundedString::<2>::try_from("abc").is_err());
+    }
+
+    #[test]
+    fn test_bounded_serialize_deserialize() {
+        let text = FnkBoundedString::<12>::try_from("Hello world!").unwrap();
+        let buffer = text.try_to_vec().unwrap();
+
+        assert_eq!(
+            buffer,
+            FnkString::from("Hello world!").try_to_vec().unwrap()
+        );
+
+        let mut de_buf = buffer.as_slice();
+        let deserialized = FnkBoundedString::<12>::deserialize(&mut de_buf).unwrap();
+
+        assert_eq!(deserialized, text);
+        assert!(de_buf.is_empty(), "Buffer not empty");
+
+        let mut de_buf = buffer.as_slice();
+        assert!(FnkBoundedString::<11>::deserialize(&mut de_buf).is_err());
+    }
+
+    #[test]
+    fn test_fixed_serialize_deserialize() {
+        let mut text = FnkFixedString::<8>::new("Hello").unwrap();
+        let buffer = text.try_to_vec().unwrap();
+
+        assert_eq!(buffer, b"Hello\0\0\0");
+
+        let mut de_buf = buffer.as_slice();
+        let deserialized = FnkFixedString::<8>::deserialize(&mut de_buf).unwrap();
+
+        assert_eq!(deserialized, text);
+        assert_eq!(deserialized.as_str(), "Hello");
+        assert!(de_buf.is_empty(), "Buffer not empty");
+
+        text.set("Hi").unwrap();
+        assert_eq!(text.try_to_vec().unwrap(), b"Hi\0\0\0\0\0\0");
+        assert!(text.set("Too long!").is_err());
+
+        let mut de_buf: &[u8] = &[0xFF, 0, 0, 0, 0, 0, 0, 0];
+        assert!(FnkFixedString::<8>::deserialize(&mut de_buf).is_err());
+    }
+
+    #[test]
+    fn test_fixed_null_characters() {
+        let mut text = FnkFixedString::<8>::new("Hello").unwrap();
+
+        for value in ["Hi\0", "\0", "H\0i"] {
+            assert_eq!(
+                FnkFixedString::<8>::new(value),
+                Err(FankorErrorCode::FixedStringNullCharacter.into()),
+                "Incorrect result for {:?}",
+                value
+            );
+            assert_eq!(
+                text.set(value),
+                Err(FankorErrorCode::FixedStringNullCharacter.into()),
+                "Incorrect result for {:?}",
+                value
+            );
+            assert_eq!(text.as_str(), "Hello");
+        }
+
+        let mut de_buf = &b"H\0i\0\0\0\0\0"[..];
+        assert!(FnkFixedString::<8>::deserialize(&mut de_buf).is_err());
+    }
 }
@@ -0,0 +1,294 @@
+use std::fmt::Display;
+use std::io::Write;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::sysvar::clock::Clock;
+use solana_program::sysvar::Sysvar;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::FnkInt;
+
+/// Unix timestamp in seconds, i.e. the same unit as `Clock::unix_timestamp`.
+///
+/// ## Encoding
+///
+/// The number of seconds is encoded as a [`FnkInt`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FnkTimestamp(pub i64);
+
+impl FnkTimestamp {
+    pub const UNIX_EPOCH: Self = Self(0);
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(unix_timestamp: i64) -> Self {
+        Self(unix_timestamp)
+    }
+
+    /// Reads the current timestamp from the `Clock` sysvar.
+    pub fn now() -> FankorResult<Self> {
+        let clock = Clock::get()?;
+        Ok(Self(clock.unix_timestamp))
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn unix_timestamp(&self) -> i64 {
+        self.0
+    }
+
+    /// Whether the timestamp is lower or equal than the current one.
+    pub fn is_expired(&self) -> FankorResult<bool> {
+        Ok(*self <= Self::now()?)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn checked_add(self, duration: FnkDuration) -> Option<Self> {
+        self.0.checked_add(duration.0).map(Self)
+    }
+
+    pub fn checked_sub(self, duration: FnkDuration) -> Option<Self> {
+        self.0.checked_sub(duration.0).map(Self)
+    }
+
+    /// The duration from `earlier` to this timestamp. It is negative if `earlier`
+    /// is actually later than this timestamp.
+    pub fn duration_since(&self, earlier: FnkTimestamp) -> Option<FnkDuration> {
+        self.0.checked_sub(earlier.0).map(FnkDuration)
+    }
+
+    /// The duration from this timestamp to the current one.
+    pub fn elapsed(&self) -> FankorResult<FnkDuration> {
+        let now = Self::now()?;
+        Ok(FnkDuration(now.0.saturating_sub(self.0)))
+    }
+
+    pub fn into_inner(self) -> i64 {
+        self.0
+    }
+}
+
+impl Display for FnkTimestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<i64> for FnkTimestamp {
+    fn from(v: i64) -> Self {
+        Self(v)
+    }
+}
+
+impl From<FnkTimestamp> for i64 {
+    fn from(v: FnkTimestamp) -> Self {
+        v.0
+    }
+}
+
+impl BorshSerialize for FnkTimestamp {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        FnkInt::new(self.0).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for FnkTimestamp {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let value = FnkInt::deserialize(buf)?;
+        Ok(Self(value.0))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Signed duration in seconds.
+///
+/// ## Encoding
+///
+/// The number of seconds is encoded as a [`FnkInt`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FnkDuration(pub i64);
+
+impl FnkDuration {
+    pub const ZERO: Self = Self(0);
+    pub const SECOND: Self = Self(1);
+    pub const MINUTE: Self = Self(60);
+    pub const HOUR: Self = Self(60 * 60);
+    pub const DAY: Self = Self(24 * 60 * 60);
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn from_seconds(seconds: i64) -> Self {
+        Self(seconds)
+    }
+
+    pub fn from_minutes(minutes: i64) -> Option<Self> {
+        minutes.checked_mul(Self::MINUTE.0).map(Self)
+    }
+
+    pub fn from_hours(hours: i64) -> Option<Self> {
+        hours.checked_mul(Self::HOUR.0).map(Self)
+    }
+
+    pub fn from_days(days: i64) -> Option<Self> {
+        days.checked_mul(Self::DAY.0).map(Self)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn seconds(&self) -> i64 {
+        self.0
+    }
+
+    pub fn milliseconds(&self) -> Option<i64> {
+        self.0.checked_mul(1000)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn checked_add(self, other: FnkDuration) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    pub fn checked_sub(self, other: FnkDuration) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    pub fn checked_mul(self, factor: i64) -> Option<Self> {
+        self.0.checked_mul(factor).map(Self)
+    }
+
+    pub fn into_inner(self) -> i64 {
+        self.0
+    }
+}
+
+impl Display for FnkDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}s", self.0)
+    }
+}
+
+impl From<i64> for FnkDuration {
+    fn from(v: i64) -> Self {
+        Self(v)
+    }
+}
+
+impl From<FnkDuration> for i64 {
+    fn from(v: FnkDuration) -> Self {
+        v.0
+    }
+}
+
+impl BorshSerialize for FnkDuration {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        FnkInt::new(self.0).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for FnkDuration {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let value = FnkInt::deserialize(buf)?;
+        Ok(Self(value.0))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Checks that `expires_at` is later than the current timestamp of the `Clock`
+/// sysvar, otherwise fails with [`FankorErrorCode::TimestampExpired`].
+///
+/// Intended to be used as an account constraint:
+/// `#[account(constraint = not_expired(self.offer.data().expires_at))]`.
+pub fn not_expired<T: Into<FnkTimestamp>>(expires_at: T) -> FankorResult<()> {
+    let expires_at = expires_at.into();
+    let now = FnkTimestamp::now()?;
+
+    check_not_expired(expires_at, now)
+}
+
+/// Same as [`not_expired`] but comparing against a given timestamp.
+pub(crate) fn check_not_expired(expires_at: FnkTimestamp, now: FnkTimestamp) -> FankorResult<()> {
+    if expires_at <= now {
+        return Err(FankorErrorCode::TimestampExpired {
+            expires_at: expires_at.0,
+            now: now.0,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_arithmetic() {
+        let start = FnkTimestamp::new(1_000);
+        let end = start.checked_add(FnkDuration::HOUR).unwrap();
+
+        assert_eq!(end, FnkTimestamp::new(4_600));
+        assert_eq!(end.duration_since(start), Some(FnkDuration::HOUR));
+        assert_eq!(start.duration_since(end), Some(FnkDuration(-3_600)));
+        assert_eq!(end.checked_sub(FnkDuration::HOUR), Some(start));
+        assert_eq!(FnkDuration::from_days(2), Some(FnkDuration(172_800)));
+        assert_eq!(FnkDuration::from_days(i64::MAX), None);
+        assert_eq!(
+            FnkTimestamp::new(i64::MAX).checked_add(FnkDuration::SECOND),
+            None
+        );
+    }
+
+    #[test]
+    fn test_check_not_expired() {
+        let now = FnkTimestamp::new(100);
+
+        assert!(check_not_expired(FnkTimestamp::new(101), now).is_ok());
+        assert!(check_not_expired(FnkTimestamp::new(100), now).is_err());
+        assert!(check_not_expired(FnkTimestamp::new(99), now).is_err());
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        for value in [0, 31, -31, 1_700_000_000, i64::MIN, i64::MAX] {
+            let timestamp = FnkTimestamp::new(value);
+            let buffer = timestamp.try_to_vec().unwrap();
+            assert_eq!(buffer, FnkInt::new(value).try_to_vec().unwrap());
+
+            let mut de_buf = buffer.as_slice();
+            let deserialized = FnkTimestamp::deserialize(&mut de_buf).unwrap();
+            assert_eq!(deserialized, timestamp);
+            assert!(de_buf.is_empty(), "Buffer not empty");
+
+            let duration = FnkDuration::from_seconds(value);
+            let buffer = duration.try_to_vec().unwrap();
+
+            let mut de_buf = buffer.as_slice();
+            let deserialized = FnkDuration::deserialize(&mut de_buf).unwrap();
+            assert_eq!(deserialized, duration);
+            assert!(de_buf.is_empty(), "Buffer not empty");
+        }
+    }
+}
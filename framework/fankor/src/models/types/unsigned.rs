@@ -271,6 +271,200 @@ impl BorshDeserialize for FnkUInt {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Wrapper over a 128-bit unsigned number that serializes to a variable-length form.
+///
+/// ## Encoding
+///
+/// The encoding is the same as [`FnkUInt`] but the length encoding can represent up to 16 bytes,
+/// so any number that fits in 64 bits is encoded exactly as a [`FnkUInt`].
+///
+/// ### Length encoding
+///
+/// The length must be in range [0, 14] which actually represents the range [2, 16], other values
+/// are forbidden.
+///
+/// ```none
+/// 1000 ssss + ssss bytes
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FnkUInt128(pub u128);
+
+impl FnkUInt128 {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(inner: u128) -> Self {
+        Self(inner)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn get_u64(&self) -> Option<u64> {
+        u64::try_from(self.0).ok()
+    }
+
+    pub fn get_u128(&self) -> u128 {
+        self.0
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn into_inner(self) -> u128 {
+        self.0
+    }
+}
+
+impl AsRef<u128> for FnkUInt128 {
+    fn as_ref(&self) -> &u128 {
+        &self.0
+    }
+}
+
+impl Deref for FnkUInt128 {
+    type Target = u128;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for FnkUInt128 {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Display for FnkUInt128 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u8> for FnkUInt128 {
+    fn from(v: u8) -> Self {
+        Self(v as u128)
+    }
+}
+
+impl From<u16> for FnkUInt128 {
+    fn from(v: u16) -> Self {
+        Self(v as u128)
+    }
+}
+
+impl From<u32> for FnkUInt128 {
+    fn from(v: u32) -> Self {
+        Self(v as u128)
+    }
+}
+
+impl From<u64> for FnkUInt128 {
+    fn from(v: u64) -> Self {
+        Self(v as u128)
+    }
+}
+
+impl From<u128> for FnkUInt128 {
+    fn from(v: u128) -> Self {
+        Self(v)
+    }
+}
+
+impl From<usize> for FnkUInt128 {
+    fn from(v: usize) -> Self {
+        Self(v as u128)
+    }
+}
+
+impl From<FnkUInt> for FnkUInt128 {
+    fn from(v: FnkUInt) -> Self {
+        Self(v.0 as u128)
+    }
+}
+
+impl BorshSerialize for FnkUInt128 {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // Numbers that fit in 64 bits are encoded as a FnkUInt.
+        if let Ok(number) = u64::try_from(self.0) {
+            return FnkUInt(number).serialize(writer);
+        }
+
+        // Length encoding.
+        let mut byte_length = 16;
+        let bytes = self.0.to_le_bytes();
+
+        for i in (1..16).rev() {
+            if bytes[i] != 0 {
+                break;
+            }
+
+            byte_length -= 1;
+        }
+
+        debug_assert!((9i32..=16).contains(&byte_length), "Invalid byte length");
+
+        let bytes = &bytes.as_slice()[..byte_length as usize];
+        let byte_length = (byte_length - 2) as u8 | 0x80;
+
+        writer.write_all(&[byte_length])?;
+        writer.write_all(bytes)?;
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for FnkUInt128 {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.is_empty() {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "Unexpected length of input",
+            ));
+        }
+
+        let first_byte = buf[0];
+        if first_byte & 0x80 == 0 {
+            // Flag encoding.
+            let number = FnkUInt::deserialize(buf)?;
+            Ok(Self(number.0 as u128))
+        } else {
+            // Length encoding.
+            let byte_length = first_byte & 0x7F;
+
+            if byte_length >= 15 {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidInput,
+                    "Incorrect FnkUInt128 length",
+                ));
+            }
+
+            let byte_length = byte_length as usize + 2;
+
+            if buf.len() < byte_length + 1 {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unexpected length of input",
+                ));
+            }
+
+            let mut number = 0;
+            let mut offset = 0;
+
+            for i in 0..byte_length {
+                number |= (buf[i + 1] as u128) << offset;
+                offset += 8;
+            }
+
+            *buf = &buf[byte_length + 1..];
+            Ok(Self(number))
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
@@ -433,4 +627,65 @@ mod test {
             assert!(de_buf.is_empty(), "Buffer not empty for {}", number);
         }
     }
+
+    #[test]
+    fn test_128_serialize_as_64() {
+        for number in [0u64, 1, (1 << 14) - 1, 1 << 14, u32::MAX as u64, u64::MAX] {
+            let expected = FnkUInt::from(number).try_to_vec().unwrap();
+            let fnk_number = FnkUInt128::from(number);
+            let buffer = fnk_number.try_to_vec().unwrap();
+
+            assert_eq!(buffer, expected, "Incorrect result for {}", number);
+            assert_eq!(fnk_number.byte_size(), buffer.len());
+        }
+    }
+
+    #[test]
+    fn test_128_serialize_length_format() {
+        for num_bytes in 9..=16 {
+            let low = 1u128 << ((num_bytes - 1) << 3);
+            let high = if num_bytes == 16 {
+                u128::MAX
+            } else {
+                (1u128 << (num_bytes << 3)) - 1
+            };
+
+            for number in [low, high] {
+                let fnk_number = FnkUInt128::from(number);
+                let buffer = fnk_number.try_to_vec().unwrap();
+
+                let length = (0x80 | (num_bytes - 2)) as u8;
+                assert_eq!(buffer.len(), num_bytes + 1);
+                assert_eq!(buffer[0], length);
+                assert_eq!(&buffer[1..], &number.to_le_bytes()[..num_bytes]);
+                assert_eq!(fnk_number.byte_size(), num_bytes + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_128_deserialize() {
+        for number in [
+            0u128,
+            (1 << 14) - 1,
+            u64::MAX as u128,
+            u64::MAX as u128 + 1,
+            1 << 100,
+            u128::MAX / 2,
+            u128::MAX,
+        ] {
+            let buffer = FnkUInt128::from(number).try_to_vec().unwrap();
+
+            let mut de_buf = buffer.as_slice();
+            let deserialized = FnkUInt128::deserialize(&mut de_buf)
+                .unwrap_or_else(|_| panic!("Failed to deserialize for {}", number));
+
+            assert_eq!(deserialized.get_u128(), number);
+            assert!(de_buf.is_empty(), "Buffer not empty for {}", number);
+        }
+
+        // Incorrect length.
+        let mut de_buf = [0x8Fu8; 20].as_slice();
+        assert!(FnkUInt128::deserialize(&mut de_buf).is_err());
+    }
 }
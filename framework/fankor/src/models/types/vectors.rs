@@ -4,6 +4,8 @@ use std::ops::{Deref, DerefMut};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
+use crate::errors::FankorResult;
+use crate::models::types::strings::check_bounded_length;
 use crate::prelude::FnkUInt;
 
 /// Wrapper over `Vec` that serializes the length into a `FnkUInt`.
@@ -124,6 +126,157 @@ impl<T: BorshDeserialize> BorshDeserialize for FnkVec<T> {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// A `FnkVec` whose length cannot exceed `N`. The bound is checked on every
+/// mutation and when it is deserialized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FnkBoundedVec<T, const N: usize>(FnkVec<T>);
+
+impl<T, const N: usize> FnkBoundedVec<T, N> {
+    pub const MAX_LENGTH: usize = N;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(inner: Vec<T>) -> FankorResult<Self> {
+        check_bounded_length(inner.len(), N)?;
+
+        Ok(Self(FnkVec::new(inner)))
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn into_inner(self) -> Vec<T> {
+        self.0.into_inner()
+    }
+
+    /// Gets a mutable slice of the elements. The length cannot change through it.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.0.as_mut_slice()
+    }
+
+    pub fn push(&mut self, value: T) -> FankorResult<()> {
+        check_bounded_length(self.0.len() + 1, N)?;
+
+        self.0.push(value);
+
+        Ok(())
+    }
+
+    pub fn insert(&mut self, index: usize, value: T) -> FankorResult<()> {
+        check_bounded_length(self.0.len() + 1, N)?;
+
+        self.0.insert(index, value);
+
+        Ok(())
+    }
+
+    pub fn append(&mut self, values: &mut Vec<T>) -> FankorResult<()> {
+        check_bounded_length(self.0.len() + values.len(), N)?;
+
+        self.0.append(values);
+
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    pub fn remove(&mut self, index: usize) -> T {
+        self.0.remove(index)
+    }
+
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.0.swap_remove(index)
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len)
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.0.retain(f)
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+}
+
+impl<T, const N: usize> Default for FnkBoundedVec<T, N> {
+    fn default() -> Self {
+        Self(FnkVec::default())
+    }
+}
+
+impl<T, const N: usize> AsRef<Vec<T>> for FnkBoundedVec<T, N> {
+    fn as_ref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> Deref for FnkBoundedVec<T, N> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for FnkBoundedVec<T, N> {
+    type Error = crate::errors::Error;
+
+    fn try_from(v: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(v)
+    }
+}
+
+impl<T, const N: usize> TryFrom<FnkVec<T>> for FnkBoundedVec<T, N> {
+    type Error = crate::errors::Error;
+
+    fn try_from(v: FnkVec<T>) -> Result<Self, Self::Error> {
+        Self::new(v.0)
+    }
+}
+
+impl<T, const N: usize> From<FnkBoundedVec<T, N>> for FnkVec<T> {
+    fn from(v: FnkBoundedVec<T, N>) -> Self {
+        v.0
+    }
+}
+
+impl<T, const N: usize> From<FnkBoundedVec<T, N>> for Vec<T> {
+    fn from(v: FnkBoundedVec<T, N>) -> Self {
+        v.0.into_inner()
+    }
+}
+
+impl<T: BorshSerialize, const N: usize> BorshSerialize for FnkBoundedVec<T, N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<T: BorshDeserialize, const N: usize> BorshDeserialize for FnkBoundedVec<T, N> {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        // Check the length before reading the elements.
+        let mut length_buf = *buf;
+        let length = FnkUInt::deserialize(&mut length_buf)?;
+
+        if length.0 > N as u64 {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Bounded length exceeded",
+            ));
+        }
+
+        Ok(Self(FnkVec::deserialize(buf)?))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
@@ -208,4 +361,40 @@ mod test {
         assert_eq!(deserialized.0, data, "Incorrect result");
         assert!(de_buf.is_empty(), "Buffer not empty");
     }
+
+    #[test]
+    fn test_bounded_mutations() {
+        let mut data = FnkBoundedVec::<u8, 3>::new(vec![1]).unwrap();
+
+        data.push(2).unwrap();
+        data.insert(0, 0).unwrap();
+        assert_eq!(data.as_slice(), &[0, 1, 2]);
+
+        assert!(data.push(3).is_err());
+        assert!(data.append(&mut vec![3]).is_err());
+        assert_eq!(data.len(), 3);
+
+        data.as_mut_slice()[0] = 5;
+        assert_eq!(data.pop(), Some(2));
+        assert_eq!(data.as_slice(), &[5, 1]);
+
+        assert!(FnkBoundedVec::<u8, 1>::new(vec![1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_bounded_serialize_deserialize() {
+        let data = FnkBoundedVec::<u16, 2>::new(vec![1, 2]).unwrap();
+        let buffer = data.try_to_vec().unwrap();
+
+        assert_eq!(buffer, FnkVec::new(vec![1u16, 2]).try_to_vec().unwrap());
+
+        let mut de_buf = buffer.as_slice();
+        let deserialized = FnkBoundedVec::<u16, 2>::deserialize(&mut de_buf).unwrap();
+
+        assert_eq!(deserialized, data);
+        assert!(de_buf.is_empty(), "Buffer not empty");
+
+        let mut de_buf = buffer.as_slice();
+        assert!(FnkBoundedVec::<u16, 1>::deserialize(&mut de_buf).is_err());
+    }
 }
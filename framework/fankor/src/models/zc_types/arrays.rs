@@ -1,22 +1,20 @@
 use std::marker::PhantomData;
 use std::mem::size_of;
 
-use solana_program::account_info::AccountInfo;
-
 use crate::errors::FankorResult;
-use crate::models::Zc;
+use crate::models::{Zc, ZcSource};
 use crate::models::zc_types::vec::Iter;
 use crate::prelude::FnkArray;
 use crate::traits::{CopyType, ZeroCopyType};
 
 pub struct ZcFnkArray<'info, T: CopyType<'info>, const N: usize> {
-    info: &'info AccountInfo<'info>,
+    info: ZcSource<'info>,
     offset: usize,
     _data: PhantomData<T>,
 }
 
 impl<'info, T: CopyType<'info>, const N: usize> ZeroCopyType<'info> for ZcFnkArray<'info, T, N> {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         Ok((
             ZcFnkArray {
                 info,
@@ -77,7 +75,7 @@ impl<'info, T: CopyType<'info>, const N: usize> ZcFnkArray<'info, T, N> {
             return Ok(None);
         }
 
-        let bytes = (*self.info.data).borrow();
+        let bytes = self.info.borrow_data();
         let mut bytes = &bytes[self.offset..];
         let initial_size = bytes.len();
 
@@ -3,16 +3,15 @@ use std::marker::PhantomData;
 use std::mem::size_of;
 
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::account_info::AccountInfo;
 
 use crate::errors::{FankorErrorCode, FankorResult};
-use crate::models::Zc;
+use crate::models::{Zc, ZcSource};
 use crate::prelude::{CopyType, FnkBMap, Node, FNK_BINARY_TREE_MAX_HEIGHT};
 use crate::traits::ZeroCopyType;
 use crate::utils::writers::ArrayWriter;
 
 pub struct ZcFnkBMap<'info, K: CopyType<'info>, V: CopyType<'info>> {
-    info: &'info AccountInfo<'info>,
+    info: ZcSource<'info>,
     offset: usize,
     _data: PhantomData<(K, V)>,
 }
@@ -24,8 +23,7 @@ impl<'info, K: CopyType<'info>, V: CopyType<'info>> ZcFnkBMap<'info, K, V> {
     pub fn len(&self) -> FankorResult<u16> {
         let bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -43,8 +41,7 @@ impl<'info, K: CopyType<'info>, V: CopyType<'info>> ZcFnkBMap<'info, K, V> {
     fn root_position(&self) -> FankorResult<u16> {
         let bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -63,7 +60,7 @@ impl<'info, K: CopyType<'info>, V: CopyType<'info>> ZcFnkBMap<'info, K, V> {
 
     /// Writes the number of elements in the map.
     fn write_len(&self, len: u16) -> FankorResult<()> {
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -78,7 +75,7 @@ impl<'info, K: CopyType<'info>, V: CopyType<'info>> ZcFnkBMap<'info, K, V> {
 
     /// Writes the root position in the map.
     fn write_root_position(&self, root_position: u16) -> FankorResult<()> {
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -95,13 +92,13 @@ impl<'info, K: CopyType<'info>, V: CopyType<'info>> ZcFnkBMap<'info, K, V> {
     pub fn clear(&self) -> FankorResult<()> {
         let actual_length = self.len()?;
         let length = Zc::<u16>::new_unchecked(self.info, self.offset);
-        length.try_write_value_unchecked(&0)?;
+        length.write_value(&0)?;
 
         let root_position = Zc::<u16>::new_unchecked(self.info, self.offset + size_of::<u16>());
-        root_position.try_write_value_unchecked(&0)?;
+        root_position.write_value(&0)?;
 
         let content = Zc::<u8>::new_unchecked(self.info, self.offset + size_of::<u16>() * 2);
-        content.remove_bytes_unchecked(actual_length as usize * Node::<K, V>::byte_size())?;
+        content.remove_bytes(actual_length as usize * Node::<K, V>::byte_size())?;
 
         Ok(())
     }
@@ -204,8 +201,7 @@ impl<
 
         let bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -223,8 +219,7 @@ impl<
 
         let bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -243,8 +238,7 @@ impl<
 
         let bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -263,8 +257,7 @@ impl<
 
         let bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -283,8 +276,7 @@ impl<
 
         let bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -303,8 +295,7 @@ impl<
 
         let bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -330,7 +321,7 @@ impl<
         let mut offset = self.content_offset();
         offset += index as usize * Node::<K, V>::byte_size();
 
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -362,7 +353,7 @@ impl<
 
             // Realloc the buffer to contain the new value.
             let cursor = Zc::<()>::new_unchecked(self.info, offset);
-            cursor.make_space(node_size)?;
+            cursor.insert_bytes(node_size)?;
         }
 
         self.write_node(length, node)?;
@@ -380,7 +371,7 @@ impl<
         offset += index as usize * Node::<K, V>::byte_size();
         offset += K::min_byte_size();
 
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -402,7 +393,7 @@ impl<
         offset += index as usize * Node::<K, V>::byte_size();
         offset += K::min_byte_size() + V::min_byte_size();
 
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -424,7 +415,7 @@ impl<
         offset += index as usize * Node::<K, V>::byte_size();
         offset += K::min_byte_size() + V::min_byte_size() + size_of::<u16>();
 
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -446,7 +437,7 @@ impl<
         offset += index as usize * Node::<K, V>::byte_size();
         offset += K::min_byte_size() + V::min_byte_size() + size_of::<u16>() * 2;
 
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -853,7 +844,7 @@ impl<
             let last_element_offset =
                 self.content_offset() + (last_node_position as usize - 1) * node_size;
             let zc = Zc::<()>::new_unchecked(self.info, last_element_offset);
-            zc.remove_bytes_unchecked(node_size)?;
+            zc.remove_bytes(node_size)?;
         }
 
         // Decrease length.
@@ -1067,7 +1058,7 @@ impl<
 }
 
 impl<'info, K: CopyType<'info>, V: CopyType<'info>> ZeroCopyType<'info> for ZcFnkBMap<'info, K, V> {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         Ok((
             ZcFnkBMap {
                 info,
@@ -1108,7 +1099,7 @@ impl<'info, K: CopyType<'info>, V: CopyType<'info>> CopyType<'info> for FnkBMap<
 // ----------------------------------------------------------------------------
 
 pub struct ZcFnkBMapIter<'info, K, V> {
-    pub(crate) info: &'info AccountInfo<'info>,
+    pub(crate) info: ZcSource<'info>,
     pub(crate) offset: usize,
     pub(crate) parents: [u16; FNK_BINARY_TREE_MAX_HEIGHT],
     /// Zero means empty.
@@ -1198,7 +1189,7 @@ mod test {
             let mut vector = vec![0u8; 10_000];
             let info = create_account_info_for_tests(&mut lamports, &mut vector);
             let mut rng = rand::thread_rng();
-            let (map, _) = ZcFnkBMap::new(&info, 0).unwrap();
+            let (map, _) = ZcFnkBMap::new(ZcSource::Account(&info), 0).unwrap();
 
             let mut keys = HashSet::with_capacity(100);
 
@@ -1232,7 +1223,7 @@ mod test {
             let mut vector = vec![0u8; combinations as usize * 45];
             let info = create_account_info_for_tests(&mut lamports, &mut vector);
             let mut rng = rand::thread_rng();
-            let (map, _) = ZcFnkBMap::new(&info, 0).unwrap();
+            let (map, _) = ZcFnkBMap::new(ZcSource::Account(&info), 0).unwrap();
 
             assert_eq!(map.validate(), 0, "(0) Invalid height");
 
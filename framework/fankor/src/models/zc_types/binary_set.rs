@@ -1,10 +1,10 @@
 use std::mem::size_of;
 
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::account_info::AccountInfo;
 
 use crate::errors::FankorResult;
 use crate::models::binary_map::ZcFnkBMap;
+use crate::models::ZcSource;
 use crate::prelude::binary_map::ZcFnkBMapIter;
 use crate::prelude::{CopyType, FnkBSet, Node};
 use crate::traits::ZeroCopyType;
@@ -89,7 +89,7 @@ impl<'info, V: Ord + Copy + BorshSerialize + BorshDeserialize + CopyType<'info>>
 }
 
 impl<'info, V: CopyType<'info>> ZeroCopyType<'info> for ZcFnkBSet<'info, V> {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let (map, offset) = ZcFnkBMap::new(info, offset)?;
         Ok((ZcFnkBSet(map), offset))
     }
@@ -160,7 +160,7 @@ mod test {
             let mut vector = vec![0u8; 10_000];
             let info = create_account_info_for_tests(&mut lamports, &mut vector);
             let mut rng = rand::thread_rng();
-            let (map, _) = ZcFnkBSet::new(&info, 0).unwrap();
+            let (map, _) = ZcFnkBSet::new(ZcSource::Account(&info), 0).unwrap();
 
             let mut keys = HashSet::with_capacity(100);
 
@@ -193,7 +193,7 @@ mod test {
             let mut vector = vec![0u8; combinations as usize * 45];
             let info = create_account_info_for_tests(&mut lamports, &mut vector);
             let mut rng = rand::thread_rng();
-            let (map, _) = ZcFnkBSet::new(&info, 0).unwrap();
+            let (map, _) = ZcFnkBSet::new(ZcSource::Account(&info), 0).unwrap();
 
             assert_eq!(map.validate(), 0, "(0) Invalid height");
 
@@ -0,0 +1,276 @@
+use std::marker::PhantomData;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::bit_position;
+use crate::models::ZcSource;
+use crate::prelude::FnkBitSet;
+use crate::traits::{CopyType, FankorFlags, ZeroCopyType};
+
+pub struct ZcFnkBitSet<'info, const N: usize> {
+    info: ZcSource<'info>,
+    offset: usize,
+}
+
+impl<'info, const N: usize> ZeroCopyType<'info> for ZcFnkBitSet<'info, N> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        Ok((ZcFnkBitSet { info, offset }, Some(N)))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        if bytes.len() < N {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "FnkBitSet",
+            }
+            .into());
+        }
+
+        Ok(N)
+    }
+}
+
+impl<'info, const N: usize> CopyType<'info> for FnkBitSet<N> {
+    type ZeroCopyType = ZcFnkBitSet<'info, N>;
+
+    fn min_byte_size() -> usize {
+        N
+    }
+}
+
+impl<'info, const N: usize> ZcFnkBitSet<'info, N> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Checks whether the bit at `index` is set.
+    ///
+    /// # Errors
+    /// If `index` is out of bounds.
+    pub fn test(&self, index: usize) -> FankorResult<bool> {
+        Self::check_index(index)?;
+        test_bit(self.info, self.offset, index, "ZcFnkBitSet")
+    }
+
+    /// Sets the bit at `index` in place.
+    ///
+    /// # Errors
+    /// If `index` is out of bounds.
+    pub fn set(&self, index: usize) -> FankorResult<()> {
+        Self::check_index(index)?;
+        write_bit(self.info, self.offset, index, true, "ZcFnkBitSet")
+    }
+
+    /// Clears the bit at `index` in place.
+    ///
+    /// # Errors
+    /// If `index` is out of bounds.
+    pub fn clear(&self, index: usize) -> FankorResult<()> {
+        Self::check_index(index)?;
+        write_bit(self.info, self.offset, index, false, "ZcFnkBitSet")
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn check_index(index: usize) -> FankorResult<()> {
+        if index >= N * 8 {
+            return Err(FankorErrorCode::ZeroCopyInvalidIndex { index }.into());
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Zero-copy version of the flag sets generated with `#[derive(FankorFlags)]`.
+pub struct ZcFlags<'info, F: FankorFlags> {
+    info: ZcSource<'info>,
+    offset: usize,
+    _data: PhantomData<F>,
+}
+
+impl<'info, F: FankorFlags> ZeroCopyType<'info> for ZcFlags<'info, F> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        Ok((
+            ZcFlags {
+                info,
+                offset,
+                _data: PhantomData,
+            },
+            Some(F::BYTE_SIZE),
+        ))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        if bytes.len() < F::BYTE_SIZE {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: std::any::type_name::<F>(),
+            }
+            .into());
+        }
+
+        Ok(F::BYTE_SIZE)
+    }
+}
+
+impl<'info, F: FankorFlags> ZcFlags<'info, F> {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Reads the whole flag set.
+    pub fn value(&self) -> FankorResult<F> {
+        let bytes =
+            self.info
+                .try_borrow_data()
+                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    type_name: "ZcFlags",
+                })?;
+        let bytes = &bytes[self.offset..];
+        let size = Self::read_byte_size(bytes)?;
+
+        let mut value = [0u8; 8];
+        value[..size].copy_from_slice(&bytes[..size]);
+
+        Ok(F::from_bits_unchecked(u64::from_le_bytes(value)))
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Checks whether `flag` is set.
+    pub fn test(&self, flag: &F::Flag) -> FankorResult<bool> {
+        test_bit(
+            self.info,
+            self.offset,
+            F::flag_bit(flag) as usize,
+            "ZcFlags",
+        )
+    }
+
+    /// Sets `flag` in place.
+    pub fn set(&self, flag: &F::Flag) -> FankorResult<()> {
+        write_bit(
+            self.info,
+            self.offset,
+            F::flag_bit(flag) as usize,
+            true,
+            "ZcFlags",
+        )
+    }
+
+    /// Clears `flag` in place.
+    pub fn clear(&self, flag: &F::Flag) -> FankorResult<()> {
+        write_bit(
+            self.info,
+            self.offset,
+            F::flag_bit(flag) as usize,
+            false,
+            "ZcFlags",
+        )
+    }
+
+    /// Overwrites the whole flag set in place.
+    pub fn write(&self, value: &F) -> FankorResult<()> {
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
+            FankorErrorCode::ZeroCopyPossibleDeadlock {
+                type_name: "ZcFlags",
+            }
+        })?;
+        let bytes = &mut bytes[self.offset..];
+        let size = Self::read_byte_size(bytes)?;
+
+        bytes[..size].copy_from_slice(&value.bits_u64().to_le_bytes()[..size]);
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn test_bit(
+    info: ZcSource,
+    offset: usize,
+    index: usize,
+    type_name: &'static str,
+) -> FankorResult<bool> {
+    let bytes = info
+        .try_borrow_data()
+        .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock { type_name })?;
+    let (byte, mask) = bit_position(index);
+
+    match bytes.get(offset + byte) {
+        Some(v) => Ok(v & mask != 0),
+        None => Err(FankorErrorCode::ZeroCopyNotEnoughLength { type_name }.into()),
+    }
+}
+
+fn write_bit(
+    info: ZcSource,
+    offset: usize,
+    index: usize,
+    value: bool,
+    type_name: &'static str,
+) -> FankorResult<()> {
+    let mut bytes = info
+        .try_borrow_mut_data()
+        .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock { type_name })?;
+    let (byte, mask) = bit_position(index);
+
+    match bytes.get_mut(offset + byte) {
+        Some(v) => {
+            if value {
+                *v |= mask;
+            } else {
+                *v &= !mask;
+            }
+
+            Ok(())
+        }
+        None => Err(FankorErrorCode::ZeroCopyNotEnoughLength { type_name }.into()),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_bitset_set_clear_test() {
+        let mut lamports = 0;
+        let mut vector = vec![99, 0, 0, 99];
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkBitSet::<2>::new(ZcSource::Account(&info), 1).unwrap();
+
+        assert_eq!(ZcFnkBitSet::<2>::read_byte_size(&[0; 3]).unwrap(), 2);
+        assert!(!zc.test(9).unwrap());
+
+        zc.set(9).unwrap();
+        zc.set(0).unwrap();
+        assert!(zc.test(9).unwrap());
+        assert!(zc.test(0).unwrap());
+
+        zc.clear(0).unwrap();
+        assert!(!zc.test(0).unwrap());
+
+        assert_eq!(info.data.borrow()[..], [99, 0, 0b0000_0010, 99]);
+    }
+
+    #[test]
+    fn test_bitset_out_of_bounds() {
+        let mut lamports = 0;
+        let mut vector = vec![0, 0];
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkBitSet::<2>::new(ZcSource::Account(&info), 0).unwrap();
+        let error = FankorErrorCode::ZeroCopyInvalidIndex { index: 16 };
+
+        assert_eq!(zc.test(16), Err(error.clone().into()));
+        assert_eq!(zc.set(16), Err(error.clone().into()));
+        assert_eq!(zc.clear(16), Err(error.into()));
+        assert_eq!(info.data.borrow()[..], [0, 0]);
+    }
+}
@@ -1,10 +1,9 @@
-use solana_program::account_info::AccountInfo;
-
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::ZcSource;
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info> ZeroCopyType<'info> for bool {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let bytes = info
             .try_borrow_data()
             .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock { type_name: "bool" })?;
@@ -1,10 +1,9 @@
-use solana_program::account_info::AccountInfo;
-
 use crate::errors::FankorResult;
+use crate::models::ZcSource;
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info, T: ZeroCopyType<'info>> ZeroCopyType<'info> for Box<T> {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let (result, size) = T::new(info, offset)?;
         Ok((Box::new(result), size))
     }
@@ -1,11 +1,12 @@
-use solana_program::account_info::AccountInfo;
+use borsh::BorshSerialize;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::{Zc, ZcSource, ZcWriteGuard};
 use crate::prelude::FnkExtension;
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info> ZeroCopyType<'info> for FnkExtension {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let bytes =
             info.try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
@@ -49,3 +50,69 @@ impl<'info> CopyType<'info> for FnkExtension {
         1
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'info> Zc<'info, FnkExtension> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Replaces the extension placeholder with `value` in place through `guard`,
+    /// only inserting the extra bytes it requires, and returns the zero-copy
+    /// version of it.
+    ///
+    /// `value` must be compatible with the extension, i.e. its default value
+    /// must be serialized as a single 0 byte, like `Option<V>` or an enum whose
+    /// first variant is empty.
+    pub fn expand<V: CopyType<'info> + BorshSerialize, U: CopyType<'info>>(
+        self,
+        guard: &ZcWriteGuard<'info, U>,
+        value: &V,
+    ) -> FankorResult<Zc<'info, V>> {
+        // Validate the placeholder.
+        FnkExtension::new(self.info, self.offset)?;
+
+        let mut bytes = Vec::with_capacity(value.byte_size());
+        value.serialize(&mut bytes)?;
+        guard.write_bytes(&self, &bytes)?;
+
+        Ok(Zc::new_unchecked(self.info, self.offset))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::models::FankorContext;
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_expand() {
+        let mut lamports = 0;
+        let mut vector = vec![9, 0, 7, 0, 0];
+        let accounts = [create_account_info_for_tests(&mut lamports, &mut vector)];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+        let guard = ZcWriteGuard::<u8>::new(&context, info).unwrap();
+        let last = guard.view(&Zc::<u8>::new_unchecked(info, 2)).unwrap();
+
+        let zc = Zc::<FnkExtension>::new_unchecked(info, 1);
+        let zc = zc.expand(&guard, &Some(5u16)).unwrap();
+        assert_eq!(zc.try_value().unwrap(), Some(5u16));
+        assert_eq!(info.data.borrow()[..], [9, 1, 5, 0, 7]);
+        assert_eq!(last.offset().unwrap(), 4);
+
+        // The placeholder must be empty.
+        let zc = Zc::<FnkExtension>::new_unchecked(info, 1);
+        assert!(zc.expand(&guard, &Some(5u16)).is_err());
+    }
+}
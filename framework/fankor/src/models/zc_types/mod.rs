@@ -2,14 +2,16 @@ use std::cmp::Ordering;
 use std::io::{Cursor, Write};
 
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::account_info::AccountInfo;
 
 use crate::errors::{FankorErrorCode, FankorResult};
 use crate::traits::{CopyType, ZeroCopyType};
 
+pub use source::*;
+
 pub mod arrays;
 pub mod binary_map;
 pub mod binary_set;
+pub mod bitset;
 pub mod bool;
 pub mod boxed;
 pub mod extensions;
@@ -21,9 +23,11 @@ pub mod strings;
 pub mod tuples;
 pub mod vec;
 
+mod source;
+
 /// A wrapper around a `T` that implements `ZeroCopyType`.
 pub struct Zc<'info, T: CopyType<'info>> {
-    pub(crate) info: &'info AccountInfo<'info>,
+    pub(crate) info: ZcSource<'info>,
     pub(crate) offset: usize,
     pub(crate) _data: std::marker::PhantomData<T>,
 }
@@ -35,9 +39,9 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     ///
     /// # Safety
     /// This method is unsafe because it does not check the offset.
-    pub fn new_unchecked(info: &'info AccountInfo<'info>, offset: usize) -> Self {
+    pub fn new_unchecked(info: impl Into<ZcSource<'info>>, offset: usize) -> Self {
         Self {
-            info,
+            info: info.into(),
             offset,
             _data: std::marker::PhantomData,
         }
@@ -45,7 +49,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
 
     // GETTERS ----------------------------------------------------------------
 
-    pub fn info(&self) -> &'info AccountInfo<'info> {
+    pub fn info(&self) -> ZcSource<'info> {
         self.info
     }
 
@@ -58,8 +62,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     pub fn byte_size(&self) -> FankorResult<usize> {
         let bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -76,15 +79,26 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     ///
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
+    #[deprecated(note = "use `ZcWriteGuard::make_space` instead")]
     pub fn make_space(&self, length: usize) -> FankorResult<()> {
+        self.insert_bytes(length)
+    }
+
+    /// Reverses `length` bytes from the current offset expading the buffer and moving
+    /// the rest bytes forward.
+    ///
+    /// # Safety
+    ///
+    /// This method can fail if there is not enough bytes to add.
+    pub(crate) fn insert_bytes(&self, length: usize) -> FankorResult<()> {
         if length == 0 {
             return Ok(());
         }
 
         // Reallocate the buffer
         #[cfg(any(feature = "test-utils", test))]
-        if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
-            let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        if self.info.is_test_account() {
+            let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
                 FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 }
@@ -98,7 +112,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
         self.info.realloc(original_len + length, false)?;
 
         // Shift bytes
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -116,8 +130,17 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     ///
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
+    #[deprecated(note = "use `ZcWriteGuard::remove_value` instead")]
     pub fn remove_unchecked(self) -> FankorResult<()> {
-        let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        self.remove()
+    }
+
+    /// Removes the data from the bytes.
+    ///
+    /// # Safety
+    /// This method can fail if `value` was not present at the position.
+    pub(crate) fn remove(self) -> FankorResult<()> {
+        let mut original_bytes = self.info.try_borrow_mut_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -128,7 +151,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
 
         drop(original_bytes);
 
-        self.remove_bytes_unchecked(value_size)?;
+        self.remove_bytes(value_size)?;
 
         Ok(())
     }
@@ -141,12 +164,22 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     ///
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
+    #[deprecated(note = "use `ZcWriteGuard::remove_bytes` instead")]
     pub fn remove_bytes_unchecked(&self, length: usize) -> FankorResult<()> {
+        self.remove_bytes(length)
+    }
+
+    /// Removes `length` bytes from the current offset.
+    ///
+    /// # Safety
+    ///
+    /// This method can fail if there is not enough bytes to remove.
+    pub(crate) fn remove_bytes(&self, length: usize) -> FankorResult<()> {
         if length == 0 {
             return Ok(());
         }
 
-        let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
+        let mut original_bytes = self.info.try_borrow_mut_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -160,8 +193,9 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
         let original_length = original_bytes.len();
         drop(original_bytes);
 
+        // Test accounts cannot be reallocated, so they keep their length.
         #[cfg(any(feature = "test-utils", test))]
-        if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+        if !self.info.is_test_account() {
             self.info.realloc(original_length - length, false)?;
         }
 
@@ -181,9 +215,19 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     ///
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
+    #[deprecated(note = "use `ZcWriteGuard::write_value` instead")]
     pub fn try_write_bytes(&self, bytes: &[u8]) -> FankorResult<()> {
+        self.write_bytes(bytes)
+    }
+
+    /// Writes a byte slice in the buffer.
+    ///
+    /// # Safety
+    /// This method can fail if `bytes` does not fit in the buffer or if any
+    /// of the sizes are incorrect.
+    pub(crate) fn write_bytes(&self, bytes: &[u8]) -> FankorResult<()> {
         let previous_size = self.byte_size()?;
-        self.try_write_bytes_with_sizes_unchecked(bytes, previous_size)
+        self.write_bytes_with_size(bytes, previous_size)
     }
 
     /// Writes a byte slice in the buffer specifying the previous size.
@@ -194,10 +238,24 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     ///
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
+    #[deprecated(note = "use `ZcWriteGuard::write_value` instead")]
     pub fn try_write_bytes_with_sizes_unchecked(
         &self,
         bytes: &[u8],
         previous_size: usize,
+    ) -> FankorResult<()> {
+        self.write_bytes_with_size(bytes, previous_size)
+    }
+
+    /// Writes a byte slice in the buffer specifying the previous size.
+    ///
+    /// # Safety
+    /// This method can fail if `bytes` does not fit in the buffer or if any
+    /// of the sizes are incorrect.
+    pub(crate) fn write_bytes_with_size(
+        &self,
+        bytes: &[u8],
+        previous_size: usize,
     ) -> FankorResult<()> {
         let original_len = self.info.data_len();
         let new_size = bytes.len();
@@ -205,7 +263,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
         match new_size.cmp(&previous_size) {
             Ordering::Less => {
                 // Serialize
-                let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
+                let mut original_bytes = self.info.try_borrow_mut_data().map_err(|_| {
                     FankorErrorCode::ZeroCopyPossibleDeadlock {
                         type_name: std::any::type_name::<Self>(),
                     }
@@ -222,8 +280,9 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
                 // Reallocate the buffer
                 drop(original_bytes);
 
+                // Test accounts cannot be reallocated, so they keep their length.
                 #[cfg(any(feature = "test-utils", test))]
-                if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+                if !self.info.is_test_account() {
                     self.info.realloc(original_len - diff, false)?;
                 }
 
@@ -234,7 +293,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
             }
             Ordering::Equal => {
                 // Serialize
-                let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
+                let mut original_bytes = self.info.try_borrow_mut_data().map_err(|_| {
                     FankorErrorCode::ZeroCopyPossibleDeadlock {
                         type_name: std::any::type_name::<Self>(),
                     }
@@ -248,9 +307,9 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
                 let diff = new_size - previous_size;
 
                 #[cfg(any(feature = "test-utils", test))]
-                if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+                if self.info.is_test_account() {
                     // Shift bytes
-                    let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
+                    let mut original_bytes = self.info.try_borrow_mut_data().map_err(|_| {
                         FankorErrorCode::ZeroCopyPossibleDeadlock {
                             type_name: std::any::type_name::<Self>(),
                         }
@@ -268,7 +327,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
                 self.info.realloc(original_len + diff, false)?;
 
                 // Shift bytes
-                let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
+                let mut original_bytes = self.info.try_borrow_mut_data().map_err(|_| {
                     FankorErrorCode::ZeroCopyPossibleDeadlock {
                         type_name: std::any::type_name::<Self>(),
                     }
@@ -302,7 +361,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
                     return Err(FankorErrorCode::ZeroCopyInvalidMove.into());
                 }
 
-                let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+                let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
                     FankorErrorCode::ZeroCopyPossibleDeadlock {
                         type_name: std::any::type_name::<Self>(),
                     }
@@ -315,7 +374,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
                 return Ok(());
             }
             Ordering::Greater => {
-                let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+                let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
                     FankorErrorCode::ZeroCopyPossibleDeadlock {
                         type_name: std::any::type_name::<Self>(),
                     }
@@ -336,7 +395,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     /// This method can fail if the element does not fit in `buffer`.
     pub fn append_to_vec(&self, buffer: &mut Vec<u8>) -> FankorResult<()> {
         let size = {
-            let original_bytes = self.info.data.try_borrow().map_err(|_| {
+            let original_bytes = self.info.try_borrow_data().map_err(|_| {
                 FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 }
@@ -356,8 +415,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     pub fn append_to_vec_with_size(&self, buffer: &mut Vec<u8>, size: usize) -> FankorResult<()> {
         let original_bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -381,8 +439,7 @@ impl<'info, T: CopyType<'info> + BorshDeserialize> Zc<'info, T> {
     pub fn try_value(&self) -> FankorResult<T> {
         let bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -410,11 +467,19 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
     ///
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
+    #[deprecated(note = "use `ZcWriteGuard::write_value` instead")]
     pub fn try_write_value_unchecked(&self, value: &T) -> FankorResult<()> {
+        self.write_value(value)
+    }
+
+    /// Writes a value in the buffer.
+    ///
+    /// # Safety
+    /// This method can fail if `value` does not fit in the buffer.
+    pub(crate) fn write_value(&self, value: &T) -> FankorResult<()> {
         let original_bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -424,7 +489,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
 
         drop(original_bytes);
 
-        self.try_write_value_with_sizes_unchecked(value, previous_size, new_size)
+        self.write_value_with_sizes(value, previous_size, new_size)
     }
 
     /// Writes a zero-copy value in the buffer.
@@ -434,11 +499,19 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
     ///
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
+    #[deprecated(note = "use `ZcWriteGuard::write_value` instead")]
     pub fn try_write_zc_value_unchecked(&self, value: &Zc<'info, T>) -> FankorResult<()> {
+        self.write_zc_value(value)
+    }
+
+    /// Writes a zero-copy value in the buffer.
+    ///
+    /// # Safety
+    /// This method can fail if `value` does not fit in the buffer.
+    pub(crate) fn write_zc_value(&self, value: &Zc<'info, T>) -> FankorResult<()> {
         let original_bytes =
             self.info
-                .data
-                .try_borrow()
+                .try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 })?;
@@ -447,7 +520,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
 
         drop(original_bytes);
 
-        let original_value_bytes = value.info.data.try_borrow().map_err(|_| {
+        let original_value_bytes = value.info.try_borrow_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -456,7 +529,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
         let value_size = T::ZeroCopyType::read_byte_size(value_bytes)?;
         let value_bytes = &value_bytes[..value_size];
 
-        self.try_write_bytes_with_sizes_unchecked(value_bytes, previous_size)
+        self.write_bytes_with_size(value_bytes, previous_size)
     }
 
     /// Writes a value in the buffer specifying the previous and new sizes.
@@ -467,18 +540,33 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
     ///
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
+    #[deprecated(note = "use `ZcWriteGuard::write_value` instead")]
     pub fn try_write_value_with_sizes_unchecked(
         &self,
         value: &T,
         previous_size: usize,
         new_size: usize,
+    ) -> FankorResult<()> {
+        self.write_value_with_sizes(value, previous_size, new_size)
+    }
+
+    /// Writes a value in the buffer specifying the previous and new sizes.
+    ///
+    /// # Safety
+    /// This method can fail if `value` does not fit in the buffer or if any
+    /// of the sizes are incorrect.
+    pub(crate) fn write_value_with_sizes(
+        &self,
+        value: &T,
+        previous_size: usize,
+        new_size: usize,
     ) -> FankorResult<()> {
         let original_len = self.info.data_len();
 
         match new_size.cmp(&previous_size) {
             Ordering::Less => {
                 // Serialize
-                let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
+                let mut original_bytes = self.info.try_borrow_mut_data().map_err(|_| {
                     FankorErrorCode::ZeroCopyPossibleDeadlock {
                         type_name: std::any::type_name::<Self>(),
                     }
@@ -495,8 +583,9 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
                 // Reallocate the buffer
                 drop(original_bytes);
 
+                // Test accounts cannot be reallocated, so they keep their length.
                 #[cfg(any(feature = "test-utils", test))]
-                if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+                if !self.info.is_test_account() {
                     self.info.realloc(original_len - diff, false)?;
                 }
 
@@ -507,7 +596,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
             }
             Ordering::Equal => {
                 // Serialize
-                let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+                let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
                     FankorErrorCode::ZeroCopyPossibleDeadlock {
                         type_name: std::any::type_name::<Self>(),
                     }
@@ -521,9 +610,9 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
                 let diff = new_size - previous_size;
 
                 #[cfg(any(feature = "test-utils", test))]
-                if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+                if self.info.is_test_account() {
                     // Shift bytes
-                    let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+                    let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
                         FankorErrorCode::ZeroCopyPossibleDeadlock {
                             type_name: std::any::type_name::<Self>(),
                         }
@@ -541,7 +630,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
                 self.info.realloc(original_len + diff, false)?;
 
                 // Shift bytes
-                let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
+                let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
                     FankorErrorCode::ZeroCopyPossibleDeadlock {
                         type_name: std::any::type_name::<Self>(),
                     }
@@ -565,12 +654,25 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
     ///
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
+    #[deprecated(note = "use `ZcWriteGuard::write_value` instead")]
     pub fn try_write_zc_value_with_size_unchecked(
         &self,
         value: &Zc<'info, T>,
         previous_size: usize,
     ) -> FankorResult<()> {
-        let original_value_bytes = value.info.data.try_borrow().map_err(|_| {
+        self.write_zc_value_with_size(value, previous_size)
+    }
+
+    /// Writes a zero-copy value in the buffer specifying the previous size.
+    ///
+    /// # Safety
+    /// This method can fail if `value` does not fit in the buffer.
+    pub(crate) fn write_zc_value_with_size(
+        &self,
+        value: &Zc<'info, T>,
+        previous_size: usize,
+    ) -> FankorResult<()> {
+        let original_value_bytes = value.info.try_borrow_data().map_err(|_| {
             FankorErrorCode::ZeroCopyPossibleDeadlock {
                 type_name: std::any::type_name::<Self>(),
             }
@@ -579,7 +681,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
         let value_size = T::ZeroCopyType::read_byte_size(value_bytes)?;
         let value_bytes = &value_bytes[..value_size];
 
-        self.try_write_bytes_with_sizes_unchecked(value_bytes, previous_size)
+        self.write_bytes_with_size(value_bytes, previous_size)
     }
 }
 
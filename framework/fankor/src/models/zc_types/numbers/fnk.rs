@@ -1,12 +1,14 @@
 use borsh::BorshDeserialize;
-use solana_program::account_info::AccountInfo;
 
 use crate::errors::{FankorErrorCode, FankorResult};
-use crate::prelude::{FnkInt, FnkUInt};
+use crate::models::ZcSource;
+use crate::prelude::{
+    FnkDecimal, FnkDuration, FnkInt, FnkInt128, FnkTimestamp, FnkUInt, FnkUInt128,
+};
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info> ZeroCopyType<'info> for FnkInt {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let bytes =
             info.try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
@@ -112,7 +114,7 @@ impl<'info> CopyType<'info> for FnkInt {
 // ----------------------------------------------------------------------------
 
 impl<'info> ZeroCopyType<'info> for FnkUInt {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let bytes =
             info.try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
@@ -215,6 +217,253 @@ impl<'info> CopyType<'info> for FnkUInt {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+impl<'info> ZeroCopyType<'info> for FnkInt128 {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let bytes = info
+            .try_borrow_data()
+            .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
+                type_name: std::any::type_name::<Self>(),
+            })?;
+        let mut bytes = &bytes[offset..];
+        let initial_size = bytes.len();
+        let value = FnkInt128::deserialize(&mut bytes)?;
+
+        Ok((value, Some(initial_size - bytes.len())))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        if bytes.is_empty() {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "FnkInt128",
+            }
+            .into());
+        }
+
+        let first_byte = bytes[0];
+        if first_byte & 0x80 == 0 {
+            // Flag encoding.
+            return FnkInt::read_byte_size(bytes);
+        }
+
+        // Length encoding.
+        let byte_length = first_byte & 0x3F;
+
+        if byte_length >= 15 {
+            return Err(FankorErrorCode::ZeroCopyCannotDeserialize {
+                type_name: "FnkInt128",
+            }
+            .into());
+        }
+
+        let byte_length = byte_length as usize + 2;
+
+        if bytes.len() < byte_length + 1 {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "FnkInt128",
+            }
+            .into());
+        }
+
+        Ok(byte_length + 1)
+    }
+}
+
+impl<'info> CopyType<'info> for FnkInt128 {
+    type ZeroCopyType = FnkInt128;
+
+    fn byte_size(&self) -> usize {
+        if let Ok(number) = i64::try_from(self.0) {
+            return FnkInt(number).byte_size();
+        }
+
+        // Length encoding.
+        let mut byte_length = 17; // 16 bytes + 1 byte for length.
+        let bytes = self.0.unsigned_abs().to_le_bytes();
+
+        for i in (1..16).rev() {
+            if bytes[i] != 0 {
+                break;
+            }
+
+            byte_length -= 1;
+        }
+
+        byte_length
+    }
+
+    fn min_byte_size() -> usize {
+        1
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'info> ZeroCopyType<'info> for FnkUInt128 {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let bytes = info
+            .try_borrow_data()
+            .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
+                type_name: std::any::type_name::<Self>(),
+            })?;
+        let mut bytes = &bytes[offset..];
+        let initial_size = bytes.len();
+        let value = FnkUInt128::deserialize(&mut bytes)?;
+
+        Ok((value, Some(initial_size - bytes.len())))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        if bytes.is_empty() {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "FnkUInt128",
+            }
+            .into());
+        }
+
+        let first_byte = bytes[0];
+        if first_byte & 0x80 == 0 {
+            // Flag encoding.
+            return FnkUInt::read_byte_size(bytes);
+        }
+
+        // Length encoding.
+        let byte_length = first_byte & 0x7F;
+
+        if byte_length >= 15 {
+            return Err(FankorErrorCode::ZeroCopyCannotDeserialize {
+                type_name: "FnkUInt128",
+            }
+            .into());
+        }
+
+        let byte_length = byte_length as usize + 2;
+
+        if bytes.len() < byte_length + 1 {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "FnkUInt128",
+            }
+            .into());
+        }
+
+        Ok(byte_length + 1)
+    }
+}
+
+impl<'info> CopyType<'info> for FnkUInt128 {
+    type ZeroCopyType = FnkUInt128;
+
+    fn byte_size(&self) -> usize {
+        if let Ok(number) = u64::try_from(self.0) {
+            return FnkUInt(number).byte_size();
+        }
+
+        // Length encoding.
+        let mut byte_length = 17; // 16 bytes + 1 byte for length.
+        let bytes = self.0.to_le_bytes();
+
+        for i in (1..16).rev() {
+            if bytes[i] != 0 {
+                break;
+            }
+
+            byte_length -= 1;
+        }
+
+        byte_length
+    }
+
+    fn min_byte_size() -> usize {
+        1
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'info, const SCALE: u8> ZeroCopyType<'info> for FnkDecimal<SCALE> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let (value, size) = <FnkInt as ZeroCopyType>::new(info, offset)?;
+        Ok((FnkDecimal(value.0), size))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        FnkInt::read_byte_size(bytes)
+    }
+}
+
+impl<'info, const SCALE: u8> CopyType<'info> for FnkDecimal<SCALE> {
+    type ZeroCopyType = FnkDecimal<SCALE>;
+
+    fn byte_size(&self) -> usize {
+        FnkInt(self.0).byte_size()
+    }
+
+    fn min_byte_size() -> usize {
+        FnkInt::min_byte_size()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'info> ZeroCopyType<'info> for FnkTimestamp {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let (value, size) = <FnkInt as ZeroCopyType>::new(info, offset)?;
+        Ok((FnkTimestamp(value.0), size))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        FnkInt::read_byte_size(bytes)
+    }
+}
+
+impl<'info> CopyType<'info> for FnkTimestamp {
+    type ZeroCopyType = FnkTimestamp;
+
+    fn byte_size(&self) -> usize {
+        FnkInt(self.0).byte_size()
+    }
+
+    fn min_byte_size() -> usize {
+        FnkInt::min_byte_size()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'info> ZeroCopyType<'info> for FnkDuration {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let (value, size) = <FnkInt as ZeroCopyType>::new(info, offset)?;
+        Ok((FnkDuration(value.0), size))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        FnkInt::read_byte_size(bytes)
+    }
+}
+
+impl<'info> CopyType<'info> for FnkDuration {
+    type ZeroCopyType = FnkDuration;
+
+    fn byte_size(&self) -> usize {
+        FnkInt(self.0).byte_size()
+    }
+
+    fn min_byte_size() -> usize {
+        FnkInt::min_byte_size()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
@@ -318,4 +567,47 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_128_read_byte_size() {
+        for number in [
+            0i128,
+            -1,
+            1 << 13,
+            i64::MAX as i128,
+            i64::MIN as i128 - 1,
+            1 << 100,
+            i128::MAX,
+            i128::MIN,
+        ] {
+            let fnk_number = FnkInt128::from(number);
+            let buffer = fnk_number.try_to_vec().unwrap();
+
+            assert_eq!(
+                FnkInt128::read_byte_size(&buffer).expect("Cannot read byte size"),
+                fnk_number.byte_size(),
+                "Incorrect result for {}",
+                number
+            );
+        }
+
+        for number in [
+            0u128,
+            1 << 14,
+            u64::MAX as u128,
+            u64::MAX as u128 + 1,
+            1 << 100,
+            u128::MAX,
+        ] {
+            let fnk_number = FnkUInt128::from(number);
+            let buffer = fnk_number.try_to_vec().unwrap();
+
+            assert_eq!(
+                FnkUInt128::read_byte_size(&buffer).expect("Cannot read byte size"),
+                fnk_number.byte_size(),
+                "Incorrect result for {}",
+                number
+            );
+        }
+    }
 }
@@ -1,10 +1,9 @@
 use std::mem::size_of;
 
-use solana_program::account_info::AccountInfo;
-
 pub use fnk::*;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::ZcSource;
 use crate::traits::{CopyType, ZeroCopyType};
 
 mod fnk;
@@ -12,10 +11,7 @@ mod fnk;
 macro_rules! impl_type {
     ($ty: ty) => {
         impl<'info> ZeroCopyType<'info> for $ty {
-            fn new(
-                info: &'info AccountInfo<'info>,
-                offset: usize,
-            ) -> FankorResult<(Self, Option<usize>)> {
+            fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
                 let bytes = info.try_borrow_data().map_err(|_| {
                     FankorErrorCode::ZeroCopyPossibleDeadlock {
                         type_name: std::any::type_name::<$ty>(),
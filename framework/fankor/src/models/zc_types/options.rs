@@ -1,14 +1,15 @@
 use std::any::type_name;
 use std::mem::size_of;
 
-use solana_program::account_info::AccountInfo;
+use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::program_option::COption;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::{Zc, ZcSource, ZcWriteGuard};
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info, T: ZeroCopyType<'info>> ZeroCopyType<'info> for Option<T> {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let flag = {
             let bytes =
                 info.try_borrow_data()
@@ -76,8 +77,117 @@ impl<'info, T: CopyType<'info>> CopyType<'info> for Option<T> {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+impl<'info, T: CopyType<'info>> Zc<'info, Option<T>> {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn is_some(&self) -> FankorResult<bool> {
+        let bytes =
+            self.info
+                .try_borrow_data()
+                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    type_name: type_name::<Self>(),
+                })?;
+
+        match bytes.get(self.offset) {
+            Some(flag) => Ok(*flag != 0),
+            None => Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: type_name::<Self>(),
+            }
+            .into()),
+        }
+    }
+
+    pub fn is_none(&self) -> FankorResult<bool> {
+        Ok(!self.is_some()?)
+    }
+
+    /// The zero-copy value inside the option if it is present.
+    pub fn inner(&self) -> FankorResult<Option<Zc<'info, T>>> {
+        if self.is_some()? {
+            Ok(Some(self.inner_unchecked()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    fn inner_unchecked(&self) -> Zc<'info, T> {
+        Zc::new_unchecked(self.info, self.offset + 1)
+    }
+}
+
+impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, Option<T>> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Sets the option to `Some(value)` in place through `guard`, only inserting
+    /// or removing the bytes that differ from the previous value.
+    pub fn set<U: CopyType<'info>>(
+        &self,
+        guard: &ZcWriteGuard<'info, U>,
+        value: &T,
+    ) -> FankorResult<()> {
+        if self.is_some()? {
+            return guard.write_value(&self.inner_unchecked(), value);
+        }
+
+        let mut bytes = Vec::with_capacity(1 + value.byte_size());
+        bytes.push(1);
+        value.serialize(&mut bytes)?;
+
+        guard.write_bytes(self, &bytes)
+    }
+}
+
+impl<'info, T: CopyType<'info> + BorshSerialize + BorshDeserialize> Zc<'info, Option<T>> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Sets the option to `None` in place through `guard`, removing the bytes
+    /// of the previous value and returning it.
+    pub fn take<U: CopyType<'info>>(
+        &self,
+        guard: &ZcWriteGuard<'info, U>,
+    ) -> FankorResult<Option<T>> {
+        let inner = match self.inner()? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let value = inner.try_value()?;
+        guard.write_bytes(self, &[0])?;
+
+        Ok(Some(value))
+    }
+
+    /// Replaces the option in place through `guard`, returning the previous
+    /// value.
+    pub fn replace<U: CopyType<'info>>(
+        &self,
+        guard: &ZcWriteGuard<'info, U>,
+        value: Option<T>,
+    ) -> FankorResult<Option<T>> {
+        match value {
+            Some(value) => {
+                let previous = match self.inner()? {
+                    Some(inner) => Some(inner.try_value()?),
+                    None => None,
+                };
+
+                self.set(guard, &value)?;
+
+                Ok(previous)
+            }
+            None => self.take(guard),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 impl<'info, T: ZeroCopyType<'info>> ZeroCopyType<'info> for COption<T> {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let size = size_of::<u32>();
         let flag = {
             let bytes =
@@ -142,3 +252,84 @@ impl<'info, T: CopyType<'info>> CopyType<'info> for COption<T> {
         size_of::<u32>() // Discriminant
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::models::FankorContext;
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_set_replace() {
+        let mut lamports = 0;
+        let mut vector = vec![9, 0, 7, 0, 0, 0, 0];
+        let accounts = [create_account_info_for_tests(&mut lamports, &mut vector)];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+        let guard = ZcWriteGuard::<u8>::new(&context, info).unwrap();
+        let zc = Zc::<Option<u32>>::new_unchecked(info, 1);
+        let last = guard.view(&Zc::<u8>::new_unchecked(info, 2)).unwrap();
+
+        assert!(zc.is_none().unwrap());
+        assert!(zc.inner().unwrap().is_none());
+
+        zc.set(&guard, &5).unwrap();
+        assert!(zc.is_some().unwrap());
+        assert_eq!(zc.try_value().unwrap(), Some(5));
+        assert_eq!(info.data.borrow()[..], [9, 1, 5, 0, 0, 0, 7]);
+        assert_eq!(last.offset().unwrap(), 6);
+
+        let previous = zc.replace(&guard, Some(6)).unwrap();
+        assert_eq!(previous, Some(5));
+        assert_eq!(zc.inner().unwrap().unwrap().try_value().unwrap(), 6);
+        assert_eq!(info.data.borrow()[..], [9, 1, 6, 0, 0, 0, 7]);
+        assert_eq!(last.offset().unwrap(), 6);
+    }
+
+    #[test]
+    fn test_take() {
+        let mut lamports = 0;
+        let mut vector = vec![9, 1, 5, 0, 0, 0, 7];
+        let accounts = [create_account_info_for_tests(&mut lamports, &mut vector)];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+        let guard = ZcWriteGuard::<u8>::new(&context, info).unwrap();
+        let zc = Zc::<Option<u32>>::new_unchecked(info, 1);
+        let inner = guard.view(&Zc::<u32>::new_unchecked(info, 2)).unwrap();
+        let last = guard.view(&Zc::<u8>::new_unchecked(info, 6)).unwrap();
+
+        assert_eq!(zc.take(&guard).unwrap(), Some(5));
+        assert!(zc.is_none().unwrap());
+        assert_eq!(info.data.borrow()[..3], [9, 0, 7]);
+        assert!(inner.offset().is_err());
+        assert_eq!(last.offset().unwrap(), 2);
+        assert_eq!(last.zc().unwrap().try_value().unwrap(), 7);
+
+        assert_eq!(zc.take(&guard).unwrap(), None);
+        assert_eq!(info.data.borrow()[..3], [9, 0, 7]);
+    }
+
+    #[test]
+    fn test_set_not_enough_length() {
+        let mut lamports = 0;
+        let mut vector = vec![9];
+        let accounts = [create_account_info_for_tests(&mut lamports, &mut vector)];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+        let guard = ZcWriteGuard::<u8>::new(&context, info).unwrap();
+        let zc = Zc::<Option<u32>>::new_unchecked(info, 1);
+
+        assert!(zc.set(&guard, &5).is_err());
+        assert!(zc.take(&guard).is_err());
+    }
+}
@@ -1,16 +1,16 @@
 use borsh::BorshDeserialize;
-use solana_program::account_info::AccountInfo;
 
 use crate::{
     errors::FankorErrorCode,
     errors::FankorResult,
-    prelude::{FnkInt, FnkUInt},
+    prelude::{FnkInt, FnkInt128, FnkUInt, FnkUInt128},
 };
-use crate::prelude::{FnkRange, FnkURange};
+use crate::models::ZcSource;
+use crate::prelude::{FnkRange, FnkRange128, FnkURange, FnkURange128};
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info> ZeroCopyType<'info> for FnkURange {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let bytes =
             info.try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
@@ -50,7 +50,7 @@ impl<'info> CopyType<'info> for FnkURange {
 // ----------------------------------------------------------------------------
 
 impl<'info> ZeroCopyType<'info> for FnkRange {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let bytes =
             info.try_borrow_data()
                 .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
@@ -82,3 +82,81 @@ impl<'info> CopyType<'info> for FnkRange {
         FnkInt::min_byte_size() * 2
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'info> ZeroCopyType<'info> for FnkURange128 {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let bytes =
+            info.try_borrow_data()
+                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    type_name: std::any::type_name::<Self>(),
+                })?;
+        let mut bytes = &bytes[offset..];
+        let initial_size = bytes.len();
+        let value = FnkURange128::deserialize(&mut bytes)?;
+
+        Ok((value, Some(initial_size - bytes.len())))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        let mut size = FnkUInt128::read_byte_size(bytes)?;
+        size += FnkInt128::read_byte_size(&bytes[size..])?;
+
+        Ok(size)
+    }
+}
+
+impl<'info> CopyType<'info> for FnkURange128 {
+    type ZeroCopyType = FnkURange128;
+
+    fn byte_size(&self) -> usize {
+        let (point, length) = self.point_and_length();
+
+        point.byte_size() + length.byte_size()
+    }
+
+    fn min_byte_size() -> usize {
+        FnkUInt128::min_byte_size() + FnkInt128::min_byte_size()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'info> ZeroCopyType<'info> for FnkRange128 {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let bytes =
+            info.try_borrow_data()
+                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    type_name: std::any::type_name::<Self>(),
+                })?;
+        let mut bytes = &bytes[offset..];
+        let initial_size = bytes.len();
+        let value = FnkRange128::deserialize(&mut bytes)?;
+
+        Ok((value, Some(initial_size - bytes.len())))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        let mut size = FnkInt128::read_byte_size(bytes)?;
+        size += FnkInt128::read_byte_size(&bytes[size..])?;
+
+        Ok(size)
+    }
+}
+
+impl<'info> CopyType<'info> for FnkRange128 {
+    type ZeroCopyType = FnkRange128;
+
+    fn byte_size(&self) -> usize {
+        self.from().byte_size() + self.to().byte_size()
+    }
+
+    fn min_byte_size() -> usize {
+        FnkInt128::min_byte_size() * 2
+    }
+}
@@ -0,0 +1,116 @@
+use std::cell::{Ref, RefMut};
+use std::ops::Deref;
+
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+
+/// The bytes the zero-copy types work over: the data of an account or a
+/// read-only slice, e.g. the instruction data.
+#[derive(Debug, Copy, Clone)]
+pub enum ZcSource<'info> {
+    Account(&'info AccountInfo<'info>),
+    Bytes(&'info [u8]),
+}
+
+impl<'info> ZcSource<'info> {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The account that contains the bytes, if any.
+    pub fn account(&self) -> Option<&'info AccountInfo<'info>> {
+        match *self {
+            ZcSource::Account(info) => Some(info),
+            ZcSource::Bytes(_) => None,
+        }
+    }
+
+    pub fn data_len(&self) -> usize {
+        match *self {
+            ZcSource::Account(info) => info.data_len(),
+            ZcSource::Bytes(bytes) => bytes.len(),
+        }
+    }
+
+    /// Whether the bytes belong to an account created for the tests.
+    #[cfg(any(feature = "test-utils", test))]
+    pub(crate) fn is_test_account(&self) -> bool {
+        match *self {
+            ZcSource::Account(info) => {
+                info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER
+            }
+            ZcSource::Bytes(_) => false,
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Borrows the bytes.
+    ///
+    /// # Panics
+    /// If the account data is already borrowed mutably.
+    pub fn borrow_data(&self) -> ZcSourceRef<'info> {
+        match *self {
+            ZcSource::Account(info) => ZcSourceRef::Account(info.data.borrow()),
+            ZcSource::Bytes(bytes) => ZcSourceRef::Bytes(bytes),
+        }
+    }
+
+    /// Borrows the bytes. It fails if the account data is already borrowed
+    /// mutably.
+    pub fn try_borrow_data(&self) -> Result<ZcSourceRef<'info>, ProgramError> {
+        match *self {
+            ZcSource::Account(info) => Ok(ZcSourceRef::Account(info.try_borrow_data()?)),
+            ZcSource::Bytes(bytes) => Ok(ZcSourceRef::Bytes(bytes)),
+        }
+    }
+
+    /// Borrows the bytes mutably. It fails if the account data is already
+    /// borrowed or the bytes are read-only.
+    pub fn try_borrow_mut_data(&self) -> Result<RefMut<'info, &'info mut [u8]>, ProgramError> {
+        match *self {
+            ZcSource::Account(info) => info.try_borrow_mut_data(),
+            ZcSource::Bytes(_) => Err(ProgramError::AccountBorrowFailed),
+        }
+    }
+
+    /// Reallocates the account data. It fails if the bytes are read-only.
+    pub fn realloc(&self, new_len: usize, zero_init: bool) -> ProgramResult {
+        match *self {
+            ZcSource::Account(info) => info.realloc(new_len, zero_init),
+            ZcSource::Bytes(_) => Err(ProgramError::InvalidRealloc),
+        }
+    }
+}
+
+impl<'info> From<&'info AccountInfo<'info>> for ZcSource<'info> {
+    fn from(info: &'info AccountInfo<'info>) -> Self {
+        ZcSource::Account(info)
+    }
+}
+
+impl<'info> From<&'info [u8]> for ZcSource<'info> {
+    fn from(bytes: &'info [u8]) -> Self {
+        ZcSource::Bytes(bytes)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A shared borrow of the bytes of a [`ZcSource`].
+pub enum ZcSourceRef<'info> {
+    Account(Ref<'info, &'info mut [u8]>),
+    Bytes(&'info [u8]),
+}
+
+impl<'info> Deref for ZcSourceRef<'info> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            ZcSourceRef::Account(bytes) => bytes,
+            ZcSourceRef::Bytes(bytes) => bytes,
+        }
+    }
+}
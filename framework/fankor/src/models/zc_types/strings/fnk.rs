@@ -1,17 +1,18 @@
 use borsh::BorshDeserialize;
-use solana_program::account_info::AccountInfo;
 
 use crate::errors::{FankorErrorCode, FankorResult};
-use crate::prelude::{FnkString, FnkUInt};
+use crate::models::types::{check_no_null_characters, fixed_string_length};
+use crate::models::ZcSource;
+use crate::prelude::{FnkBoundedString, FnkFixedString, FnkString, FnkUInt};
 use crate::traits::{CopyType, ZeroCopyType};
 
 pub struct ZcFnkString<'info> {
-    info: &'info AccountInfo<'info>,
+    info: ZcSource<'info>,
     offset: usize,
 }
 
 impl<'info> ZeroCopyType<'info> for ZcFnkString<'info> {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         Ok((ZcFnkString { info, offset }, None))
     }
 
@@ -41,6 +42,19 @@ impl<'info, 'a> CopyType<'info> for FnkString<'a> {
     }
 }
 
+impl<'info, 'a, const N: usize> CopyType<'info> for FnkBoundedString<'a, N> {
+    type ZeroCopyType = ZcFnkString<'info>;
+
+    fn byte_size(&self) -> usize {
+        let length = FnkUInt::from(self.len() as u64);
+        length.byte_size() + self.len()
+    }
+
+    fn min_byte_size() -> usize {
+        FnkUInt::min_byte_size()
+    }
+}
+
 impl<'info> ZcFnkString<'info> {
     // GETTERS ----------------------------------------------------------------
 
@@ -130,10 +144,142 @@ impl<'info> ZcFnkString<'info> {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+pub struct ZcFnkFixedString<'info, const N: usize> {
+    info: ZcSource<'info>,
+    offset: usize,
+}
+
+impl<'info, const N: usize> ZeroCopyType<'info> for ZcFnkFixedString<'info, N> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        Ok((ZcFnkFixedString { info, offset }, Some(N)))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        if bytes.len() < N {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "FnkFixedString",
+            }
+            .into());
+        }
+
+        Ok(N)
+    }
+}
+
+impl<'info, const N: usize> CopyType<'info> for FnkFixedString<N> {
+    type ZeroCopyType = ZcFnkFixedString<'info, N>;
+
+    fn byte_size(&self) -> usize {
+        N
+    }
+
+    fn min_byte_size() -> usize {
+        N
+    }
+}
+
+impl<'info, const N: usize> ZcFnkFixedString<'info, N> {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The length of the string without the padding.
+    pub fn len(&self) -> FankorResult<usize> {
+        let bytes =
+            self.info
+                .try_borrow_data()
+                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    type_name: "ZcFnkFixedString",
+                })?;
+        let bytes = &bytes[self.offset..];
+
+        if bytes.len() < N {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "FnkFixedString",
+            }
+            .into());
+        }
+
+        Ok(fixed_string_length(&bytes[..N]))
+    }
+
+    pub fn is_empty(&self) -> FankorResult<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Reads the string as `&str` without copying it.
+    pub fn borrow_as_str<R, F: FnOnce(&str) -> R>(&self, f: F) -> FankorResult<R> {
+        let bytes =
+            self.info
+                .try_borrow_data()
+                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    type_name: "ZcFnkFixedString",
+                })?;
+        let bytes = &bytes[self.offset..];
+
+        if bytes.len() < N {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "FnkFixedString",
+            }
+            .into());
+        }
+
+        let bytes = &bytes[..N];
+        let text = std::str::from_utf8(&bytes[..fixed_string_length(bytes)]).map_err(|_| {
+            FankorErrorCode::ZeroCopyCannotDeserialize {
+                type_name: "FnkFixedString",
+            }
+        })?;
+
+        Ok(f(text))
+    }
+
+    /// Replaces the content of the string in place. The size of the type does
+    /// not change, so the rest of the account is not moved.
+    pub fn write_str(&self, value: &str) -> FankorResult<()> {
+        let value = value.as_bytes();
+
+        if value.len() > N {
+            return Err(FankorErrorCode::BoundedLengthExceeded {
+                max: N,
+                actual: value.len(),
+            }
+            .into());
+        }
+
+        check_no_null_characters(value)?;
+
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
+            FankorErrorCode::ZeroCopyPossibleDeadlock {
+                type_name: "ZcFnkFixedString",
+            }
+        })?;
+        let bytes = &mut bytes[self.offset..];
+
+        if bytes.len() < N {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "FnkFixedString",
+            }
+            .into());
+        }
+
+        bytes[..value.len()].copy_from_slice(value);
+        bytes[value.len()..N].fill(0);
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use std::mem::size_of;
 
+    use crate::tests::create_account_info_for_tests;
+
     use super::*;
 
     #[test]
@@ -143,4 +289,26 @@ mod test {
 
         assert_eq!(size, 1 + 5 * size_of::<u8>());
     }
+
+    #[test]
+    fn test_fixed_read_and_write() {
+        let mut lamports = 0;
+        let mut vector = vec![b'a', b'b', 0, 0, 99];
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkFixedString::<4>::new(ZcSource::Account(&info), 0).unwrap();
+
+        assert_eq!(ZcFnkFixedString::<4>::read_byte_size(&[0; 5]).unwrap(), 4);
+        assert_eq!(zc.len().unwrap(), 2);
+        assert_eq!(zc.borrow_as_str(|v| v.to_string()).unwrap(), "ab");
+
+        zc.write_str("xyz").unwrap();
+        assert_eq!(zc.borrow_as_str(|v| v.to_string()).unwrap(), "xyz");
+
+        zc.write_str("q").unwrap();
+        assert_eq!(zc.borrow_as_str(|v| v.to_string()).unwrap(), "q");
+        assert!(zc.write_str("12345").is_err());
+        assert!(zc.write_str("a\0").is_err());
+
+        assert_eq!(info.data.borrow()[..], [b'q', 0, 0, 0, 99]);
+    }
 }
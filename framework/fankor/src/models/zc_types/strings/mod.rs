@@ -1,22 +1,22 @@
 use std::mem::size_of;
 
 use borsh::BorshDeserialize;
-use solana_program::account_info::AccountInfo;
 
 pub use fnk::*;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::ZcSource;
 use crate::traits::{CopyType, ZeroCopyType};
 
 mod fnk;
 
 pub struct ZcString<'info> {
-    info: &'info AccountInfo<'info>,
+    info: ZcSource<'info>,
     offset: usize,
 }
 
 impl<'info> ZeroCopyType<'info> for ZcString<'info> {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         Ok((ZcString { info, offset }, None))
     }
 
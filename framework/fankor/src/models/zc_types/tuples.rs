@@ -1,15 +1,11 @@
 use std::any::type_name;
 
-use solana_program::account_info::AccountInfo;
-
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::ZcSource;
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info> ZeroCopyType<'info> for () {
-    fn new(
-        _info: &'info AccountInfo<'info>,
-        _offset: usize,
-    ) -> FankorResult<(Self, Option<usize>)> {
+    fn new(_info: ZcSource<'info>, _offset: usize) -> FankorResult<(Self, Option<usize>)> {
         Ok(((), Some(0)))
     }
 
@@ -35,7 +31,7 @@ macro_rules! impl_tuple {
         #[allow(non_snake_case)]
         impl<'info, $($types: ZeroCopyType<'info>),*> ZeroCopyType<'info> for ($($types),*) {
             fn new(
-                info: &'info AccountInfo<'info>,
+                info: ZcSource<'info>,
                 mut offset: usize,
             ) -> FankorResult<(Self, Option<usize>)> {
                 let original_offset = offset;
@@ -2,22 +2,21 @@ use std::marker::PhantomData;
 use std::mem::size_of;
 
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::account_info::AccountInfo;
 
 use crate::errors::{FankorErrorCode, FankorResult};
-use crate::models::Zc;
+use crate::models::{Zc, ZcSource};
 use crate::models::zc_types::vec::Iter;
-use crate::prelude::{FnkMap, FnkSet, FnkUInt, FnkVec};
+use crate::prelude::{FnkBoundedVec, FnkMap, FnkSet, FnkUInt, FnkVec};
 use crate::traits::{CopyType, ZeroCopyType};
 
 pub struct ZcFnkVec<'info, T: CopyType<'info>> {
-    info: &'info AccountInfo<'info>,
+    info: ZcSource<'info>,
     offset: usize,
     _data: PhantomData<T>,
 }
 
 impl<'info, T: CopyType<'info>> ZeroCopyType<'info> for ZcFnkVec<'info, T> {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         Ok((
             ZcFnkVec {
                 info,
@@ -116,7 +115,7 @@ impl<'info, T: CopyType<'info>> ZcFnkVec<'info, T> {
 
     /// The length of the vector.
     pub fn len(&self) -> FankorResult<usize> {
-        let bytes = (*self.info.data).borrow();
+        let bytes = self.info.borrow_data();
         let mut bytes = &bytes[self.offset..];
         let len = FnkUInt::deserialize(&mut bytes)?;
 
@@ -133,7 +132,7 @@ impl<'info, T: CopyType<'info>> ZcFnkVec<'info, T> {
 
     /// Gets the element at the specified position.
     pub fn get_zc_index(&self, index: usize) -> FankorResult<Option<Zc<'info, T>>> {
-        let bytes = (*self.info.data).borrow();
+        let bytes = self.info.borrow_data();
         let mut bytes = &bytes[self.offset..];
         let initial_size = bytes.len();
 
@@ -171,7 +170,7 @@ impl<'info, T: CopyType<'info>> ZcFnkVec<'info, T> {
     {
         let mut offset = self.offset;
         let mut length = {
-            let original_bytes = (*self.info.data).borrow();
+            let original_bytes = self.info.borrow_data();
             let bytes = &original_bytes[self.offset..];
             let mut bytes2 = bytes;
             let len = FnkUInt::deserialize(&mut bytes2)?;
@@ -187,7 +186,7 @@ impl<'info, T: CopyType<'info>> ZcFnkVec<'info, T> {
             let zc = Zc::<T>::new_unchecked(self.info, offset);
 
             if !f(&zc)? {
-                zc.remove_unchecked()?;
+                zc.remove()?;
                 length -= 1;
             } else {
                 offset += zc.byte_size()?;
@@ -200,7 +199,7 @@ impl<'info, T: CopyType<'info>> ZcFnkVec<'info, T> {
     }
 
     pub fn iter(&self) -> Iter<'info, T> {
-        let bytes = (*self.info.data).borrow();
+        let bytes = self.info.borrow_data();
         let mut bytes = &bytes[self.offset..];
         let original_len = bytes.len();
         let len =
@@ -220,7 +219,7 @@ impl<'info, T: CopyType<'info>> ZcFnkVec<'info, T> {
     /// Writes the length of the vector.
     pub fn write_len_unchecked(&self, new_length: FnkUInt) -> FankorResult<()> {
         let zc = Zc::new_unchecked(self.info, self.offset);
-        zc.try_write_value_unchecked(&new_length)
+        zc.write_value(&new_length)
     }
 }
 
@@ -232,7 +231,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcFnkVec<'info, T> {
     pub fn append(&self, values: &[T]) -> FankorResult<usize> {
         // Get current size.
         let mut size = {
-            let bytes = (*self.info.data).borrow();
+            let bytes = self.info.borrow_data();
             let bytes = &bytes[self.offset..];
             Self::read_byte_size(bytes)?
         };
@@ -249,7 +248,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcFnkVec<'info, T> {
         for value in values {
             let zc = Zc::new_unchecked(self.info, self.offset + size);
             let value_size = value.byte_size();
-            zc.try_write_value_with_sizes_unchecked(value, 0, value_size)?;
+            zc.write_value_with_sizes(value, 0, value_size)?;
             size += value_size;
         }
 
@@ -265,7 +264,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcFnkVec<'info, T> {
     pub fn append_zc(&self, values: &[Zc<'info, T>]) -> FankorResult<usize> {
         // Get current size.
         let mut size = {
-            let bytes = (*self.info.data).borrow();
+            let bytes = self.info.borrow_data();
             let bytes = &bytes[self.offset..];
             Self::read_byte_size(bytes)?
         };
@@ -280,7 +279,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcFnkVec<'info, T> {
 
         // Append values.
         for value in values {
-            let original_value_bytes = value.info.data.try_borrow().map_err(|_| {
+            let original_value_bytes = value.info.try_borrow_data().map_err(|_| {
                 FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 }
@@ -290,7 +289,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcFnkVec<'info, T> {
             let value_bytes = &value_bytes[..value_size];
 
             let zc = Zc::<T>::new_unchecked(self.info, self.offset + size);
-            zc.try_write_bytes_with_sizes_unchecked(value_bytes, 0)?;
+            zc.write_bytes_with_size(value_bytes, 0)?;
             size += value_size;
         }
 
@@ -314,12 +313,128 @@ impl<'info, T: CopyType<'info>> IntoIterator for ZcFnkVec<'info, T> {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Zero-copy version of `FnkBoundedVec` that checks the bound before
+/// appending new elements.
+pub struct ZcFnkBoundedVec<'info, T: CopyType<'info>, const N: usize>(ZcFnkVec<'info, T>);
+
+impl<'info, T: CopyType<'info>, const N: usize> ZeroCopyType<'info>
+    for ZcFnkBoundedVec<'info, T, N>
+{
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let (inner, size) = ZcFnkVec::new(info, offset)?;
+        Ok((ZcFnkBoundedVec(inner), size))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        ZcFnkVec::<T>::read_byte_size(bytes)
+    }
+}
+
+impl<'info, T: CopyType<'info>, const N: usize> CopyType<'info> for FnkBoundedVec<T, N> {
+    type ZeroCopyType = ZcFnkBoundedVec<'info, T, N>;
+
+    fn byte_size(&self) -> usize {
+        let len = FnkUInt::from(self.len() as u64);
+        len.byte_size() + self.iter().map(|v| v.byte_size()).sum::<usize>()
+    }
+
+    fn min_byte_size() -> usize {
+        FnkUInt::min_byte_size()
+    }
+}
+
+impl<'info, T: CopyType<'info>, const N: usize> ZcFnkBoundedVec<'info, T, N> {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The length of the vector.
+    pub fn len(&self) -> FankorResult<usize> {
+        self.0.len()
+    }
+
+    /// Whether the vector is empty or not
+    pub fn is_empty(&self) -> FankorResult<bool> {
+        self.0.is_empty()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Gets the element at the specified position.
+    pub fn get_zc_index(&self, index: usize) -> FankorResult<Option<Zc<'info, T>>> {
+        self.0.get_zc_index(index)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// # Safety
+    ///
+    /// DO NOT WRITE TO THE ACCOUNT WHILE INSIDE THE PREDICATE.
+    pub fn retain<F>(&self, f: F) -> FankorResult<()>
+    where
+        F: FnMut(&Zc<T>) -> FankorResult<bool>,
+    {
+        self.0.retain(f)
+    }
+
+    pub fn iter(&self) -> Iter<'info, T> {
+        self.0.iter()
+    }
+}
+
+impl<'info, T: CopyType<'info> + BorshSerialize, const N: usize> ZcFnkBoundedVec<'info, T, N> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Appends a list of elements to the end of the vector.
+    /// Returns the size of the vector in bytes.
+    pub fn append(&self, values: &[T]) -> FankorResult<usize> {
+        self.check_new_length(values.len())?;
+        self.0.append(values)
+    }
+
+    /// Appends a list of zero-copy elements to the end of the vector.
+    /// Returns the size of the vector in bytes.
+    pub fn append_zc(&self, values: &[Zc<'info, T>]) -> FankorResult<usize> {
+        self.check_new_length(values.len())?;
+        self.0.append_zc(values)
+    }
+
+    fn check_new_length(&self, additional: usize) -> FankorResult<()> {
+        let new_length = self
+            .len()?
+            .checked_add(additional)
+            .ok_or(FankorErrorCode::ZeroCopyLengthFieldOverflow)?;
+
+        if new_length > N {
+            return Err(FankorErrorCode::BoundedLengthExceeded {
+                max: N,
+                actual: new_length,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info, T: CopyType<'info>, const N: usize> IntoIterator for ZcFnkBoundedVec<'info, T, N> {
+    type Item = Zc<'info, T>;
+    type IntoIter = Iter<'info, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use std::cell::RefCell;
     use std::mem::size_of;
     use std::rc::Rc;
 
+    use solana_program::account_info::AccountInfo;
     use solana_program::pubkey::Pubkey;
 
     use crate::tests::create_account_info_for_tests;
@@ -339,7 +454,7 @@ mod test {
         let mut lamports = 0;
         let mut vector = vec![5, 3, 3, 3, 3, 3];
         let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let (zc, _) = ZcFnkVec::<u8>::new(&info, 0).unwrap();
+        let (zc, _) = ZcFnkVec::<u8>::new(ZcSource::Account(&info), 0).unwrap();
 
         assert_eq!(zc.len().unwrap(), 5);
 
@@ -359,7 +474,7 @@ mod test {
         let mut lamports = 0;
         let mut vector = vec![2, 3, 3, 3, 3, 3];
         let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let (zc, _) = ZcFnkVec::<u8>::new(&info, 0).unwrap();
+        let (zc, _) = ZcFnkVec::<u8>::new(ZcSource::Account(&info), 0).unwrap();
         zc.write_len_unchecked(FnkUInt::new(5)).unwrap();
 
         assert_eq!(zc.len().unwrap(), 5);
@@ -384,7 +499,7 @@ mod test {
         vector[2] = 3;
 
         let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let (zc, _) = ZcFnkVec::<u8>::new(&info, 0).unwrap();
+        let (zc, _) = ZcFnkVec::<u8>::new(ZcSource::Account(&info), 0).unwrap();
         let new_offset = zc.append(&[3; 500]).unwrap();
 
         assert_eq!(zc.len().unwrap(), 502);
@@ -412,7 +527,7 @@ mod test {
         vector[4] = 4;
 
         let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let (zc, _) = ZcFnkVec::<(u8, u8)>::new(&info, 0).unwrap();
+        let (zc, _) = ZcFnkVec::<(u8, u8)>::new(ZcSource::Account(&info), 0).unwrap();
         let data = vec![(3, 4); 500];
         let new_offset = zc.append(&data).unwrap();
 
@@ -452,7 +567,7 @@ mod test {
             rent_epoch: 0,
         };
 
-        let (zc, _) = ZcFnkVec::<u8>::new(&info, 0).unwrap();
+        let (zc, _) = ZcFnkVec::<u8>::new(ZcSource::Account(&info), 0).unwrap();
         let zc_el = Zc::<u8>::new_unchecked(&info_el, 0);
         let new_offset = zc.append_zc(&[zc_el.clone(), zc_el]).unwrap();
 
@@ -485,7 +600,7 @@ mod test {
         vector[8] = 1;
 
         let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let (zc, _) = ZcFnkVec::<u8>::new(&info, 0).unwrap();
+        let (zc, _) = ZcFnkVec::<u8>::new(ZcSource::Account(&info), 0).unwrap();
         zc.retain(|zc_el| {
             let value = zc_el.try_value()?;
             Ok(value != 1)
@@ -504,4 +619,23 @@ mod test {
 
         assert_eq!(count, 3);
     }
+
+    #[test]
+    fn test_bounded_append() {
+        let mut lamports = 0;
+        let mut vector = vec![0; 10];
+        vector[0] = 1;
+        vector[1] = 3;
+
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkBoundedVec::<u8, 3>::new(ZcSource::Account(&info), 0).unwrap();
+
+        assert!(zc.append(&[3; 3]).is_err());
+        assert_eq!(zc.len().unwrap(), 1);
+
+        let new_offset = zc.append(&[3; 2]).unwrap();
+        assert_eq!(zc.len().unwrap(), 3);
+        assert_eq!(new_offset, 4);
+        assert!(zc.append(&[3]).is_err());
+    }
 }
@@ -2,25 +2,24 @@ use std::marker::PhantomData;
 use std::mem::size_of;
 
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::account_info::AccountInfo;
 
 pub use fnk::*;
 
 use crate::errors::{FankorErrorCode, FankorResult};
-use crate::models::Zc;
+use crate::models::{Zc, ZcSource};
 use crate::traits::{CopyType, ZeroCopyType};
 use crate::utils::writers::ArrayWriter;
 
 mod fnk;
 
 pub struct ZcVec<'info, T: CopyType<'info>> {
-    info: &'info AccountInfo<'info>,
+    info: ZcSource<'info>,
     offset: usize,
     _data: PhantomData<T>,
 }
 
 impl<'info, T: CopyType<'info>> ZeroCopyType<'info> for ZcVec<'info, T> {
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         Ok((
             ZcVec {
                 info,
@@ -68,7 +67,7 @@ impl<'info, T: CopyType<'info>> ZcVec<'info, T> {
 
     /// The length of the vector.
     pub fn len(&self) -> FankorResult<usize> {
-        let bytes = (*self.info.data).borrow();
+        let bytes = self.info.borrow_data();
         let mut bytes = &bytes[self.offset..];
         let len = u32::deserialize(&mut bytes)?;
 
@@ -84,7 +83,7 @@ impl<'info, T: CopyType<'info>> ZcVec<'info, T> {
 
     /// Gets the element at the specified position.
     pub fn get_zc_index(&self, index: usize) -> FankorResult<Option<Zc<'info, T>>> {
-        let bytes = (*self.info.data).borrow();
+        let bytes = self.info.borrow_data();
         let mut bytes = &bytes[self.offset..];
         let initial_size = bytes.len();
 
@@ -128,7 +127,7 @@ impl<'info, T: CopyType<'info>> ZcVec<'info, T> {
             let zc = Zc::<T>::new_unchecked(self.info, offset);
 
             if !f(&zc)? {
-                zc.remove_unchecked()?;
+                zc.remove()?;
                 length -= 1;
             } else {
                 offset += zc.byte_size()?;
@@ -141,7 +140,7 @@ impl<'info, T: CopyType<'info>> ZcVec<'info, T> {
     }
 
     pub fn iter(&self) -> Iter<'info, T> {
-        let bytes = (*self.info.data).borrow();
+        let bytes = self.info.borrow_data();
         let mut bytes = &bytes[self.offset..];
         let original_len = bytes.len();
         let len = u32::deserialize(&mut bytes).expect("Failed to get length of ZcVec in iterator");
@@ -157,7 +156,11 @@ impl<'info, T: CopyType<'info>> ZcVec<'info, T> {
 
     /// Writes the length of the vector.
     pub fn write_len_unchecked(&self, new_length: u32) -> FankorResult<()> {
-        let mut bytes = (*self.info.data).borrow_mut();
+        let mut bytes = self.info.try_borrow_mut_data().map_err(|_| {
+            FankorErrorCode::ZeroCopyPossibleDeadlock {
+                type_name: std::any::type_name::<Self>(),
+            }
+        })?;
         let bytes = &mut bytes[self.offset..];
         let mut writer = ArrayWriter::new(bytes);
         u32::serialize(&new_length, &mut writer)?;
@@ -174,7 +177,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcVec<'info, T> {
     pub fn append(&self, values: &[T]) -> FankorResult<usize> {
         // Get current size.
         let mut size = {
-            let bytes = (*self.info.data).borrow();
+            let bytes = self.info.borrow_data();
             let bytes = &bytes[self.offset..];
             Self::read_byte_size(bytes)?
         };
@@ -192,7 +195,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcVec<'info, T> {
         for value in values {
             let zc = Zc::new_unchecked(self.info, self.offset + size);
             let v_size = value.byte_size();
-            zc.try_write_value_with_sizes_unchecked(value, 0, v_size)?;
+            zc.write_value_with_sizes(value, 0, v_size)?;
             size += v_size;
         }
 
@@ -204,7 +207,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcVec<'info, T> {
     pub fn append_zc(&self, values: &[Zc<'info, T>]) -> FankorResult<usize> {
         // Get current size.
         let mut size = {
-            let bytes = (*self.info.data).borrow();
+            let bytes = self.info.borrow_data();
             let bytes = &bytes[self.offset..];
             Self::read_byte_size(bytes)?
         };
@@ -220,7 +223,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcVec<'info, T> {
 
         // Append values.
         for value in values {
-            let original_value_bytes = value.info.data.try_borrow().map_err(|_| {
+            let original_value_bytes = value.info.try_borrow_data().map_err(|_| {
                 FankorErrorCode::ZeroCopyPossibleDeadlock {
                     type_name: std::any::type_name::<Self>(),
                 }
@@ -230,7 +233,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcVec<'info, T> {
             let value_bytes = &value_bytes[..value_size];
 
             let zc = Zc::<T>::new_unchecked(self.info, self.offset + size);
-            zc.try_write_bytes_with_sizes_unchecked(value_bytes, 0)?;
+            zc.write_bytes_with_size(value_bytes, 0)?;
             size += value_size;
         }
 
@@ -252,7 +255,7 @@ impl<'info, T: CopyType<'info>> IntoIterator for ZcVec<'info, T> {
 // ----------------------------------------------------------------------------
 
 pub struct Iter<'info, T: CopyType<'info>> {
-    pub(crate) info: &'info AccountInfo<'info>,
+    pub(crate) info: ZcSource<'info>,
     pub(crate) len: usize,
     pub(crate) index: usize,
     pub(crate) offset: usize,
@@ -268,7 +271,7 @@ impl<'info, T: CopyType<'info>> Iterator for Iter<'info, T> {
         }
 
         if self.index != 0 {
-            let bytes = (*self.info.data).borrow();
+            let bytes = self.info.borrow_data();
             let bytes = &bytes[self.offset..];
 
             self.offset += T::ZeroCopyType::read_byte_size(bytes)
@@ -304,6 +307,7 @@ mod test {
     use std::cell::RefCell;
     use std::rc::Rc;
 
+    use solana_program::account_info::AccountInfo;
     use solana_program::pubkey::Pubkey;
 
     use crate::tests::create_account_info_for_tests;
@@ -323,7 +327,7 @@ mod test {
         let mut lamports = 0;
         let mut vector = vec![5, 0, 0, 0, 3, 3, 3, 3, 3];
         let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let (zc, _) = ZcVec::<u8>::new(&info, 0).unwrap();
+        let (zc, _) = ZcVec::<u8>::new(ZcSource::Account(&info), 0).unwrap();
 
         assert_eq!(zc.len().unwrap(), 5);
 
@@ -343,13 +347,13 @@ mod test {
         let mut lamports = 0;
         let mut vector = vec![5, 0, 0, 0, 1, 3, 1, 2, 1, 3, 1, 2, 1, 3];
         let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let (zc, _) = ZcVec::<Option<u8>>::new(&info, 0).unwrap();
+        let (zc, _) = ZcVec::<Option<u8>>::new(ZcSource::Account(&info), 0).unwrap();
 
         zc.iter().for_each(|zc_el| {
             let value = zc_el.try_value().unwrap().unwrap();
 
             if value == 2 {
-                zc_el.try_write_value_unchecked(&None).unwrap();
+                zc_el.write_value(&None).unwrap();
             }
         });
 
@@ -376,7 +380,7 @@ mod test {
         let mut lamports = 0;
         let mut vector = vec![3, 0, 0, 0, 3, 3, 3, 3, 3];
         let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let (zc, _) = ZcVec::<u8>::new(&info, 0).unwrap();
+        let (zc, _) = ZcVec::<u8>::new(ZcSource::Account(&info), 0).unwrap();
         zc.write_len_unchecked(5).unwrap();
 
         assert_eq!(zc.len().unwrap(), 5);
@@ -397,7 +401,7 @@ mod test {
         let mut lamports = 0;
         let mut vector = vec![3, 0, 0, 0, 3, 3, 3, 0, 0];
         let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let (zc, _) = ZcVec::<u8>::new(&info, 0).unwrap();
+        let (zc, _) = ZcVec::<u8>::new(ZcSource::Account(&info), 0).unwrap();
         let new_offset = zc.append(&[3, 3]).unwrap();
 
         assert_eq!(zc.len().unwrap(), 5);
@@ -432,7 +436,7 @@ mod test {
             rent_epoch: 0,
         };
 
-        let (zc, _) = ZcVec::<u8>::new(&info, 0).unwrap();
+        let (zc, _) = ZcVec::<u8>::new(ZcSource::Account(&info), 0).unwrap();
         let zc_el = Zc::<u8>::new_unchecked(&info_el, 0);
         let new_offset = zc.append_zc(&[zc_el.clone(), zc_el]).unwrap();
 
@@ -6,6 +6,8 @@ pub use bs58;
 pub use lazy_static::lazy_static;
 #[cfg(feature = "metadata-program")]
 pub use mpl_token_metadata;
+#[cfg(feature = "address-lookup-table-program")]
+pub use solana_address_lookup_table_program;
 pub use solana_program;
 pub use solana_program::account_info::{AccountInfo, next_account_info};
 pub use solana_program::instruction::AccountMeta;
@@ -50,6 +52,9 @@ pub use crate::tests::*;
 pub use crate::traits::*;
 #[cfg(feature = "ts-gen")]
 pub use crate::ts_gen;
+#[cfg(target_os = "solana")]
+pub use crate::utils::heap::heap_usage;
+pub use crate::utils::heap::{bump_heap_allocator, MAX_HEAP_LENGTH};
 pub use crate::utils::seeds::byte_seeds_to_slices;
 pub use crate::utils::type_id_of;
 pub use crate::utils::writers::ArrayWriter;
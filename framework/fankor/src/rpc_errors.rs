@@ -2,10 +2,13 @@ use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
 use std::str::FromStr;
 
+use crate::errors::error_name_hash;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RpcFankorError<'a> {
     pub code: u32,
     pub name: Cow<'a, str>,
+    pub name_hash: u32,
     pub message: Cow<'a, str>,
 }
 
@@ -15,13 +18,21 @@ impl<'a> RpcFankorError<'a> {
     pub fn new(code: u32, name: Cow<'a, str>, message: Cow<'a, str>) -> Self {
         Self {
             code,
+            name_hash: error_name_hash(&name),
             name,
             message,
         }
     }
 
+    /// Parses the error from the logs of a transaction. The errors logged by
+    /// programs with the `minimal-logs` feature have neither name nor message,
+    /// use [`RpcFankorError::resolve`] to reconstruct them.
     pub fn from_logs(logs: &'a [String]) -> Option<Self> {
         for log in logs {
+            if let Some(error) = Self::from_minimal_log(log) {
+                return Some(error);
+            }
+
             let log = match log.strip_prefix("Program log: FankorError occurred. Error Name: ") {
                 Some(v) => v,
                 None => continue,
@@ -58,6 +69,47 @@ impl<'a> RpcFankorError<'a> {
 
         None
     }
+
+    fn from_minimal_log(log: &str) -> Option<Self> {
+        let log = log.strip_prefix("Program log: FankorError occurred. Error Code: ")?;
+        let position = log.find('.')?;
+
+        let (code, log) = log.split_at(position);
+        let code = u32::from_str(code).ok()?;
+
+        let log = log.strip_prefix(". Error Name Hash: ")?;
+        let name_hash = u32::from_str(log.strip_suffix('.')?).ok()?;
+
+        Some(Self {
+            code,
+            name: Cow::Borrowed(""),
+            name_hash,
+            message: Cow::Borrowed(""),
+        })
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Fills the name and message of an error logged with the `minimal-logs`
+    /// feature from the `ERROR_TABLE` of its error type. The message is the
+    /// template of the original one because the logs do not include the
+    /// values of the error.
+    pub fn resolve(mut self, table: &[(u32, &'a str, &'a str)]) -> Self {
+        if !self.name.is_empty() {
+            return self;
+        }
+
+        let entry = table
+            .iter()
+            .find(|(code, name, _)| *code == self.code && error_name_hash(name) == self.name_hash);
+
+        if let Some((_, name, message)) = entry {
+            self.name = Cow::Borrowed(name);
+            self.message = Cow::Borrowed(message);
+        }
+
+        self
+    }
 }
 
 impl<'a> Display for RpcFankorError<'a> {
@@ -76,6 +128,10 @@ impl<'a> Display for RpcFankorError<'a> {
 
 #[cfg(test)]
 mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::errors::{Error, FankorErrorCode};
+
     use super::*;
 
     #[test]
@@ -106,4 +162,41 @@ mod test {
         assert_eq!(error.name, name, "Invalid name");
         assert_eq!(error.message, message, "Invalid message");
     }
+
+    #[test]
+    fn test_minimal_logs() {
+        let error: Error = FankorErrorCode::InvalidPda {
+            expected: Pubkey::default(),
+            actual: Pubkey::default(),
+        }
+        .into();
+        let error = match error {
+            Error::FankorError(v) => v,
+            Error::ProgramError(_) => unreachable!(),
+        };
+        let logs = [
+            "Program log: FankorError occurred. Error Code: 1006.".to_string(),
+            format!(
+                "Program log: FankorError occurred. Error Code: {}. Error Name Hash: {}.",
+                error.error_code_number, error.error_name_hash
+            ),
+        ];
+
+        let rpc_error = RpcFankorError::from_logs(&logs).expect("Cannot parse error");
+
+        assert_eq!(rpc_error.code, 1006, "Invalid code");
+        assert_eq!(rpc_error.name, "", "Invalid name");
+        assert_eq!(rpc_error.name_hash, error_name_hash("InvalidPda"));
+
+        let rpc_error = rpc_error.resolve(FankorErrorCode::ERROR_TABLE);
+
+        assert_eq!(
+            rpc_error,
+            RpcFankorError::new(
+                1006,
+                Cow::Borrowed("InvalidPda"),
+                Cow::Borrowed("The provided PDA ({}) does not match expected one ({})")
+            )
+        );
+    }
 }
@@ -55,10 +55,12 @@ pub enum TestInstructionAction {
 impl<'info> TestInstruction<'info> {
     // METHODS ----------------------------------------------------------------
 
-    pub fn processor(self, context: FankorContext<'info>) -> FankorResult<()> {
-        match self.args.into_inner() {
+    pub fn processor(&mut self, context: FankorContext<'info>) -> FankorResult<()> {
+        match self.args.as_ref() {
             TestInstructionAction::Init { bytes } => {
-                msg!("Testable Instruction: Init");
+                if !crate::MINIMAL_LOGS {
+                    msg!("Testable Instruction: Init");
+                }
 
                 // Init account.
                 let rent = Rent::get()?;
@@ -86,7 +88,9 @@ impl<'info> TestInstruction<'info> {
                 data_bytes.copy_from_slice(&bytes);
             }
             TestInstructionAction::InitPDA { seeds, bytes } => {
-                msg!("Testable Instruction: InitPDA");
+                if !crate::MINIMAL_LOGS {
+                    msg!("Testable Instruction: InitPDA");
+                }
 
                 // Init account.
                 let rent = Rent::get()?;
@@ -115,7 +119,9 @@ impl<'info> TestInstruction<'info> {
                 data_bytes.copy_from_slice(&bytes);
             }
             TestInstructionAction::Replace { bytes } => {
-                msg!("Testable Instruction: Replace");
+                if !crate::MINIMAL_LOGS {
+                    msg!("Testable Instruction: Replace");
+                }
 
                 // Realloc account.
                 self.account.realloc_unchecked(
@@ -135,7 +141,9 @@ impl<'info> TestInstruction<'info> {
                 data_bytes.copy_from_slice(&bytes);
             }
             TestInstructionAction::Append { bytes } => {
-                msg!("Testable Instruction: Append");
+                if !crate::MINIMAL_LOGS {
+                    msg!("Testable Instruction: Append");
+                }
 
                 // Realloc account.
                 let length = self.account.info().data_len();
@@ -156,7 +164,8 @@ impl<'info> TestInstruction<'info> {
                 data_bytes.copy_from_slice(&bytes);
             }
             TestInstructionAction::Close => {
-                self.account.close(self.payer.info())?;
+                UncheckedAccount::new(self.account.context(), self.account.info())
+                    .close(self.payer.info())?;
             }
         }
 
@@ -168,6 +177,15 @@ impl<'info> TestInstruction<'info> {
 impl<'info> Instruction<'info> for TestInstruction<'info> {
     type CPI = CpiTestInstruction;
     type LPI = LpiTestInstruction<'info>;
+
+    fn exit(&mut self, context: &'info FankorContext<'info>) -> FankorResult<()> {
+        Instruction::exit(&mut self.args, context)?;
+        Instruction::exit(&mut self.account, context)?;
+        Instruction::exit(&mut self.payer, context)?;
+        Instruction::exit(&mut self.system_program, context)?;
+        Ok(())
+    }
+
     fn try_from(
         context: &'info FankorContext<'info>,
         buf: &mut &[u8],
@@ -9,6 +9,7 @@ use solana_sdk::{
     signature::{Keypair, Signer},
     system_transaction,
     sysvar::rent::Rent,
+    sysvar::slot_hashes::SlotHashes,
     transaction::Transaction,
 };
 use spl_associated_token_account::get_associated_token_address;
@@ -72,6 +73,28 @@ pub trait ClientExtensions {
     ) -> Result<Pubkey, Box<dyn std::error::Error>> {
         unimplemented!();
     }
+
+    /// Create a new Address Lookup Table derived from a recent slot.
+    #[cfg(feature = "address-lookup-table-program")]
+    async fn create_lookup_table(
+        &mut self,
+        _authority: &Pubkey,
+        _payer: &Keypair,
+    ) -> Result<Pubkey, Box<dyn std::error::Error>> {
+        unimplemented!();
+    }
+
+    /// Extend an Address Lookup Table with new addresses.
+    #[cfg(feature = "address-lookup-table-program")]
+    async fn extend_lookup_table(
+        &mut self,
+        _lookup_table: &Pubkey,
+        _authority: &Keypair,
+        _new_addresses: Vec<Pubkey>,
+        _payer: &Keypair,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        unimplemented!();
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -214,4 +237,72 @@ impl ClientExtensions for BanksClient {
 
         return Ok(associated_token_account);
     }
+
+    #[cfg(feature = "address-lookup-table-program")]
+    async fn create_lookup_table(
+        &mut self,
+        authority: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<Pubkey, Box<dyn std::error::Error>> {
+        let latest_blockhash = self.get_latest_blockhash().await?;
+
+        // The derivation slot must be in the SlotHashes sysvar, and two tables of
+        // the same authority cannot share it, so the most recent slot whose table
+        // does not exist yet is used.
+        let slot_hashes = self.get_sysvar::<SlotHashes>().await?;
+        let mut instruction = None;
+
+        for (recent_slot, _) in slot_hashes.iter() {
+            let (ix, lookup_table) =
+                solana_address_lookup_table_program::instruction::create_lookup_table(
+                    *authority,
+                    payer.pubkey(),
+                    *recent_slot,
+                );
+
+            if self.get_account(lookup_table).await?.is_none() {
+                instruction = Some((ix, lookup_table));
+                break;
+            }
+        }
+
+        let (ix, lookup_table) =
+            instruction.ok_or("There is no recent slot available to create the lookup table")?;
+
+        self.process_transaction(Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[payer],
+            latest_blockhash,
+        ))
+        .await?;
+
+        Ok(lookup_table)
+    }
+
+    #[cfg(feature = "address-lookup-table-program")]
+    async fn extend_lookup_table(
+        &mut self,
+        lookup_table: &Pubkey,
+        authority: &Keypair,
+        new_addresses: Vec<Pubkey>,
+        payer: &Keypair,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let latest_blockhash = self.get_latest_blockhash().await?;
+        let ix = solana_address_lookup_table_program::instruction::extend_lookup_table(
+            *lookup_table,
+            authority.pubkey(),
+            Some(payer.pubkey()),
+            new_addresses,
+        );
+
+        self.process_transaction(Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[payer, authority],
+            latest_blockhash,
+        ))
+        .await
+        .map_err(Into::into)
+    }
 }
@@ -0,0 +1,27 @@
+/// Trait implemented by the flag sets generated with `#[derive(FankorFlags)]`.
+///
+/// The flags are stored as a little-endian integer of `BYTE_SIZE` bytes where
+/// every flag is a single bit.
+pub trait FankorFlags: Copy {
+    /// The fieldless enum that defines the flags.
+    type Flag;
+
+    /// The size in bytes of the backing integer.
+    const BYTE_SIZE: usize;
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Gets the bits of the set.
+    fn bits_u64(&self) -> u64;
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Gets the bit index of `flag`.
+    fn flag_bit(flag: &Self::Flag) -> u8;
+
+    /// Creates a new set from its bits without checking for unknown ones.
+    fn from_bits_unchecked(bits: u64) -> Self;
+
+    /// Gets the mask with all valid bits set.
+    fn all_bits() -> u64;
+}
@@ -5,14 +5,18 @@ use solana_program::clock::Epoch;
 use solana_program::instruction::AccountMeta;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::FankorResult;
-use crate::models::FankorContext;
+use crate::errors::{Error, FankorResult};
+use crate::models::{CompactLayoutReader, CompactLayoutWriter, FankorContext};
 
 /// Trait for instruction definitions.
 pub trait Instruction<'info>: Sized {
     type CPI: CpiInstruction<'info>;
     type LPI: LpiInstruction;
 
+    /// Whether the instruction can be executed while the program is paused.
+    /// See `#[program(pausable = <account>)]`.
+    const ALLOW_WHEN_PAUSED: bool = false;
+
     /// Verifies the account info with specific data.
     #[allow(unused_variables)]
     fn verify_account_infos<'a>(
@@ -27,6 +31,30 @@ pub trait Instruction<'info>: Sized {
         data: &mut &[u8],
         accounts: &mut &'info [AccountInfo<'info>],
     ) -> FankorResult<Self>;
+
+    /// Executes the exit actions of the accounts, e.g. saving their data or
+    /// closing them, returning their errors. It is called by the program after
+    /// the processor of the instruction succeeds.
+    ///
+    /// The accounts dropped without being exited execute their exit actions
+    /// in their `Drop` as a fallback, see [`FankorContext::exit`].
+    #[allow(unused_variables)]
+    fn exit(&mut self, context: &'info FankorContext<'info>) -> FankorResult<()> {
+        Ok(())
+    }
+
+    /// Same as [`try_from`](Self::try_from) but reading the structural data,
+    /// i.e. the flags of `Option` and `Either` and the lengths of `Vec`, from
+    /// the header of an instruction declared with `#[instruction(compact_layout)]`.
+    #[allow(unused_variables)]
+    fn try_from_compact(
+        context: &'info FankorContext<'info>,
+        header: &mut CompactLayoutReader,
+        data: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        Self::try_from(context, data, accounts)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -107,6 +135,45 @@ impl<'a, 'info> AccountInfoVerification<'a, 'info> {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// The result of a `#[account(constraint = ...)]` expression. Boolean conditions
+/// fail with the error of the constraint while fallible ones, like
+/// [`not_expired`](crate::models::types::not_expired), can return a dedicated error.
+pub trait AccountConstraint {
+    /// Checks the constraint returning `error` if a boolean condition is false.
+    fn check<F: FnOnce() -> Error>(self, error: F) -> FankorResult<()>;
+
+    /// Checks the constraint returning `error` whenever it fails.
+    fn check_or<F: FnOnce() -> Error>(self, error: F) -> FankorResult<()>;
+}
+
+impl AccountConstraint for bool {
+    fn check<F: FnOnce() -> Error>(self, error: F) -> FankorResult<()> {
+        if self {
+            Ok(())
+        } else {
+            Err(error())
+        }
+    }
+
+    fn check_or<F: FnOnce() -> Error>(self, error: F) -> FankorResult<()> {
+        self.check(error)
+    }
+}
+
+impl AccountConstraint for FankorResult<()> {
+    fn check<F: FnOnce() -> Error>(self, _error: F) -> FankorResult<()> {
+        self
+    }
+
+    fn check_or<F: FnOnce() -> Error>(self, error: F) -> FankorResult<()> {
+        self.map_err(|_| error())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 pub trait CpiInstruction<'info> {
     fn serialize_into_instruction_parts<W: Write>(
         &self,
@@ -114,6 +181,19 @@ pub trait CpiInstruction<'info> {
         metas: &mut Vec<AccountMeta>,
         infos: &mut Vec<AccountInfo<'info>>,
     ) -> FankorResult<()>;
+
+    /// Same as [`serialize_into_instruction_parts`](Self::serialize_into_instruction_parts)
+    /// but writing the structural data into the header of the compact layout.
+    #[allow(unused_variables)]
+    fn serialize_into_instruction_parts_compact<W: Write>(
+        &self,
+        header: &mut CompactLayoutWriter,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+        infos: &mut Vec<AccountInfo<'info>>,
+    ) -> FankorResult<()> {
+        self.serialize_into_instruction_parts(writer, metas, infos)
+    }
 }
 
 impl<'info> CpiInstruction<'info> for AccountInfo<'info> {
@@ -143,6 +223,18 @@ pub trait LpiInstruction {
         writer: &mut W,
         metas: &mut Vec<AccountMeta>,
     ) -> FankorResult<()>;
+
+    /// Same as [`serialize_into_instruction_parts`](Self::serialize_into_instruction_parts)
+    /// but writing the structural data into the header of the compact layout.
+    #[allow(unused_variables)]
+    fn serialize_into_instruction_parts_compact<W: Write>(
+        &self,
+        header: &mut CompactLayoutWriter,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+    ) -> FankorResult<()> {
+        self.serialize_into_instruction_parts(writer, metas)
+    }
 }
 
 impl LpiInstruction for Pubkey {
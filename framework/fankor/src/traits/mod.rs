@@ -1,5 +1,7 @@
 pub use account::*;
+pub use flags::*;
 pub use instruction::*;
+pub use pausable::*;
 pub use pda_checker::*;
 pub use pda_generator::*;
 pub use program::*;
@@ -7,7 +9,9 @@ pub use ts_gen::*;
 pub use zero_copy::*;
 
 mod account;
+mod flags;
 mod instruction;
+mod pausable;
 mod pda_checker;
 mod pda_generator;
 mod program;
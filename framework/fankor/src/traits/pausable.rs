@@ -0,0 +1,52 @@
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::traits::AccountType;
+
+/// The seed of the config PDA of the pausable programs.
+pub const PAUSE_CONFIG_SEED: &[u8] = b"pause_config";
+
+/// Trait for the config accounts of the programs declared with
+/// `#[program(pausable = <account>)]`.
+///
+/// The config account must be the canonical PDA of the program with
+/// [`PAUSE_CONFIG_SEED`] as its only seed and it is initialized by the program
+/// itself, storing its bump seed so the dispatcher does not need to find it.
+/// See `UninitializedAccount::init_pause_config`.
+pub trait PausableConfig: AccountType {
+    /// Creates an unpaused config.
+    fn new(admin: Pubkey, bump: u8) -> Self;
+
+    /// The canonical bump seed of the config account.
+    fn bump(&self) -> u8;
+
+    /// Whether the program is paused.
+    fn is_paused(&self) -> bool;
+
+    /// Pauses or unpauses the program.
+    fn set_paused(&mut self, paused: bool);
+
+    /// The account allowed to pause and unpause the program.
+    fn admin(&self) -> &Pubkey;
+
+    /// Replaces the admin of the program.
+    fn set_admin(&mut self, admin: Pubkey);
+
+    /// Checks `signer` is the admin of the program.
+    fn check_admin(&self, signer: &Pubkey) -> FankorResult<()> {
+        if self.admin() != signer {
+            return Err(FankorErrorCode::PauseAdminMismatch {
+                expected: *self.admin(),
+                actual: *signer,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// The address of the config account of `program_id`.
+    fn pda_address(program_id: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[PAUSE_CONFIG_SEED], program_id).0
+    }
+}
@@ -1,11 +1,10 @@
-use solana_program::account_info::AccountInfo;
-
 use crate::errors::FankorResult;
+use crate::models::ZcSource;
 
 pub trait ZeroCopyType<'info>: Sized {
     // CONSTRUCTORS -----------------------------------------------------------
 
-    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)>;
+    fn new(info: ZcSource<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)>;
 
     // STATIC METHODS ---------------------------------------------------------
 
@@ -3,9 +3,11 @@ use std::borrow::Cow;
 use solana_program::pubkey::Pubkey;
 use solana_program::sysvar::SysvarId;
 
+#[cfg(feature = "address-lookup-table-program")]
+use crate::models::AddressLookupTable;
 use crate::models::{
     Account, Argument, Either, MaybeUninitialized, Program, Rest, RestArguments, SingleEither,
-    SysvarAccount, UncheckedAccount, UninitializedAccount, ZcAccount,
+    SysvarAccount, UncheckedAccount, UninitializedAccount, ZcAccount, ZcArgument,
 };
 use crate::prelude::ProgramType;
 use crate::traits::{AccountType, CopyType, TsInstructionGen, TsTypeGen, TsTypesCache};
@@ -16,6 +18,13 @@ impl<'info, T: AccountType> TsInstructionGen for Account<'info, T> {
     }
 }
 
+#[cfg(feature = "address-lookup-table-program")]
+impl<'info> TsInstructionGen for AddressLookupTable<'info> {
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("solana.PublicKey")
+    }
+}
+
 impl<T: TsTypeGen> TsInstructionGen for Argument<T> {
     fn value_type() -> Cow<'static, str> {
         T::value_type()
@@ -30,11 +39,11 @@ impl<T: TsTypeGen> TsInstructionGen for Argument<T> {
         _signer: bool,
         _writable: bool,
     ) -> Cow<'static, str> {
-        Cow::Owned(format!(
-            "{}.serialize(writer, {});",
-            T::schema_name(),
-            value
-        ))
+        // Parameterized schemas, e.g. bounded types, are only valid as expressions
+        // when they are generated.
+        let schema = T::generate_schema(&mut TsTypesCache::new());
+
+        Cow::Owned(format!("{}.serialize(writer, {});", schema, value))
     }
 }
 
@@ -81,10 +90,10 @@ impl<L: TsInstructionGen, R: TsInstructionGen> TsInstructionGen for Either<L, R>
     ) -> Cow<'static, str> {
         Cow::Owned(format!(
             "if ({}.type === 'Left') {{
-                writer.writeByte(0);
+                fnk.writeInstructionFlag(writer, false);
                 {}
             }} else {{
-                writer.writeByte(1);
+                fnk.writeInstructionFlag(writer, true);
                 {}
             }}",
             value,
@@ -131,10 +140,10 @@ impl<T: TsInstructionGen> TsInstructionGen for Option<T> {
     ) -> Cow<'static, str> {
         Cow::Owned(format!(
             "if ({}) {{
-                writer.writeByte(1);
+                fnk.writeInstructionFlag(writer, true);
                 {}
             }} else {{
-                writer.writeByte(0);
+                fnk.writeInstructionFlag(writer, false);
             }}",
             value.clone(),
             T::get_external_account_metas(value, signer, writable),
@@ -275,7 +284,7 @@ impl<T: TsInstructionGen> TsInstructionGen for Vec<T> {
         writable: bool,
     ) -> Cow<'static, str> {
         Cow::Owned(format!(
-            "writer.writeByte({}.length); {}.forEach(v => {{ {} }});",
+            "fnk.writeInstructionLength(writer, {}.length); {}.forEach(v => {{ {} }});",
             value,
             value,
             T::get_external_account_metas(Cow::Borrowed("v"), signer, writable)
@@ -288,3 +297,21 @@ impl<'info, T: AccountType + CopyType<'info>> TsInstructionGen for ZcAccount<'in
         Cow::Borrowed("solana.PublicKey")
     }
 }
+
+impl<'info, T: CopyType<'info> + TsTypeGen> TsInstructionGen for ZcArgument<'info, T> {
+    fn value_type() -> Cow<'static, str> {
+        Argument::<T>::value_type()
+    }
+
+    fn generate_type(registered_types: &mut TsTypesCache) -> Cow<'static, str> {
+        Argument::<T>::generate_type(registered_types)
+    }
+
+    fn get_account_metas(
+        value: Cow<'static, str>,
+        signer: bool,
+        writable: bool,
+    ) -> Cow<'static, str> {
+        Argument::<T>::get_account_metas(value, signer, writable)
+    }
+}
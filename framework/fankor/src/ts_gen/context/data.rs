@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 
 use convert_case::{Case, Converter};
 
-use crate::traits::{TsInstructionGen, TsTypeGen, TsTypesCache};
+use crate::traits::{TsInstructionGen, TsTypeGen, TsTypesCache, PAUSE_CONFIG_SEED};
 
 /// Contains the info for building the IDL.
 pub struct DataContext {
@@ -16,6 +16,7 @@ pub struct DataContext {
     pub account_schemas_constants: TsTypesCache,
     pub get_meta_methods: TsTypesCache,
     pub program_methods: TsTypesCache,
+    pub heap_size: Option<usize>,
 
     // Type-value pairs.
     pub constants: HashMap<&'static str, (Cow<'static, str>, Cow<'static, str>)>,
@@ -36,6 +37,7 @@ impl DataContext {
             account_schemas_constants: TsTypesCache::new(),
             get_meta_methods: HashMap::new(),
             program_methods: HashMap::new(),
+            heap_size: None,
             constants: HashMap::new(),
         }
     }
@@ -64,6 +66,25 @@ impl DataContext {
         Ok(())
     }
 
+    /// Adds the table of an error enum to reconstruct the errors off-chain.
+    pub fn add_error_table(
+        &mut self,
+        name: &'static str,
+        table: &[(u32, &str, &str)],
+    ) -> Result<(), String> {
+        let entries = table
+            .iter()
+            .map(|(code, name, message)| format!("[{}, [{:?}, {:?}]]", code, name, message))
+            .collect::<Vec<_>>();
+        let table = format!(
+            "export const {}: fnk.FnkErrorTable = new Map([{}]);",
+            name,
+            entries.join(",")
+        );
+
+        self.add_created_type(name, Cow::Owned(table))
+    }
+
     /// Adds an account.
     pub fn add_account<T: TsTypeGen>(&mut self) -> Result<(), String> {
         let name = T::value_type();
@@ -126,6 +147,78 @@ impl DataContext {
         &mut self,
         discriminant_name: &'static str,
         variant_name: &'static str,
+    ) -> Result<(), String> {
+        self.add_program_method_with_extra_metas::<T>(discriminant_name, variant_name, "")
+    }
+
+    /// Adds a program method of a pausable program. If `check_pause` is set,
+    /// the pause config account is appended to the account metas.
+    pub fn add_pausable_program_method<T: TsInstructionGen>(
+        &mut self,
+        discriminant_name: &'static str,
+        variant_name: &'static str,
+        check_pause: bool,
+    ) -> Result<(), String> {
+        let helper_name = Cow::Borrowed("getPauseConfigAddress");
+        if !self.account_types.contains_key(&helper_name) {
+            let helper = format!(
+                "export function getPauseConfigAddress(): solana.PublicKey {{
+                    return solana.PublicKey.findProgramAddressSync([Buffer.from('{}')], ID)[0];
+                }}",
+                std::str::from_utf8(PAUSE_CONFIG_SEED).unwrap()
+            );
+
+            self.account_types.insert(helper_name, Cow::Owned(helper));
+        }
+
+        let extra_metas = if check_pause {
+            "accountMetas.push({ pubkey: getPauseConfigAddress(), isSigner: false, isWritable: false });"
+        } else {
+            ""
+        };
+
+        self.add_program_method_with_extra_metas::<T>(discriminant_name, variant_name, extra_metas)
+    }
+
+    /// Adds the method that packs multiple instructions of the program into
+    /// its `Batch` instruction.
+    pub fn add_batch_program_method(
+        &mut self,
+        discriminant_name: &'static str,
+    ) -> Result<(), String> {
+        let name = Cow::Borrowed("batch");
+
+        if self.program_methods.contains_key(&name) {
+            return Err(format!("Duplicated program method: '{}'", name));
+        }
+
+        let method = format!(
+            "batch(instructions: solana.TransactionInstruction[]) {{
+                const writer = new fnk.FnkBorshWriter();
+                writer.writeByte({}.Batch);
+                const accountMetas: solana.AccountMeta[] = [];
+
+                fnk.writeBatchInstructions(writer, accountMetas, instructions, ID);
+
+                return new solana.TransactionInstruction({{
+                    keys: accountMetas,
+                    programId: ID,
+                    data: writer.toBuffer()
+                }});
+            }}",
+            discriminant_name,
+        );
+
+        self.program_methods.insert(name, Cow::Owned(method));
+
+        Ok(())
+    }
+
+    fn add_program_method_with_extra_metas<T: TsInstructionGen>(
+        &mut self,
+        discriminant_name: &'static str,
+        variant_name: &'static str,
+        extra_metas: &str,
     ) -> Result<(), String> {
         let case_converter = Converter::new()
             .from_case(Case::Pascal)
@@ -144,6 +237,7 @@ impl DataContext {
                 const accountMetas: solana.AccountMeta[] = [];
 
                 getMetasOf{}(accounts, accountMetas, writer);
+                {}
 
                 return new solana.TransactionInstruction({{
                     keys: accountMetas,
@@ -151,7 +245,7 @@ impl DataContext {
                     data: writer.toBuffer()
                 }});
             }}",
-            name, accounts_type, discriminant_name, variant_name, accounts_type,
+            name, accounts_type, discriminant_name, variant_name, accounts_type, extra_metas,
         );
 
         self.program_methods.insert(name, Cow::Owned(method));
@@ -159,6 +253,17 @@ impl DataContext {
         Ok(())
     }
 
+    /// Sets the heap size requested by the program.
+    pub fn set_heap_size(&mut self, heap_size: usize) -> Result<(), String> {
+        if self.heap_size.is_some() {
+            return Err("Duplicated heap size".to_string());
+        }
+
+        self.heap_size = Some(heap_size);
+
+        Ok(())
+    }
+
     /// Adds an account type extension.
     pub fn add_account_type_extensions(
         &mut self,
@@ -192,6 +297,36 @@ impl DataContext {
             buffer.push_str(format!("export const {}: {} = {};\n", name, ty, value).as_str());
         }
 
+        // Build the transaction helpers. If the program requests a heap
+        // frame, it is prepended once to the instructions of the transaction.
+        match self.heap_size {
+            Some(heap_size) => buffer.push_str(
+                format!(
+                    "export const HEAP_SIZE: number = {};
+                    function isHeapFrameRequest(instruction: solana.TransactionInstruction): boolean {{
+                        return instruction.programId.equals(solana.ComputeBudgetProgram.programId)
+                            && solana.ComputeBudgetInstruction.decodeInstructionType(instruction) === 'RequestHeapFrame';
+                    }}
+                    export function withHeapFrame(instructions: solana.TransactionInstruction[]): solana.TransactionInstruction[] {{
+                        return [
+                            solana.ComputeBudgetProgram.requestHeapFrame({{ bytes: HEAP_SIZE }}),
+                            ...instructions.filter((v) => !isHeapFrameRequest(v)),
+                        ];
+                    }}
+                    export function buildTransaction(instructions: solana.TransactionInstruction[]): solana.Transaction {{
+                        return new solana.Transaction().add(...withHeapFrame(instructions));
+                    }}",
+                    heap_size
+                )
+                .as_str(),
+            ),
+            None => buffer.push_str(
+                "export function buildTransaction(instructions: solana.TransactionInstruction[]): solana.Transaction {
+                    return new solana.Transaction().add(...instructions);
+                }",
+            ),
+        }
+
         // Build types.
         let mut account_types = self.account_types.iter().collect::<Vec<_>>();
         account_types.sort_by(|a, b| a.0.cmp(b.0));
@@ -2,8 +2,9 @@ use std::any::{Any, TypeId};
 use std::borrow::Cow;
 
 use crate::prelude::{
-    FnkArray, FnkBMap, FnkExtension, FnkInt, FnkMap, FnkRange, FnkSet, FnkString, FnkUInt,
-    FnkURange, FnkVec,
+    FnkArray, FnkBMap, FnkBitSet, FnkBoundedString, FnkBoundedVec, FnkDecimal, FnkDuration,
+    FnkExtension, FnkFixedString, FnkInt, FnkInt128, FnkMap, FnkRange, FnkRange128, FnkSet,
+    FnkString, FnkTimestamp, FnkUInt, FnkUInt128, FnkURange, FnkURange128, FnkVec,
 };
 use crate::traits::{TsTypeGen, TsTypesCache};
 
@@ -35,6 +36,84 @@ impl TsTypeGen for FnkUInt {
     }
 }
 
+impl TsTypeGen for FnkInt128 {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("new BN(\"{}\")", self))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("BN")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkInt128")
+    }
+}
+
+impl TsTypeGen for FnkUInt128 {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("new BN(\"{}\")", self))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("BN")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkUInt128")
+    }
+}
+
+impl<const SCALE: u8> TsTypeGen for FnkDecimal<SCALE> {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "new fnk.FnkDecimal(new BN(\"{}\"), {})",
+            self.mantissa(),
+            SCALE
+        ))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkDecimal")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkDecimalSchema")
+    }
+
+    fn generate_schema(_registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Owned(format!("fnk.TFnkDecimal({})", SCALE))
+    }
+}
+
+impl TsTypeGen for FnkTimestamp {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("new Date({} * 1000)", self.0))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("Date")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkTimestamp")
+    }
+}
+
+impl TsTypeGen for FnkDuration {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("{} * 1000", self.0))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("number")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkDuration")
+    }
+}
+
 impl TsTypeGen for FnkRange {
     fn value(&self) -> Cow<'static, str> {
         Cow::Owned(format!(
@@ -71,6 +150,67 @@ impl TsTypeGen for FnkURange {
     }
 }
 
+impl TsTypeGen for FnkRange128 {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "new fnk.FnkRange128(new BN(\"{}\"), new BN(\"{}\"))",
+            self.from(),
+            self.to()
+        ))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkRange128")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.TFnkRange128")
+    }
+}
+
+impl TsTypeGen for FnkURange128 {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "new fnk.FnkURange128(new BN(\"{}\"), new BN(\"{}\"))",
+            self.from(),
+            self.to()
+        ))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkURange128")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.TFnkURange128")
+    }
+}
+
+impl<const N: usize> TsTypeGen for FnkBitSet<N> {
+    fn value(&self) -> Cow<'static, str> {
+        let hex = self
+            .0
+            .iter()
+            .rev()
+            .map(|v| format!("{:02x}", v))
+            .collect::<String>();
+
+        Cow::Owned(format!("new fnk.FnkFlags(new BN(\"{}\", 16))", hex))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkFlags")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkFlagsSchema")
+    }
+
+    fn generate_schema(_registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Owned(format!("fnk.TFnkFlags({})", N))
+    }
+}
+
 impl<'a> TsTypeGen for FnkString<'a> {
     fn value(&self) -> Cow<'static, str> {
         Cow::Owned(format!("{:?}", self))
@@ -85,6 +225,42 @@ impl<'a> TsTypeGen for FnkString<'a> {
     }
 }
 
+impl<'a, const N: usize> TsTypeGen for FnkBoundedString<'a, N> {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("{:?}", self.as_ref()))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("string")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkBoundedStringSchema")
+    }
+
+    fn generate_schema(_registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Owned(format!("fnk.FnkBoundedString({})", N))
+    }
+}
+
+impl<const N: usize> TsTypeGen for FnkFixedString<N> {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("{:?}", self.as_str()))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("string")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkFixedStringSchema")
+    }
+
+    fn generate_schema(_registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Owned(format!("fnk.FnkFixedString({})", N))
+    }
+}
+
 impl<T: TsTypeGen + Any, const S: usize> TsTypeGen for FnkArray<T, S> {
     fn value(&self) -> Cow<'static, str> {
         let values = self.iter().map(|v| v.value()).collect::<Vec<_>>();
@@ -166,6 +342,43 @@ impl<T: TsTypeGen + Any> TsTypeGen for FnkVec<T> {
     }
 }
 
+impl<T: TsTypeGen + Any, const N: usize> TsTypeGen for FnkBoundedVec<T, N> {
+    fn value(&self) -> Cow<'static, str> {
+        let values = self.iter().map(|v| v.value()).collect::<Vec<_>>();
+
+        if TypeId::of::<u8>() == TypeId::of::<T>() {
+            Cow::Owned(format!("new Uint8Array([{}])", values.join(",")))
+        } else {
+            Cow::Owned(format!("[{}]", values.join(",")))
+        }
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        if TypeId::of::<u8>() == TypeId::of::<T>() {
+            Cow::Borrowed("Uint8Array")
+        } else {
+            Cow::Owned(format!("({})[]", T::value_type()))
+        }
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        if TypeId::of::<u8>() == TypeId::of::<T>() {
+            Cow::Borrowed("fnk.FnkBoundedByteVecSchema")
+        } else {
+            Cow::Owned(format!("fnk.FnkBoundedVecSchema<{}>", T::schema_name()))
+        }
+    }
+
+    fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
+        let inner_schema = T::generate_schema(registered_schemas);
+        if TypeId::of::<u8>() == TypeId::of::<T>() {
+            Cow::Owned(format!("fnk.FnkBoundedByteVec({})", N))
+        } else {
+            Cow::Owned(format!("fnk.FnkBoundedVec({}, {})", inner_schema, N))
+        }
+    }
+}
+
 impl<T: TsTypeGen> TsTypeGen for FnkSet<T> {
     fn value(&self) -> Cow<'static, str> {
         let values = self.iter().map(|v| v.value()).collect::<Vec<_>>();
@@ -4,11 +4,25 @@ use solana_program::system_program;
 use crate::errors::{FankorErrorCode, FankorResult};
 use crate::models::FankorContext;
 
+/// The data of the accounts closed with a tombstone. It starts with the zero
+/// discriminant, which is reserved, so it cannot match any account type.
+pub const CLOSED_ACCOUNT_TOMBSTONE: [u8; 8] = [0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+
+/// Whether `data` belongs to an account closed with a tombstone.
+pub fn is_closed_account_tombstone(data: &[u8]) -> bool {
+    data.starts_with(&CLOSED_ACCOUNT_TOMBSTONE)
+}
+
 /// Closes the `account` and sends the lamports to the `destination_account`.
+///
+/// If `tombstone` is set, the account is kept owned by the program and its data
+/// is replaced by [`CLOSED_ACCOUNT_TOMBSTONE`], so it cannot be used again if
+/// it is refunded later in the same transaction.
 pub(crate) fn close_account<'info>(
     info: &AccountInfo<'info>,
     context: &FankorContext<'info>,
     destination_account: &AccountInfo<'info>,
+    tombstone: bool,
 ) -> FankorResult<()> {
     if info.owner != context.program_id() {
         return Err(FankorErrorCode::AccountNotOwnedByProgram {
@@ -34,19 +48,76 @@ pub(crate) fn close_account<'info>(
     **info.lamports.borrow_mut() = 0;
 
     // Close the account.
-    info.assign(&system_program::ID);
+    let size = if tombstone {
+        CLOSED_ACCOUNT_TOMBSTONE.len()
+    } else {
+        info.assign(&system_program::ID);
+        0
+    };
 
     #[cfg(any(feature = "test-utils", test))]
     if info.rent_epoch != crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
-        info.realloc(0, false)?;
+        info.realloc(size, false)?;
     }
 
     #[cfg(not(any(feature = "test-utils", test)))]
     {
-        info.realloc(0, false)?;
+        info.realloc(size, false)?;
+    }
+
+    if tombstone {
+        let mut data = info.try_borrow_mut_data()?;
+        let length = data.len().min(size);
+        data[..length].copy_from_slice(&CLOSED_ACCOUNT_TOMBSTONE[..length]);
+        data[length..].fill(0);
     }
 
     context.remove_exit_action(info);
 
     Ok(())
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_close_with_tombstone() {
+        let mut lamports = 10;
+        let mut vector = vec![5u8; 12];
+        let mut destination_lamports = 2;
+        let mut destination_vector = vec![];
+        let mut accounts = [
+            create_account_info_for_tests(&mut lamports, &mut vector),
+            create_account_info_for_tests(&mut destination_lamports, &mut destination_vector),
+        ];
+        accounts[0].is_writable = true;
+
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+        assert!(!context.is_account_tombstoned(info));
+
+        close_account(info, &context, &accounts[1], true).unwrap();
+
+        assert_eq!(info.lamports(), 0);
+        assert_eq!(accounts[1].lamports(), 12);
+        assert_eq!(info.owner, &program_id);
+        assert_eq!(info.data.borrow()[..8], CLOSED_ACCOUNT_TOMBSTONE);
+        assert_eq!(info.data.borrow()[8..], [0; 4]);
+        assert!(context.is_account_tombstoned(info));
+        assert!(context.is_account_closed(info));
+
+        // Refunding the account does not revive it.
+        **info.lamports.borrow_mut() = 5;
+        assert!(context.is_account_tombstoned(info));
+    }
+}
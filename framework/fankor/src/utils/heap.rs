@@ -0,0 +1,32 @@
+use solana_program::entrypoint::{BumpAllocator, HEAP_START_ADDRESS};
+
+/// The maximum size of the heap that a transaction can request with the
+/// `RequestHeapFrame` instruction of the compute budget program.
+pub const MAX_HEAP_LENGTH: usize = 256 * 1024;
+
+/// Creates a bump allocator like the default one of Solana but over a heap of
+/// `length` bytes. The transactions must request the heap frame with the
+/// `RequestHeapFrame` instruction, otherwise the program fails with an access
+/// violation in its first allocation beyond the default 32KB heap. This also
+/// applies to the transactions of other programs that call it via CPI.
+pub const fn bump_heap_allocator(length: usize) -> BumpAllocator {
+    BumpAllocator {
+        start: HEAP_START_ADDRESS as usize,
+        len: length,
+    }
+}
+
+/// Gets the number of bytes allocated in a heap of `length` bytes managed by
+/// a bump allocator.
+#[cfg(target_os = "solana")]
+pub fn heap_usage(length: usize) -> usize {
+    // The bump allocators keep their position at the start of the heap and
+    // allocate downwards from its end.
+    let position = unsafe { *(HEAP_START_ADDRESS as usize as *const usize) };
+
+    if position == 0 {
+        0
+    } else {
+        HEAP_START_ADDRESS as usize + length - position
+    }
+}
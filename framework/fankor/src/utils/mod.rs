@@ -1,6 +1,7 @@
 use std::any::TypeId;
 
 pub mod close;
+pub mod heap;
 pub mod realloc;
 pub mod rent;
 pub mod seeds;
@@ -76,8 +76,14 @@ mod test {
         }
 
         let vector_save = vector.clone();
-        let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let zc = Zc::<EnumAccountData>::new_unchecked(&info, 0);
+        let mut info = create_account_info_for_tests(&mut lamports, &mut vector);
+        info.is_writable = true;
+
+        let accounts = [info];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+        let zc = Zc::<EnumAccountData>::new_unchecked(info, 0);
         let zc_value = zc.zc_value().unwrap();
 
         match zc_value {
@@ -94,13 +100,61 @@ mod test {
         }
 
         zc.info().try_borrow_mut_data().unwrap().fill(0);
-        zc.try_write_value_unchecked(&EnumAccountData::C {
-            value1: 1,
-            value2_snake: "test".to_string(),
-        })
+        let account = ZcAccount::<EnumAccountData>::new_unchecked(&context, info);
+        let guard = account.write().unwrap();
+        guard
+            .write_value(
+                &zc,
+                &EnumAccountData::C {
+                    value1: 1,
+                    value2_snake: "test".to_string(),
+                },
+            )
             .unwrap();
 
         let data = info.try_borrow_data().unwrap();
         assert_eq!(*data, &vector_save);
     }
+
+    #[test]
+    fn test_zc_set_variant() {
+        let mut lamports = 0;
+        let mut vector = vec![8u8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let mut info = create_account_info_for_tests(&mut lamports, &mut vector);
+        info.is_writable = true;
+
+        let accounts = [info];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+        let account = ZcAccount::<EnumAccountData>::new_unchecked(&context, info);
+        let guard = account.write().unwrap();
+        let zc = Zc::<EnumAccountData>::new_unchecked(info, 0);
+
+        zc.set_variant_b(&guard, 5).unwrap();
+        assert_eq!(info.data.borrow()[..7], [8, 1, 5, 0, 0, 0, 0]);
+
+        zc.set_variant_c(&guard, 1, "ab".to_string()).unwrap();
+        assert_eq!(
+            info.data.borrow()[..],
+            [8, 2, 1, 0, 0, 0, 2, 0, 0, 0, 97, 98, 0]
+        );
+
+        match zc.zc_value().unwrap() {
+            ZcEnumAccountData::C {
+                value1,
+                value2_snake,
+            } => {
+                assert_eq!(value1.try_value().unwrap(), 1);
+                assert_eq!(value2_snake.try_value().unwrap(), "ab");
+            }
+            _ => {
+                panic!("Unexpected variant");
+            }
+        }
+
+        zc.set_variant_a(&guard).unwrap();
+        assert_eq!(info.data.borrow()[..2], [8, 0]);
+        assert!(matches!(zc.zc_value().unwrap(), ZcEnumAccountData::A));
+    }
 }
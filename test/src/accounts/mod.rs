@@ -12,6 +12,7 @@ pub enum ProgramAccount {
     StructAccountData2,
     ZeroCopyStructAccountData,
     EnumAccountData,
+    PauseConfigData,
 }
 
 #[accounts(base = ProgramAccount)]
@@ -77,8 +78,14 @@ mod test {
         }
 
         let vector_save = vector.clone();
-        let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let zc = Zc::<ProgramAccount>::new_unchecked(&info, 0);
+        let mut info = create_account_info_for_tests(&mut lamports, &mut vector);
+        info.is_writable = true;
+
+        let accounts = [info];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+        let zc = Zc::<ProgramAccount>::new_unchecked(info, 0);
         let value = zc.try_value().unwrap();
         match value {
             ProgramAccount::StructAccountData(v) => {
@@ -100,11 +107,12 @@ mod test {
             bytes.fill(0);
             bytes[0] = 5;
         }
-        zc.try_write_value_unchecked(&ProgramAccount::StructAccountData(StructAccountData {
+        let account = ZcAccount::<ProgramAccount>::new_unchecked(&context, info);
+        let guard = account.write().unwrap();
+        guard.write_value(&zc, &ProgramAccount::StructAccountData(StructAccountData {
             value1: 1,
             value2: "test".to_string(),
-        }))
-        .unwrap();
+        })).unwrap();
 
         let data = info.try_borrow_data().unwrap();
         assert_eq!(*data, &vector_save);
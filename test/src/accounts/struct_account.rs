@@ -24,6 +24,43 @@ pub struct ZeroCopyStructAccountData {
     pub value5: FnkExtension,
 }
 
+#[account(base = ProgramAccount)]
+pub struct PauseConfigData {
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub bump: u8,
+}
+
+impl PausableConfig for PauseConfigData {
+    fn new(admin: Pubkey, bump: u8) -> Self {
+        PauseConfigData {
+            admin,
+            paused: false,
+            bump,
+        }
+    }
+
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    fn admin(&self) -> &Pubkey {
+        &self.admin
+    }
+
+    fn set_admin(&mut self, admin: Pubkey) {
+        self.admin = admin;
+    }
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -78,18 +115,30 @@ mod test {
         }
 
         let vector_save = vector.clone();
-        let info = create_account_info_for_tests(&mut lamports, &mut vector);
-        let zc = Zc::<StructAccountData>::new_unchecked(&info, 0);
+        let mut info = create_account_info_for_tests(&mut lamports, &mut vector);
+        info.is_writable = true;
+
+        let accounts = [info];
+        let program_id = Pubkey::default();
+        let context = FankorContext::new_unchecked(&program_id, &accounts);
+        let info = &accounts[0];
+        let zc = Zc::<StructAccountData>::new_unchecked(info, 0);
         let zc_value = zc.zc_value().unwrap();
         assert_eq!(zc_value.value1().unwrap().try_value().unwrap(), 1);
         assert_eq!(zc_value.value2().unwrap().try_value().unwrap(), "test");
 
         zc.info().try_borrow_mut_data().unwrap().fill(0);
-        zc.try_write_value_unchecked(&StructAccountData {
-            value1: 1,
-            value2: "test".to_string(),
-        })
-        .unwrap();
+        let account = ZcAccount::<StructAccountData>::new_unchecked(&context, info);
+        let guard = account.write().unwrap();
+        guard
+            .write_value(
+                &zc,
+                &StructAccountData {
+                    value1: 1,
+                    value2: "test".to_string(),
+                },
+            )
+            .unwrap();
 
         let data = info.try_borrow_data().unwrap();
         assert_eq!(*data, &vector_save);
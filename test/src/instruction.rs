@@ -5,6 +5,7 @@ use fankor::prelude::*;
 use crate::accounts::*;
 use crate::arguments::*;
 use crate::errors::Errors;
+use crate::program::{RelayProgram, TestProgram, TestProgramDiscriminant};
 
 #[instruction(initial_validation, final_validation)]
 #[allow(dead_code)]
@@ -32,6 +33,7 @@ pub struct StructAccounts<'info> {
     #[account(pda_bytes = vec![1, 2, 3])]
     pub account3: Option<Account<'info, StructAccountData>>,
 
+    /// CHECK: only used to test the unchecked accounts.
     pub unchecked_account: UncheckedAccount<'info>,
 
     pub zero_copy_account: ZcAccount<'info, ZeroCopyStructAccountData>,
@@ -60,6 +62,7 @@ pub struct StructAccounts<'info> {
 
     // Must be placed in the last position.
     #[account(writable)]
+    #[account(unchecked = "only used to test the rest accounts")]
     pub rest: Rest<'info>,
 }
 
@@ -67,7 +70,7 @@ pub struct StructAccounts<'info> {
 impl<'info> StructAccounts<'info> {
     // METHODS ----------------------------------------------------------------
 
-    pub fn processor(self, _context: FankorContext<'info>) -> FankorResult<()> {
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
         Ok(())
     }
 
@@ -114,7 +117,7 @@ pub struct StructAccountsWithoutAssociatedType<'info> {
 impl<'info> StructAccountsWithoutAssociatedType<'info> {
     // METHODS ----------------------------------------------------------------
 
-    pub fn processor(self, _context: FankorContext<'info>) -> FankorResult<()> {
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
         Ok(())
     }
 
@@ -144,7 +147,7 @@ pub enum EnumAccounts<'info> {
 impl<'info> EnumAccounts<'info> {
     // METHODS ----------------------------------------------------------------
 
-    pub fn processor(self, _context: FankorContext<'info>) -> FankorResult<()> {
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
         Ok(())
     }
 }
@@ -169,7 +172,7 @@ pub enum EnumAccountsWithoutArgs<'info> {
 impl<'info> EnumAccountsWithoutArgs<'info> {
     // METHODS ----------------------------------------------------------------
 
-    pub fn processor(self, _context: FankorContext<'info>) -> FankorResult<()> {
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
         Ok(())
     }
 }
@@ -184,3 +187,309 @@ pub struct AuxiliarInstruction<'info> {
     pub args: Argument<InstructionArgs>,
     pub account: Account<'info, StructAccountData>,
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[instruction(callable_by = [RelayProgram])]
+#[allow(dead_code)]
+pub struct CallerRestrictedInstruction<'info> {
+    pub instructions_sysvar: SysvarAccount<'info, Instructions>,
+}
+
+#[allow(dead_code)]
+impl<'info> CallerRestrictedInstruction<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
+        Ok(())
+    }
+}
+
+#[instruction(no_reentrancy)]
+#[allow(dead_code)]
+pub struct NoReentrancyInstruction<'info> {
+    pub instructions_sysvar: SysvarAccount<'info, Instructions>,
+}
+
+#[allow(dead_code)]
+impl<'info> NoReentrancyInstruction<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
+        Ok(())
+    }
+}
+
+/// Calls `NoReentrancyInstruction` directly to re-enter the program.
+#[instruction]
+#[allow(dead_code)]
+pub struct ReentrantInstruction<'info> {
+    pub test_program: Program<'info, TestProgram>,
+    pub instructions_sysvar: SysvarAccount<'info, Instructions>,
+}
+
+#[allow(dead_code)]
+impl<'info> ReentrantInstruction<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
+        let instruction = solana_program::instruction::Instruction {
+            program_id: *self.test_program.address(),
+            accounts: vec![AccountMeta::new_readonly(
+                *self.instructions_sysvar.address(),
+                false,
+            )],
+            data: vec![TestProgramDiscriminant::NoReentrancyInstruction.code()],
+        };
+
+        self.test_program.invoke_signed(
+            &instruction,
+            &[
+                self.test_program.info().clone(),
+                self.instructions_sysvar.info().clone(),
+            ],
+            &[],
+        )
+    }
+}
+
+#[instruction(top_level_only)]
+#[allow(dead_code)]
+pub struct TopLevelInstruction<'info> {
+    /// CHECK: only used to test the caller restrictions.
+    pub account: UncheckedAccount<'info>,
+}
+
+#[allow(dead_code)]
+impl<'info> TopLevelInstruction<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
+        Ok(())
+    }
+}
+
+#[instruction(compact_layout)]
+#[allow(dead_code)]
+pub struct CompactLayoutInstruction<'info> {
+    pub account: Option<Account<'info, StructAccountData>>,
+    pub accounts: Vec<Account<'info, StructAccountData>>,
+    /// CHECK: only used to test the compact layout.
+    pub either: Either<Account<'info, StructAccountData>, UncheckedAccount<'info>>,
+}
+
+#[instruction]
+#[allow(dead_code)]
+pub struct ZcArgumentInstruction<'info> {
+    pub amounts: ZcArgument<'info, Vec<u64>>,
+    pub account: Account<'info, StructAccountData>,
+}
+
+#[instruction]
+#[allow(dead_code)]
+pub struct DuplicatedAccountsInstruction<'info> {
+    /// CHECK: only used to test the errors of the exit phase.
+    #[account(writable)]
+    pub account1: Account<'info, StructAccountData>,
+
+    /// CHECK: only used to test the errors of the exit phase.
+    #[account(writable)]
+    pub account2: Account<'info, StructAccountData>,
+}
+
+#[allow(dead_code)]
+impl<'info> DuplicatedAccountsInstruction<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
+        Ok(())
+    }
+}
+
+#[instruction(allow_when_paused)]
+#[allow(dead_code)]
+pub struct AllowWhenPausedInstruction<'info> {
+    /// CHECK: only used to test the pausable programs.
+    pub account: UncheckedAccount<'info>,
+}
+
+#[allow(dead_code)]
+impl<'info> AllowWhenPausedInstruction<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
+        Ok(())
+    }
+}
+
+#[instruction(allow_when_paused)]
+#[allow(dead_code)]
+pub struct InitPauseConfig<'info> {
+    pub admin: Argument<Pubkey>,
+
+    /// CHECK: only used to pay the pause config.
+    #[account(signer)]
+    #[account(writable)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(writable)]
+    pub config: UninitializedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(dead_code)]
+impl<'info> InitPauseConfig<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Anyone can initialize the pause config of the test program. Real programs
+    /// must restrict it, e.g. to their upgrade authority.
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
+        let config = UninitializedAccount::new(self.config.context(), self.config.info())?;
+        config.init_pause_config::<PauseConfigData>(
+            *self.admin,
+            self.payer.info(),
+            &self.system_program,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[instruction]
+#[allow(dead_code)]
+pub struct SplitStakeInstruction<'info> {
+    pub lamports: Argument<u64>,
+
+    /// CHECK: validated by the stake program.
+    #[account(writable)]
+    pub stake: Account<'info, StakeAccount>,
+
+    /// CHECK: allocated and assigned to the stake program by the split.
+    #[account(signer)]
+    #[account(writable)]
+    pub split_stake: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the stake program.
+    #[account(signer)]
+    pub authorized: UncheckedAccount<'info>,
+
+    pub stake_program: Program<'info, Stake>,
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(dead_code)]
+impl<'info> SplitStakeInstruction<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
+        cpi::stake::split(
+            &self.stake_program,
+            cpi::stake::CpiSplit {
+                stake: self.stake.info().clone(),
+                split_stake: self.split_stake.info().clone(),
+                authorized: self.authorized.info().clone(),
+                system_program: self.system_program.info().clone(),
+            },
+            *self.lamports,
+            &[],
+        )
+    }
+}
+
+#[instruction]
+#[allow(dead_code)]
+pub struct CreateLookupTableInstruction<'info> {
+    pub recent_slot: Argument<u64>,
+    pub addresses: Argument<Vec<Pubkey>>,
+
+    /// CHECK: created by the address lookup table program.
+    #[account(writable)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the address lookup table program.
+    #[account(signer)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: only used to pay the lookup table.
+    #[account(signer)]
+    #[account(writable)]
+    pub payer: UncheckedAccount<'info>,
+
+    pub lookup_table_program: Program<'info, AddressLookupTableProgram>,
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(dead_code)]
+impl<'info> CreateLookupTableInstruction<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Creates and extends the lookup table, reading it back afterwards.
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
+        cpi::address_lookup_table::create_lookup_table(
+            &self.lookup_table_program,
+            cpi::address_lookup_table::CpiCreateLookupTable {
+                lookup_table: self.lookup_table.info().clone(),
+                authority: self.authority.info().clone(),
+                payer: self.payer.info().clone(),
+                system_program: self.system_program.info().clone(),
+            },
+            *self.recent_slot,
+            &[],
+        )?;
+
+        cpi::address_lookup_table::extend_lookup_table(
+            &self.lookup_table_program,
+            cpi::address_lookup_table::CpiExtendLookupTable {
+                lookup_table: self.lookup_table.info().clone(),
+                authority: self.authority.info().clone(),
+                payer: self.payer.info().clone(),
+                system_program: self.system_program.info().clone(),
+            },
+            self.addresses.to_vec(),
+            &[],
+        )?;
+
+        let lookup_table =
+            AddressLookupTable::new(self.lookup_table.context(), self.lookup_table.info())?;
+        require!(
+            lookup_table.authority()? == Some(*self.authority.address()),
+            Errors::A
+        );
+        require!(
+            *lookup_table.addresses()? == *self.addresses.as_slice(),
+            Errors::A
+        );
+
+        Ok(())
+    }
+}
+
+#[instruction]
+#[allow(dead_code)]
+pub struct ReadLookupTableInstruction<'info> {
+    pub addresses: Argument<Vec<Pubkey>>,
+    pub lookup_table: AddressLookupTable<'info>,
+}
+
+#[allow(dead_code)]
+impl<'info> ReadLookupTableInstruction<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(&mut self, _context: FankorContext<'info>) -> FankorResult<()> {
+        require!(self.lookup_table.len() == self.addresses.len(), Errors::A);
+
+        for (index, address) in self.addresses.iter().enumerate() {
+            require!(self.lookup_table.get(index) == Some(*address), Errors::A);
+        }
+
+        require!(
+            *self.lookup_table.addresses()? == *self.addresses.as_slice(),
+            Errors::A
+        );
+
+        Ok(())
+    }
+}
@@ -13,7 +13,11 @@ mod serialization;
 #[cfg(all(test, feature = "test-utils"))]
 mod tests;
 
-setup!("7JKciYMdWKBo1yPhjVe5eDDjoxYfB8YhkAL7DRpJj3xE");
+setup!(
+    "7JKciYMdWKBo1yPhjVe5eDDjoxYfB8YhkAL7DRpJj3xE",
+    heap_size = 65536,
+    log_heap_usage
+);
 
 pub static PUBKEY_FROM_CONSTANT: Pubkey =
     const_pubkey!("7JKciYMdWKBo1yPhjVe5eDDjoxYfB8YhkAL7DRpJj3xE");
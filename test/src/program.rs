@@ -1,8 +1,16 @@
 use fankor::prelude::*;
 
+use crate::accounts::PauseConfigData;
 use crate::instruction::*;
 
-#[program(testable, fallback)]
+#[program(
+    testable,
+    fallback,
+    batch,
+    pausable = PauseConfigData,
+    before = before_hook,
+    after = after_hook
+)]
 enum TestProgram {
     #[discriminant = 3]
     StructAccounts,
@@ -17,6 +25,26 @@ enum TestProgram {
     #[return_type = u8]
     #[boxed]
     EnumAccountsWithoutArgs,
+
+    TopLevelInstruction,
+
+    CallerRestrictedInstruction,
+
+    NoReentrancyInstruction,
+
+    ReentrantInstruction,
+
+    AllowWhenPausedInstruction,
+
+    InitPauseConfig,
+
+    DuplicatedAccountsInstruction,
+
+    SplitStakeInstruction,
+
+    CreateLookupTableInstruction,
+
+    ReadLookupTableInstruction,
 }
 
 #[allow(dead_code)]
@@ -32,3 +60,46 @@ fn fallback<'info>(
 
     Ok(())
 }
+
+#[allow(dead_code)]
+fn before_hook<'info>(
+    _context: &'info FankorContext<'info>,
+    discriminant: u8,
+    _instruction_data: &[u8],
+) -> FankorResult<()> {
+    msg!("dispatching instruction {}", discriminant);
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+fn after_hook<'info>(
+    _context: &'info FankorContext<'info>,
+    discriminant: u8,
+    _instruction_data: &[u8],
+) -> FankorResult<()> {
+    msg!("instruction {} succeeded", discriminant);
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The program that forwards its instruction data to the program of its first
+/// account, used to test the instructions called through CPIs.
+#[derive(Debug, Copy, Clone)]
+pub struct RelayProgram;
+
+impl ProgramType for RelayProgram {
+    fn name() -> &'static str {
+        "RelayProgram"
+    }
+
+    fn address() -> &'static Pubkey {
+        &RELAY_PROGRAM_ID
+    }
+}
+
+pub static RELAY_PROGRAM_ID: Pubkey = const_pubkey!("Re1ay11111111111111111111111111111111111111");
@@ -24,6 +24,22 @@ enum X {
     },
 }
 
+#[derive(Debug, Eq, PartialEq, EnumDiscriminants, FankorSerialize, FankorDeserialize)]
+#[repr(u8)]
+enum Y {
+    A,
+    #[deprecated(note = "use A instead")]
+    B,
+
+    #[discriminant = 5]
+    C,
+    #[deprecated = "use C instead"]
+    D,
+
+    #[discriminant = 10]
+    E,
+}
+
 #[cfg(test)]
 mod test {
     use fankor::prelude::borsh::{BorshDeserialize, BorshSerialize};
@@ -86,4 +102,20 @@ mod test {
         assert_eq!(X::try_from_slice(&f).unwrap(), X::F);
         assert_eq!(X::try_from_slice(&g).unwrap(), X::G { a: 100, b: 200 });
     }
+
+    #[test]
+    fn test_deprecated_with_note_y() {
+        assert_eq!(YDiscriminant::A.code(), 0);
+        assert_eq!(YDiscriminant::B.code(), 1);
+        assert_eq!(YDiscriminant::C.code(), 5);
+        assert_eq!(YDiscriminant::D.code(), 6);
+        assert_eq!(YDiscriminant::E.code(), 10);
+
+        for (value, code) in [(Y::A, 0), (Y::B, 1), (Y::C, 5), (Y::D, 6), (Y::E, 10)] {
+            let buffer = value.try_to_vec().unwrap();
+
+            assert_eq!(buffer, vec![code]);
+            assert_eq!(Y::try_from_slice(&buffer).unwrap(), value);
+        }
+    }
 }
@@ -1,27 +1,36 @@
+use fankor::prelude::solana_program::entrypoint::ProgramResult;
+use fankor::prelude::solana_program::instruction::Instruction;
+use fankor::prelude::solana_program::program::invoke;
 use fankor::prelude::*;
 use fankor::tests::ClientExtensions;
+use solana_program_test::{processor, BanksClient, BanksClientError, ProgramTest};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::stake::instruction as stake_instruction;
+use solana_sdk::stake::state::{Authorized, Lockup, StakeState};
+use solana_sdk::sysvar::slot_hashes::SlotHashes;
+use solana_sdk::transaction::TransactionError;
 
-use crate::instruction::LpiInstructionStructAccountsWithoutAssociatedType;
-use crate::program::TestProgram;
+use crate::accounts::{PauseConfigData, StructAccountData};
+use crate::instruction::{
+    LpiAllowWhenPausedInstruction, LpiCallerRestrictedInstruction, LpiCreateLookupTableInstruction,
+    LpiDuplicatedAccountsInstruction, LpiEnumAccountsWithoutArgs, LpiInitPauseConfig,
+    LpiNoReentrancyInstruction, LpiReadLookupTableInstruction, LpiReentrantInstruction,
+    LpiSplitStakeInstruction, LpiTopLevelInstruction,
+};
+use crate::program::{
+    LpiPause, LpiTransferAdmin, LpiUnpause, RelayProgram, TestProgram, TestProgramDiscriminant,
+};
 
 #[tokio::test]
 async fn test_program_instruction() {
-    let mut program = TestProgram::new_program_test();
+    let mut program = new_program_test();
     let payer = add_payer(&mut program);
 
     let (mut banks_client, _payer_keypair, mut _recent_blockhash) = program.start().await;
-    let instruction = crate::program::lpi::instruction_without_args(
-        LpiInstructionStructAccountsWithoutAssociatedType {
-            account: Pubkey::default(),
-            boxed_zc_account: Pubkey::default(),
-            optional_zc_account: LpiEither::Left(Pubkey::default()),
-            option_zc_account: None,
-            either: LpiEither::Left(Pubkey::default()),
-            maybe_uninitialized: LpiEither::Left(Pubkey::default()),
-            instructions_sysvar: Default::default(),
-        },
-    )
-    .expect("Cannot build instruction");
+    let instruction =
+        crate::program::lpi::enum_accounts_without_args(LpiEnumAccountsWithoutArgs::EmptyVariant)
+            .expect("Cannot build instruction");
 
     let transaction = banks_client
         .create_transaction_from_instructions(&[instruction], &payer, vec![&payer])
@@ -32,19 +41,559 @@ async fn test_program_instruction() {
         .process_transaction(transaction)
         .await
         .expect("Cannot process transaction");
+}
+
+#[tokio::test]
+async fn test_deprecated_instruction() {
+    let mut program = new_program_test();
+    let payer = add_payer(&mut program);
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: vec![],
+        data: vec![TestProgramDiscriminant::StructAccountsWithoutAssociatedType.code()],
+    };
+
+    let result = process_instructions(&mut banks_client, &payer, &[instruction.clone()]).await;
+    assert_fankor_error(
+        result,
+        FankorErrorCode::InstructionDeprecated {
+            name: "StructAccountsWithoutAssociatedType",
+        },
+    );
+
+    // Inside a batch.
+    let instruction = crate::program::lpi::batch(&[instruction]).expect("Cannot build batch");
+
+    let result = process_instructions(&mut banks_client, &payer, &[instruction]).await;
+    assert_fankor_error(
+        result,
+        FankorErrorCode::InstructionDeprecated {
+            name: "StructAccountsWithoutAssociatedType",
+        },
+    );
+}
+
+#[tokio::test]
+async fn test_hooks() {
+    let mut program = new_program_test();
+    let payer = add_payer(&mut program);
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+    let instruction = crate::program::lpi::top_level_instruction(LpiTopLevelInstruction {
+        account: payer.pubkey(),
+    })
+    .expect("Cannot build instruction");
+
+    let discriminant = TestProgramDiscriminant::TopLevelInstruction.code();
+    let before_log = format!("Program log: dispatching instruction {}", discriminant);
+    let after_log = format!("Program log: instruction {} succeeded", discriminant);
+
+    let (logs, _) = simulate_instructions(&mut banks_client, &payer, &[instruction.clone()]).await;
+    assert!(logs.contains(&before_log));
+    assert!(logs.contains(&after_log));
+
+    // The after hook only runs if the instruction succeeds.
+    let (logs, _) =
+        simulate_instructions(&mut banks_client, &payer, &[relay_instruction(instruction)]).await;
+    assert!(logs.contains(&before_log));
+    assert!(!logs.contains(&after_log));
+}
+
+#[tokio::test]
+async fn test_exit_errors() {
+    let mut program = new_program_test();
+    let payer = add_payer(&mut program);
+
+    let account = Pubkey::new_unique();
+    let data = StructAccountData {
+        value1: 0,
+        value2: String::new(),
+    }
+    .try_to_vec()
+    .expect("Cannot serialize the account");
+
+    program.add_account(
+        account,
+        solana_sdk::account::Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: crate::ID,
+            ..Default::default()
+        },
+    );
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+
+    // The error of the exit phase reaches the client instead of a panic.
+    let instruction =
+        crate::program::lpi::duplicated_accounts_instruction(LpiDuplicatedAccountsInstruction {
+            account1: account,
+            account2: account,
+        })
+        .expect("Cannot build instruction");
+
+    let result = process_instructions(&mut banks_client, &payer, &[instruction]).await;
+    assert_fankor_error(
+        result,
+        FankorErrorCode::DuplicatedWritableAccounts { address: account },
+    );
+}
+
+#[tokio::test]
+async fn test_pausable() {
+    let admin = Keypair::new();
+    let mut program = new_program_test_without_pause_config();
+    let payer = add_payer(&mut program);
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+    let config = PauseConfigData::pda_address(&crate::ID);
+
+    // The config must be the canonical PDA.
+    let instruction = crate::program::lpi::init_pause_config(LpiInitPauseConfig {
+        admin: Argument::new(admin.pubkey()),
+        payer: payer.pubkey(),
+        config: admin.pubkey(),
+        system_program: *System::address(),
+    })
+    .expect("Cannot build instruction");
+
+    let result = process_instructions(&mut banks_client, &payer, &[instruction]).await;
+    assert_fankor_error(
+        result,
+        FankorErrorCode::InvalidPda {
+            expected: config,
+            actual: admin.pubkey(),
+        },
+    );
+
+    let instruction = crate::program::lpi::init_pause_config(LpiInitPauseConfig {
+        admin: Argument::new(admin.pubkey()),
+        payer: payer.pubkey(),
+        config,
+        system_program: *System::address(),
+    })
+    .expect("Cannot build instruction");
+
+    process_instructions(&mut banks_client, &payer, &[instruction])
+        .await
+        .expect("Cannot initialize the pause config");
+
+    let account = banks_client
+        .get_account(config)
+        .await
+        .expect("Cannot get the pause config")
+        .expect("The pause config must exist");
+    let data = PauseConfigData::try_from_slice(&account.data)
+        .expect("Cannot deserialize the pause config");
+    assert_eq!(data.admin, admin.pubkey());
+    assert!(!data.paused);
+
+    // Only the admin can pause the program.
+    let instruction = crate::program::lpi::pause(LpiPause {
+        admin: payer.pubkey(),
+        config,
+    })
+    .expect("Cannot build instruction");
+
+    let result = process_instructions(&mut banks_client, &payer, &[instruction]).await;
+    assert_fankor_error(
+        result,
+        FankorErrorCode::PauseAdminMismatch {
+            expected: admin.pubkey(),
+            actual: payer.pubkey(),
+        },
+    );
+
+    let instruction = crate::program::lpi::pause(LpiPause {
+        admin: admin.pubkey(),
+        config,
+    })
+    .expect("Cannot build instruction");
+
+    process_signed_instructions(
+        &mut banks_client,
+        &payer,
+        vec![&payer, &admin],
+        &[instruction],
+    )
+    .await
+    .expect("Cannot pause the program");
+
+    // Only the instructions allowed when paused can be executed.
+    let instruction = crate::program::lpi::top_level_instruction(LpiTopLevelInstruction {
+        account: payer.pubkey(),
+    })
+    .expect("Cannot build instruction");
+
+    let result = process_instructions(&mut banks_client, &payer, &[instruction]).await;
+    assert_fankor_error(result, FankorErrorCode::ProgramPaused);
+
+    let instruction =
+        crate::program::lpi::allow_when_paused_instruction(LpiAllowWhenPausedInstruction {
+            account: payer.pubkey(),
+        })
+        .expect("Cannot build instruction");
+
+    process_instructions(&mut banks_client, &payer, &[instruction])
+        .await
+        .expect("The instruction must be allowed when paused");
+
+    // The new admin replaces the previous one.
+    let instruction = crate::program::lpi::transfer_admin(LpiTransferAdmin {
+        new_admin: Argument::new(payer.pubkey()),
+        admin: admin.pubkey(),
+        config,
+    })
+    .expect("Cannot build instruction");
+
+    process_signed_instructions(
+        &mut banks_client,
+        &payer,
+        vec![&payer, &admin],
+        &[instruction],
+    )
+    .await
+    .expect("Cannot transfer the admin");
+
+    let instruction = crate::program::lpi::unpause(LpiUnpause {
+        admin: admin.pubkey(),
+        config,
+    })
+    .expect("Cannot build instruction");
+
+    let result = process_signed_instructions(
+        &mut banks_client,
+        &payer,
+        vec![&payer, &admin],
+        &[instruction],
+    )
+    .await;
+    assert_fankor_error(
+        result,
+        FankorErrorCode::PauseAdminMismatch {
+            expected: payer.pubkey(),
+            actual: admin.pubkey(),
+        },
+    );
+
+    let instruction = crate::program::lpi::unpause(LpiUnpause {
+        admin: payer.pubkey(),
+        config,
+    })
+    .expect("Cannot build instruction");
+
+    process_instructions(&mut banks_client, &payer, &[instruction])
+        .await
+        .expect("Cannot unpause the program");
+
+    // A different account avoids repeating the failed transaction.
+    let instruction = crate::program::lpi::top_level_instruction(LpiTopLevelInstruction {
+        account: admin.pubkey(),
+    })
+    .expect("Cannot build instruction");
+
+    process_instructions(&mut banks_client, &payer, &[instruction])
+        .await
+        .expect("The program must be unpaused");
+}
+
+#[tokio::test]
+async fn test_top_level_only() {
+    let mut program = new_program_test();
+    let payer = add_payer(&mut program);
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+    let instruction = crate::program::lpi::top_level_instruction(LpiTopLevelInstruction {
+        account: payer.pubkey(),
+    })
+    .expect("Cannot build instruction");
+
+    process_instructions(&mut banks_client, &payer, &[instruction.clone()])
+        .await
+        .expect("Cannot process transaction");
 
-    // Final checks
-    // let account_data = banks_client
-    //     .get_account_data_with_borsh(account.pubkey())
-    //     .await
-    //     .unwrap();
-    // assert_eq!(account_data.owner, Pubkey::default());
+    let result =
+        process_instructions(&mut banks_client, &payer, &[relay_instruction(instruction)]).await;
+    assert_fankor_error(result, FankorErrorCode::InstructionNotTopLevel);
+}
+
+#[tokio::test]
+async fn test_callable_by() {
+    let mut program = new_program_test();
+    let payer = add_payer(&mut program);
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+    let instruction =
+        crate::program::lpi::caller_restricted_instruction(LpiCallerRestrictedInstruction {
+            instructions_sysvar: sysvar::instructions::ID,
+        })
+        .expect("Cannot build instruction");
+
+    let result = process_instructions(&mut banks_client, &payer, &[instruction.clone()]).await;
+    assert_fankor_error(result, FankorErrorCode::InstructionNotCalledViaCpi);
+
+    process_instructions(
+        &mut banks_client,
+        &payer,
+        &[relay_instruction(instruction.clone())],
+    )
+    .await
+    .expect("Cannot process transaction");
+
+    // The caller of nested CPIs cannot be determined.
+    let result = process_instructions(
+        &mut banks_client,
+        &payer,
+        &[relay_instruction(relay_instruction(instruction))],
+    )
+    .await;
+    assert_fankor_error(result, FankorErrorCode::InstructionCallerUnknown);
+}
+
+#[tokio::test]
+async fn test_no_reentrancy() {
+    let mut program = new_program_test();
+    let payer = add_payer(&mut program);
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+    let instruction = crate::program::lpi::no_reentrancy_instruction(LpiNoReentrancyInstruction {
+        instructions_sysvar: sysvar::instructions::ID,
+    })
+    .expect("Cannot build instruction");
+
+    process_instructions(&mut banks_client, &payer, &[relay_instruction(instruction)])
+        .await
+        .expect("Cannot process transaction");
+
+    let instruction = crate::program::lpi::reentrant_instruction(LpiReentrantInstruction {
+        test_program: crate::ID,
+        instructions_sysvar: sysvar::instructions::ID,
+    })
+    .expect("Cannot build instruction");
+
+    let result = process_instructions(&mut banks_client, &payer, &[instruction.clone()]).await;
+    assert_fankor_error(
+        result,
+        FankorErrorCode::ReentrancyDetected {
+            program_id: crate::ID,
+        },
+    );
+
+    // The re-entries of nested CPIs cannot be told apart from other calls.
+    let result =
+        process_instructions(&mut banks_client, &payer, &[relay_instruction(instruction)]).await;
+    assert_fankor_error(result, FankorErrorCode::InstructionCallerUnknown);
+}
+
+#[tokio::test]
+async fn test_split_stake() {
+    let mut program = new_program_test();
+    let payer = add_payer(&mut program);
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+    let stake = Keypair::new();
+    let split_stake = Keypair::new();
+    let reserve = Rent::default().minimum_balance(StakeState::size_of());
+
+    process_signed_instructions(
+        &mut banks_client,
+        &payer,
+        vec![&payer, &stake],
+        &stake_instruction::create_account(
+            &payer.pubkey(),
+            &stake.pubkey(),
+            &Authorized::auto(&payer.pubkey()),
+            &Lockup::default(),
+            2 * reserve,
+        ),
+    )
+    .await
+    .expect("Cannot create the stake account");
+
+    let instruction = crate::program::lpi::split_stake_instruction(LpiSplitStakeInstruction {
+        lamports: Argument::new(reserve),
+        stake: stake.pubkey(),
+        split_stake: split_stake.pubkey(),
+        authorized: payer.pubkey(),
+        stake_program: *Stake::address(),
+        system_program: *System::address(),
+    })
+    .expect("Cannot build instruction");
+
+    process_signed_instructions(
+        &mut banks_client,
+        &payer,
+        vec![&payer, &split_stake],
+        &[instruction],
+    )
+    .await
+    .expect("Cannot process transaction");
+
+    let account = banks_client
+        .get_account(split_stake.pubkey())
+        .await
+        .expect("Cannot get the split stake account")
+        .expect("Missing split stake account");
+    assert_eq!(account.owner, *Stake::address(), "Invalid owner");
+    assert_eq!(account.lamports, reserve, "Invalid lamports");
+
+    let data = StakeAccount::deserialize(&mut account.data.as_slice())
+        .expect("Cannot deserialize the split stake account");
+    match *data {
+        StakeState::Initialized(meta) => {
+            assert_eq!(meta.authorized.staker, payer.pubkey(), "Invalid staker");
+        }
+        _ => panic!("The split stake account must be initialized"),
+    }
+}
+
+#[tokio::test]
+async fn test_lookup_table_cpi() {
+    let mut program = new_program_test();
+    let payer = add_payer(&mut program);
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+    let authority = Keypair::new();
+    let addresses = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+    let (recent_slot, _) = *banks_client
+        .get_sysvar::<SlotHashes>()
+        .await
+        .expect("Cannot get the SlotHashes sysvar")
+        .first()
+        .expect("Missing recent slot");
+    let (lookup_table, _) =
+        solana_address_lookup_table_program::instruction::derive_lookup_table_address(
+            &authority.pubkey(),
+            recent_slot,
+        );
+
+    let instruction =
+        crate::program::lpi::create_lookup_table_instruction(LpiCreateLookupTableInstruction {
+            recent_slot: Argument::new(recent_slot),
+            addresses: Argument::new(addresses.clone()),
+            lookup_table,
+            authority: authority.pubkey(),
+            payer: payer.pubkey(),
+            lookup_table_program: *AddressLookupTableProgram::address(),
+            system_program: *System::address(),
+        })
+        .expect("Cannot build instruction");
+
+    process_signed_instructions(
+        &mut banks_client,
+        &payer,
+        vec![&payer, &authority],
+        &[instruction],
+    )
+    .await
+    .expect("Cannot process transaction");
+
+    let instruction =
+        crate::program::lpi::read_lookup_table_instruction(LpiReadLookupTableInstruction {
+            addresses: Argument::new(addresses),
+            lookup_table,
+        })
+        .expect("Cannot build instruction");
+
+    process_instructions(&mut banks_client, &payer, &[instruction])
+        .await
+        .expect("Cannot process transaction");
+}
+
+#[tokio::test]
+async fn test_lookup_table_client_extensions() {
+    let mut program = new_program_test();
+    let payer = add_payer(&mut program);
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+    let authority = Keypair::new();
+    let addresses = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let lookup_table = banks_client
+        .create_lookup_table(&authority.pubkey(), &payer)
+        .await
+        .expect("Cannot create the lookup table");
+    banks_client
+        .extend_lookup_table(&lookup_table, &authority, addresses.clone(), &payer)
+        .await
+        .expect("Cannot extend the lookup table");
+
+    let account = banks_client
+        .get_account(lookup_table)
+        .await
+        .expect("Cannot get the lookup table")
+        .expect("Missing lookup table");
+    let table =
+        solana_address_lookup_table_program::state::AddressLookupTable::deserialize(&account.data)
+            .expect("Cannot deserialize the lookup table");
+    assert_eq!(table.meta.authority, Some(authority.pubkey()));
+    assert_eq!(table.addresses[..], addresses[..]);
+
+    // The account wrapper reads the same addresses.
+    let instruction =
+        crate::program::lpi::read_lookup_table_instruction(LpiReadLookupTableInstruction {
+            addresses: Argument::new(addresses),
+            lookup_table,
+        })
+        .expect("Cannot build instruction");
+
+    process_instructions(&mut banks_client, &payer, &[instruction])
+        .await
+        .expect("Cannot process transaction");
+}
+
+#[test]
+fn test_batch_discriminant() {
+    assert_eq!(TestProgramDiscriminant::Batch.code(), 255);
 }
 
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Creates the program test of `TestProgram` along with the relay program.
+pub fn new_program_test() -> ProgramTest {
+    new_program_test_with_admin(&Pubkey::default())
+}
+
+/// Creates the program test with the unpaused pause config account of the
+/// program managed by `admin`.
+pub fn new_program_test_with_admin(admin: &Pubkey) -> ProgramTest {
+    let mut program = new_program_test_without_pause_config();
+
+    let (address, bump) = Pubkey::find_program_address(&[PAUSE_CONFIG_SEED], &crate::ID);
+    let data = PauseConfigData {
+        admin: *admin,
+        paused: false,
+        bump,
+    }
+    .try_to_vec()
+    .expect("Cannot serialize the pause config");
+
+    program.add_account(
+        address,
+        solana_sdk::account::Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: crate::ID,
+            ..Default::default()
+        },
+    );
+    program
+}
+
+pub fn new_program_test_without_pause_config() -> ProgramTest {
+    let mut program = TestProgram::new_program_test();
+    program.add_program(
+        RelayProgram::name(),
+        *RelayProgram::address(),
+        processor!(process_relay_instruction),
+    );
+    program
+}
+
 pub fn add_payer(program: &mut ProgramTest) -> Keypair {
     let payer = Keypair::new();
     program.add_account(
@@ -56,3 +605,95 @@ pub fn add_payer(program: &mut ProgramTest) -> Keypair {
     );
     payer
 }
+
+/// Processes the `instructions` in a transaction paid by `payer`.
+pub async fn process_instructions(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    instructions: &[Instruction],
+) -> Result<(), BanksClientError> {
+    process_signed_instructions(banks_client, payer, vec![payer], instructions).await
+}
+
+/// Processes the `instructions` in a transaction paid by `payer` and signed by
+/// `signers`.
+pub async fn process_signed_instructions(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    signers: Vec<&Keypair>,
+    instructions: &[Instruction],
+) -> Result<(), BanksClientError> {
+    let transaction = banks_client
+        .create_transaction_from_instructions(instructions, payer, signers)
+        .await
+        .expect("Cannot build transaction");
+
+    banks_client.process_transaction(transaction).await
+}
+
+/// Simulates the `instructions` in a transaction paid by `payer`, returning
+/// its logs and the compute units it has consumed.
+pub async fn simulate_instructions(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    instructions: &[Instruction],
+) -> (Vec<String>, u64) {
+    let transaction = banks_client
+        .create_transaction_from_instructions(instructions, payer, vec![payer])
+        .await
+        .expect("Cannot build transaction");
+
+    let details = banks_client
+        .simulate_transaction(transaction)
+        .await
+        .expect("Cannot simulate transaction")
+        .simulation_details
+        .expect("Missing simulation details");
+
+    (details.logs, details.units_consumed)
+}
+
+/// Checks the transaction has failed in its first instruction with `error`.
+pub fn assert_fankor_error(result: Result<(), BanksClientError>, error: FankorErrorCode) {
+    assert_eq!(
+        result.expect_err("The transaction must fail").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(error.error_code()))
+    );
+}
+
+/// Wraps `instruction` into an instruction of the relay program that forwards
+/// it through a CPI.
+pub fn relay_instruction(instruction: Instruction) -> Instruction {
+    let mut accounts = vec![AccountMeta::new_readonly(instruction.program_id, false)];
+    accounts.extend(instruction.accounts);
+
+    Instruction {
+        program_id: *RelayProgram::address(),
+        accounts,
+        data: instruction.data,
+    }
+}
+
+/// The processor of the relay program, that forwards its instruction data to
+/// the program of its first account along with the rest of the accounts.
+fn process_relay_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let program = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let instruction = Instruction {
+        program_id: *program.key,
+        accounts: accounts[1..]
+            .iter()
+            .map(|v| AccountMeta {
+                pubkey: *v.key,
+                is_signer: v.is_signer,
+                is_writable: v.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+
+    invoke(&instruction, accounts)
+}
@@ -0,0 +1,51 @@
+import assert from 'assert';
+import { AccountMeta, Keypair, TransactionInstruction } from '@solana/web3.js';
+import { FnkBorshWriter } from '../serde';
+import { writeBatchInstructions } from './batch';
+
+describe('writeBatchInstructions Tests', () => {
+    it('test_write', () => {
+        const programId = Keypair.generate().publicKey;
+        const account = {
+            pubkey: Keypair.generate().publicKey,
+            isSigner: false,
+            isWritable: true,
+        };
+        const instructions = [
+            new TransactionInstruction({
+                keys: [account, account],
+                programId,
+                data: Buffer.from([3, 1, 2]),
+            }),
+            new TransactionInstruction({
+                keys: [],
+                programId,
+                data: Buffer.from([5]),
+            }),
+        ];
+
+        const writer = new FnkBorshWriter();
+        const accountMetas: AccountMeta[] = [];
+        writeBatchInstructions(writer, accountMetas, instructions, programId);
+
+        assert.deepEqual([...writer.toBuffer()], [2, 3, 2, 1, 2, 2, 5, 0, 0]);
+        assert.deepEqual(accountMetas, [account, account]);
+    });
+
+    it('test_other_program', () => {
+        const instruction = new TransactionInstruction({
+            keys: [],
+            programId: Keypair.generate().publicKey,
+            data: Buffer.from([3]),
+        });
+
+        assert.throws(() =>
+            writeBatchInstructions(
+                new FnkBorshWriter(),
+                [],
+                [instruction],
+                Keypair.generate().publicKey
+            )
+        );
+    });
+});
@@ -0,0 +1,42 @@
+import BN from 'bn.js';
+import {
+    AccountMeta,
+    PublicKey,
+    TransactionInstruction,
+} from '@solana/web3.js';
+import { FnkBorshWriter, FnkUInt } from '../serde';
+
+/**
+ * Writes `instructions` as the data of the `Batch` instruction generated by
+ * `#[program(batch)]`, appending their accounts to `accountMetas`. All the
+ * instructions must belong to `programId`.
+ */
+export function writeBatchInstructions(
+    writer: FnkBorshWriter,
+    accountMetas: AccountMeta[],
+    instructions: TransactionInstruction[],
+    programId: PublicKey
+) {
+    FnkUInt.serialize(writer, new BN(instructions.length));
+
+    for (const instruction of instructions) {
+        if (!instruction.programId.equals(programId)) {
+            throw new Error(
+                `Cannot batch an instruction of another program: ${instruction.programId.toBase58()}`
+            );
+        }
+
+        if (instruction.data.length === 0) {
+            throw new Error('Cannot batch an instruction without discriminant');
+        }
+
+        const data = instruction.data.subarray(1);
+
+        writer.writeByte(instruction.data[0]);
+        FnkUInt.serialize(writer, new BN(data.length));
+        writer.writeBuffer(data);
+        FnkUInt.serialize(writer, new BN(instruction.keys.length));
+
+        accountMetas.push(...instruction.keys);
+    }
+}
@@ -0,0 +1,41 @@
+import assert from 'assert';
+import { FnkBorshWriter } from '../serde';
+import {
+    FnkCompactInstructionWriter,
+    writeInstructionFlag,
+    writeInstructionLength,
+} from './compact';
+
+describe('FnkCompactInstructionWriter Tests', () => {
+    it('test_inline_layout', () => {
+        const writer = new FnkBorshWriter();
+        writeInstructionFlag(writer, true);
+        writeInstructionLength(writer, 3);
+        writer.writeByte(7);
+
+        assert.deepEqual([...writer.toBuffer()], [1, 3, 7]);
+    });
+
+    it('test_compact_layout', () => {
+        const writer = new FnkBorshWriter();
+        writer.writeByte(9);
+
+        const compactWriter = FnkCompactInstructionWriter.from(writer);
+        assert.notEqual(compactWriter, writer);
+        assert.equal(FnkCompactInstructionWriter.from(compactWriter), compactWriter);
+
+        for (let i = 0; i < 10; i += 1) {
+            writeInstructionFlag(compactWriter, i % 3 === 0);
+        }
+
+        writeInstructionLength(compactWriter, 3);
+        writeInstructionLength(compactWriter, 200);
+        compactWriter.writeByte(1);
+        compactWriter.flushInto(writer);
+
+        assert.deepEqual(
+            [...writer.toBuffer()],
+            [9, 10, 0b0100_1001, 0b0000_0010, 2, 3, 0x48, 3, 1]
+        );
+    });
+});
@@ -0,0 +1,91 @@
+import BN from 'bn.js';
+import { FnkBorshWriter, FnkUInt } from '../serde';
+
+/**
+ * Writer for instructions declared with `#[instruction(compact_layout)]`. It
+ * collects the flags of `Option` and `Either` and the lengths of `Vec` to
+ * write them as a header that precedes the rest of the data.
+ */
+export class FnkCompactInstructionWriter extends FnkBorshWriter {
+    flags: boolean[] = [];
+    lengths: number[] = [];
+
+    // METHODS ----------------------------------------------------------------
+
+    writeFlag(flag: boolean) {
+        this.flags.push(flag);
+    }
+
+    writeLength(length: number) {
+        this.lengths.push(length);
+    }
+
+    /**
+     * Writes the header followed by the data into `writer`. It does nothing
+     * if `writer` is this writer, i.e. when it has been reused for a nested
+     * instruction.
+     */
+    flushInto(writer: FnkBorshWriter) {
+        if (writer === this) {
+            return;
+        }
+
+        FnkUInt.serialize(writer, new BN(this.flags.length));
+
+        for (let i = 0; i < this.flags.length; i += 8) {
+            let byte = 0;
+
+            for (let j = 0; j < 8 && i + j < this.flags.length; j += 1) {
+                if (this.flags[i + j]) {
+                    byte |= 1 << j;
+                }
+            }
+
+            writer.writeByte(byte);
+        }
+
+        FnkUInt.serialize(writer, new BN(this.lengths.length));
+
+        for (const length of this.lengths) {
+            FnkUInt.serialize(writer, new BN(length));
+        }
+
+        writer.writeBuffer(this.toBuffer());
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /**
+     * Reuses `writer` if it is already a compact writer, otherwise creates a
+     * new one.
+     */
+    static from(writer: FnkBorshWriter) {
+        if (writer instanceof FnkCompactInstructionWriter) {
+            return writer;
+        }
+
+        return new FnkCompactInstructionWriter();
+    }
+}
+
+/**
+ * Writes the flag of an `Option` or `Either` instruction account.
+ */
+export function writeInstructionFlag(writer: FnkBorshWriter, flag: boolean) {
+    if (writer instanceof FnkCompactInstructionWriter) {
+        writer.writeFlag(flag);
+    } else {
+        writer.writeByte(flag ? 1 : 0);
+    }
+}
+
+/**
+ * Writes the length of a `Vec` instruction account.
+ */
+export function writeInstructionLength(writer: FnkBorshWriter, length: number) {
+    if (writer instanceof FnkCompactInstructionWriter) {
+        writer.writeLength(length);
+    } else {
+        writer.writeByte(length);
+    }
+}
@@ -1 +1,3 @@
+export * from './batch';
+export * from './compact';
 export * from './either';
@@ -3,7 +3,7 @@ import { AccountInfo, PublicKey } from '@solana/web3.js';
 export * from './accounts';
 export * from './serde';
 export * from './utils';
-export * from './errors';
+export * from './rpc_errors';
 
 /**
  * Data information returned by lots of functions.
@@ -1,5 +1,5 @@
 import assert from 'assert';
-import { RpcFankorError } from './rpc_errors';
+import { errorNameHash, FnkErrorTable, RpcFankorError } from './rpc_errors';
 
 describe('RpcFankorError tests', () => {
     it('test', () => {
@@ -28,4 +28,50 @@ describe('RpcFankorError tests', () => {
         assert.strictEqual(error.name, name, 'Invalid error name');
         assert.strictEqual(error.message, message, 'Invalid error message');
     });
+
+    it('test_minimal_logs', () => {
+        const code = 1006;
+        const nameHash = 3506102775;
+        const log = `Program log: FankorError occurred. Error Code: ${code}. Error Name Hash: ${nameHash}.`;
+
+        assert.strictEqual(errorNameHash('InvalidPda'), nameHash);
+
+        let logs = [
+            'another log',
+            'Program log: FankorError occurred. Error Code: ',
+            'Program log: FankorError occurred. Error Code: {}. Error Name Hash: ',
+            log,
+        ];
+        let error = RpcFankorError.fromLogs(logs);
+
+        if (error === null) {
+            throw new Error('error is null');
+        }
+
+        assert.strictEqual(error.code, code, 'Invalid error code');
+        assert.strictEqual(error.name, '', 'Invalid error name');
+        assert.strictEqual(error.nameHash, nameHash, 'Invalid name hash');
+
+        const table: FnkErrorTable = new Map([
+            [
+                1006,
+                [
+                    'InvalidPda',
+                    'The provided PDA ({}) does not match expected one ({})',
+                ],
+            ],
+        ]);
+        error = RpcFankorError.fromLogs(logs, table);
+
+        if (error === null) {
+            throw new Error('error is null');
+        }
+
+        assert.strictEqual(error.name, 'InvalidPda', 'Invalid error name');
+        assert.strictEqual(
+            error.message,
+            'The provided PDA ({}) does not match expected one ({})',
+            'Invalid error message'
+        );
+    });
 });
@@ -1,4 +1,27 @@
+/**
+ * The table of an error type that maps every error code to its name and
+ * message template. It is generated for the errors defined with
+ * `#[error_code]`.
+ */
+export type FnkErrorTable = Map<number, [string, string]>;
+
+/**
+ * Computes the hash of an error name that the programs compiled with the
+ * `minimal-logs` feature log instead of the name.
+ */
+export function errorNameHash(name: string): number {
+    let hash = 0x811c9dc5;
+
+    for (const byte of Buffer.from(name, 'utf8')) {
+        hash = Math.imul(hash ^ byte, 0x01000193);
+    }
+
+    return hash >>> 0;
+}
+
 export class RpcFankorError extends Error {
+    public nameHash: number;
+
     // CONSTRUCTORS -----------------------------------------------------------
 
     constructor(
@@ -7,10 +30,21 @@ export class RpcFankorError extends Error {
         public message: string
     ) {
         super();
+        this.nameHash = errorNameHash(name);
     }
 
-    static fromLogs(logs: string[]) {
+    /**
+     * Parses the error from the logs of a transaction. The errors logged by
+     * programs with the `minimal-logs` feature are resolved from `tables`,
+     * keeping an empty name and message if none of them contains the error.
+     */
+    static fromLogs(logs: string[], ...tables: FnkErrorTable[]) {
         for (let log of logs) {
+            const minimalError = RpcFankorError.fromMinimalLog(log);
+            if (minimalError !== null) {
+                return minimalError.resolve(tables);
+            }
+
             if (
                 !log.startsWith(
                     'Program log: FankorError occurred. Error Name: '
@@ -64,8 +98,73 @@ export class RpcFankorError extends Error {
         return null;
     }
 
+    private static fromMinimalLog(log: string) {
+        if (
+            !log.startsWith('Program log: FankorError occurred. Error Code: ')
+        ) {
+            return null;
+        }
+
+        log = log.slice(
+            'Program log: FankorError occurred. Error Code: '.length
+        );
+        const position = log.indexOf('.');
+        if (position === -1) {
+            return null;
+        }
+
+        const code = parseU32(log.slice(0, position));
+        log = log.slice(position);
+
+        if (
+            code === null ||
+            !log.startsWith('. Error Name Hash: ') ||
+            !log.endsWith('.')
+        ) {
+            return null;
+        }
+
+        const nameHash = parseU32(
+            log.slice('. Error Name Hash: '.length, log.length - 1)
+        );
+        if (nameHash === null) {
+            return null;
+        }
+
+        const error = new RpcFankorError(code, '', '');
+        error.nameHash = nameHash;
+
+        return error;
+    }
+
     // METHODS ----------------------------------------------------------------
 
+    /**
+     * Fills the name and message of an error logged with the `minimal-logs`
+     * feature. The message is the template of the original one because the
+     * logs do not include the values of the error.
+     */
+    resolve(tables: FnkErrorTable[]) {
+        if (this.name !== '') {
+            return this;
+        }
+
+        for (const table of tables) {
+            const entry = table.get(this.code);
+
+            if (
+                entry !== undefined &&
+                errorNameHash(entry[0]) === this.nameHash
+            ) {
+                this.name = entry[0];
+                this.message = entry[1];
+                break;
+            }
+        }
+
+        return this;
+    }
+
     toString(buffer?: Buffer) {
         return `FankorError ${this.name} ${this.code}(0x${this.code.toString(
             16
@@ -80,3 +179,12 @@ export class RpcFankorError extends Error {
         );
     }
 }
+
+function parseU32(value: string) {
+    if (!/^[0-9]+$/.test(value)) {
+        return null;
+    }
+
+    const result = parseInt(value);
+    return result > 0xffffffff ? null : result;
+}
@@ -0,0 +1,56 @@
+import assert from 'assert';
+import { FnkDecimal, TFnkDecimal } from './decimals';
+import BN from 'bn.js';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshReader } from '../../deserializer';
+
+describe('FnkDecimal Tests', () => {
+    const schema = TFnkDecimal(2);
+
+    it('test_to_string', () => {
+        assert.equal(new FnkDecimal(new BN(0), 2).toString(), '0.00');
+        assert.equal(new FnkDecimal(new BN(5), 2).toString(), '0.05');
+        assert.equal(new FnkDecimal(new BN(-1234), 2).toString(), '-12.34');
+        assert.equal(new FnkDecimal(new BN(-7), 0).toString(), '-7');
+    });
+
+    it('test_from_string', () => {
+        for (const value of ['0.00', '0.05', '-12.34', '1500.00']) {
+            assert.equal(FnkDecimal.fromString(value, 2).toString(), value);
+        }
+
+        assert(FnkDecimal.fromString('3.1', 2).mantissa.eqn(310));
+        assert.throws(() => FnkDecimal.fromString('1.234', 2));
+        assert.throws(() => FnkDecimal.fromString('1.2.3', 2));
+    });
+
+    it('test_serialize_deserialize', () => {
+        for (const mantissa of [
+            new BN(0),
+            new BN(1),
+            new BN(-1),
+            new BN(8191),
+            new BN(-8192),
+            new BN('9223372036854775807'),
+            new BN('-9223372036854775808'),
+        ]) {
+            const decimal = new FnkDecimal(mantissa, 2);
+            const writer = new FnkBorshWriter();
+            schema.serialize(writer, decimal);
+
+            const buffer = writer.buffer.slice(0, writer.length);
+            const reader = new FnkBorshReader(buffer);
+            const actual = schema.deserialize(reader);
+
+            assert(actual.equals(decimal), `${actual} != ${decimal}`);
+        }
+    });
+
+    it('test_serialize_wrong_scale', () => {
+        const writer = new FnkBorshWriter();
+
+        assert.throws(() =>
+            schema.serialize(writer, new FnkDecimal(new BN(1), 3))
+        );
+    });
+});
@@ -0,0 +1,109 @@
+import BN from 'bn.js';
+import { FnkIntSchema } from './integers';
+import { FnkBorshReader } from '../../deserializer';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshError } from '../../errors';
+import { FnkBorshSchema } from '../../borsh';
+
+const TEN = new BN(10);
+
+export class FnkDecimal {
+    readonly mantissa: BN;
+    readonly scale: number;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    constructor(mantissa: BN, scale: number) {
+        if (!Number.isInteger(scale) || scale < 0 || scale > 18) {
+            throw new RangeError('FnkDecimal scale must be in range [0, 18]');
+        }
+
+        this.mantissa = mantissa;
+        this.scale = scale;
+    }
+
+    /**
+     * Parses a decimal string like `-12.345`. The number of fractional digits
+     * cannot exceed the scale.
+     */
+    static fromString(value: string, scale: number): FnkDecimal {
+        const match = /^(-?)(\d+)(?:\.(\d+))?$/.exec(value.trim());
+
+        if (match === null) {
+            throw new FnkBorshError(`Invalid FnkDecimal: ${value}`);
+        }
+
+        const [, sign, integer, fraction = ''] = match;
+
+        if (fraction.length > scale) {
+            throw new FnkBorshError(
+                `FnkDecimal has more than ${scale} fractional digits: ${value}`
+            );
+        }
+
+        let mantissa = new BN(integer + fraction.padEnd(scale, '0'));
+
+        if (sign === '-') {
+            mantissa = mantissa.neg();
+        }
+
+        return new FnkDecimal(mantissa, scale);
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    equals(other: FnkDecimal): boolean {
+        return this.scale === other.scale && this.mantissa.eq(other.mantissa);
+    }
+
+    toString(): string {
+        const sign = this.mantissa.isNeg() ? '-' : '';
+        const abs = this.mantissa.abs();
+
+        if (this.scale === 0) {
+            return sign + abs.toString();
+        }
+
+        const factor = TEN.pow(new BN(this.scale));
+        const integer = abs.div(factor).toString();
+        const fraction = abs.mod(factor).toString().padStart(this.scale, '0');
+
+        return `${sign}${integer}.${fraction}`;
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export class FnkDecimalSchema implements FnkBorshSchema<FnkDecimal> {
+    readonly scale: number;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    constructor(scale: number) {
+        this.scale = scale;
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: FnkDecimal) {
+        if (value.scale !== this.scale) {
+            throw new FnkBorshError(
+                `Incorrect FnkDecimal scale: ${value.scale} != ${this.scale}`
+            );
+        }
+
+        new FnkIntSchema().serialize(writer, value.mantissa);
+    }
+
+    deserialize(reader: FnkBorshReader): FnkDecimal {
+        const mantissa = new FnkIntSchema().deserialize(reader);
+
+        return new FnkDecimal(mantissa, this.scale);
+    }
+}
+
+export function TFnkDecimal(scale: number) {
+    return new FnkDecimalSchema(scale);
+}
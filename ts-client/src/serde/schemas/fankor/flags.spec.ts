@@ -0,0 +1,38 @@
+import assert from 'assert';
+import { FnkFlags, TFnkFlags } from './flags';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshReader } from '../../deserializer';
+
+describe('FnkFlags Tests', () => {
+    it('test_set_clear_has', () => {
+        let flags = FnkFlags.fromIndexes([0, 9]);
+
+        assert(flags.has(0));
+        assert(flags.has(9));
+        assert(!flags.has(1));
+
+        flags = flags.clear(0).toggle(1);
+        assert.deepEqual(flags.toIndexes(), [1, 9]);
+        assert(!flags.isEmpty());
+    });
+
+    it('test_serialize_deserialize', () => {
+        const schema = TFnkFlags(2);
+        const flags = FnkFlags.fromIndexes([0, 1, 9]);
+
+        const writer = new FnkBorshWriter();
+        schema.serialize(writer, flags);
+
+        const buffer = writer.buffer.slice(0, writer.length);
+        assert(buffer.equals(Buffer.from([0b0000_0011, 0b0000_0010])));
+
+        const reader = new FnkBorshReader(buffer);
+        const actual = schema.deserialize(reader);
+        assert(actual.equals(flags));
+        assert(reader.offset === buffer.length);
+
+        assert.throws(() =>
+            TFnkFlags(1).serialize(new FnkBorshWriter(), flags)
+        );
+    });
+});
@@ -0,0 +1,110 @@
+import BN from 'bn.js';
+import { FnkBorshReader } from '../../deserializer';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshError } from '../../errors';
+import { FnkBorshSchema } from '../../borsh';
+
+export class FnkFlags {
+    readonly bits: BN;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    constructor(bits?: BN) {
+        bits = bits ?? new BN(0);
+
+        if (bits.isNeg()) {
+            throw new RangeError('FnkFlags bits cannot be negative');
+        }
+
+        this.bits = bits;
+    }
+
+    /**
+     * Creates a new set with the bits at the given indexes set.
+     */
+    static fromIndexes(indexes: number[]): FnkFlags {
+        let bits = new BN(0);
+
+        for (const index of indexes) {
+            bits = bits.setn(index, true);
+        }
+
+        return new FnkFlags(bits);
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    has(index: number): boolean {
+        return this.bits.testn(index);
+    }
+
+    set(index: number): FnkFlags {
+        return new FnkFlags(this.bits.setn(index, true));
+    }
+
+    clear(index: number): FnkFlags {
+        return new FnkFlags(this.bits.setn(index, false));
+    }
+
+    toggle(index: number): FnkFlags {
+        return this.has(index) ? this.clear(index) : this.set(index);
+    }
+
+    isEmpty(): boolean {
+        return this.bits.isZero();
+    }
+
+    /**
+     * Gets the indexes of the bits that are set.
+     */
+    toIndexes(): number[] {
+        const result: number[] = [];
+
+        for (let i = 0; i < this.bits.bitLength(); i++) {
+            if (this.bits.testn(i)) {
+                result.push(i);
+            }
+        }
+
+        return result;
+    }
+
+    equals(other: FnkFlags): boolean {
+        return this.bits.eq(other.bits);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export class FnkFlagsSchema implements FnkBorshSchema<FnkFlags> {
+    readonly byteSize: number;
+
+    // CONSTRUCTOR ------------------------------------------------------------
+
+    constructor(byteSize: number) {
+        this.byteSize = byteSize;
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: FnkFlags) {
+        if (value.bits.byteLength() > this.byteSize) {
+            throw new FnkBorshError(
+                `FnkFlags do not fit in ${this.byteSize} bytes`
+            );
+        }
+
+        writer.writeBuffer(value.bits.toArrayLike(Buffer, 'le', this.byteSize));
+    }
+
+    deserialize(reader: FnkBorshReader): FnkFlags {
+        const buffer = reader.readBuffer(this.byteSize);
+        return new FnkFlags(new BN(buffer, 'le'));
+    }
+}
+
+export function TFnkFlags(byteSize: number) {
+    return new FnkFlagsSchema(byteSize);
+}
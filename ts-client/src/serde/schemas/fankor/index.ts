@@ -1,7 +1,10 @@
 export * from './bmap';
+export * from './decimals';
+export * from './flags';
 export * from './integers';
 export * from './maps';
 export * from './ranges';
 export * from './strings';
+export * from './time';
 export * from './unsigned';
 export * from './vectors';
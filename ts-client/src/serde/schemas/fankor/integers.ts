@@ -133,3 +133,116 @@ export class FnkIntSchema implements FnkBorshSchema<BN> {
 }
 
 export const FnkInt = new FnkIntSchema();
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+const MIN_VALUE_128 = new BN(1).shln(127).neg(); // -2^127
+const MIN_I128_ABS = new BN(1).shln(127); // 2^127
+const MAX_VALUE_128 = new BN(1).shln(127).subn(1); // 2^127 - 1
+
+/**
+ * Same encoding as `FnkInt` but the length encoding can use up to 16 bytes,
+ * so numbers in range [-2^63, 2^63 - 1] are encoded exactly as a `FnkInt`.
+ */
+export class FnkInt128Schema implements FnkBorshSchema<BN> {
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: BN) {
+        if (value.lt(MIN_VALUE_128)) {
+            throw new RangeError('FnkInt128 cannot be lower than -2^127');
+        }
+
+        if (value.gt(MAX_VALUE_128)) {
+            throw new RangeError('FnkInt128 cannot be greater than 2^127 - 1');
+        }
+
+        if (value.gte(MIN_VALUE) && value.lte(MAX_VALUE)) {
+            new FnkIntSchema().serialize(writer, value);
+            return;
+        }
+
+        let isNegative = value.isNeg();
+        value = value.abs();
+
+        // Length encoding.
+        let byteLength = 16;
+        let bytes = value.toArrayLike(Buffer, 'le', 16);
+
+        for (let i = 15; i >= 0; i -= 1) {
+            if (bytes[i] != 0) {
+                break;
+            }
+
+            byteLength -= 1;
+        }
+
+        bytes = bytes.slice(0, byteLength);
+        byteLength = (byteLength - 2) | 0x80;
+
+        // Include sign bit.
+        if (isNegative) {
+            byteLength |= 0x40;
+        }
+
+        writer.writeByte(byteLength);
+        writer.writeBuffer(bytes);
+    }
+
+    deserialize(reader: FnkBorshReader): BN {
+        let firstByte = reader.readByte();
+
+        if ((firstByte & 0x80) === 0) {
+            // Flag encoding.
+            let number = new BN(firstByte & 0x1f);
+
+            if ((firstByte & 0x40) !== 0) {
+                // Read second byte.
+                let byte = reader.readByte();
+                number = number.or(new BN(byte).shln(5));
+            }
+
+            // Process sign bit.
+            if ((firstByte & 0x20) !== 0) {
+                number = number.neg();
+            }
+
+            return number;
+        } else {
+            // Length encoding.
+            let byteLength = firstByte & 0x3f;
+
+            if (byteLength >= 15) {
+                throw new RangeError('Incorrect FnkInt128 length');
+            }
+
+            byteLength += 2;
+
+            let number = ZERO;
+            let offset = 0;
+
+            for (let i = 0; i < byteLength; i += 1) {
+                let byte = new BN(reader.readByte()).shln(offset);
+                number = number.or(byte);
+                offset += 8;
+            }
+
+            if ((firstByte & 0x40) === 0) {
+                if (number.gt(MAX_VALUE_128)) {
+                    throw new RangeError('Incorrect FnkInt128 value');
+                }
+            } else {
+                if (number.lte(MIN_I128_ABS)) {
+                    number = number.neg();
+                } else {
+                    throw new RangeError('Incorrect FnkInt128 value');
+                }
+            }
+
+            return number;
+        }
+    }
+}
+
+export const FnkInt128 = new FnkInt128Schema();
@@ -1,6 +1,6 @@
-import { FnkUIntSchema } from './unsigned';
+import { FnkUInt128Schema, FnkUIntSchema } from './unsigned';
 import BN from 'bn.js';
-import { FnkIntSchema } from './integers';
+import { FnkInt128Schema, FnkIntSchema } from './integers';
 import { FnkBorshReader } from '../../deserializer';
 import { FnkBorshWriter } from '../../serializer';
 import { FnkBorshError } from '../../errors';
@@ -10,6 +10,9 @@ const ZERO = new BN(0);
 const U64_MAX_VALUE = new BN('18446744073709551615'); // 2^64 - 1
 const I64_MIN_VALUE = new BN('-9223372036854775808'); // -2^63
 const I64_MAX_VALUE = new BN('9223372036854775807'); // 2^63 - 1
+const U128_MAX_VALUE = new BN(1).shln(128).subn(1); // 2^128 - 1
+const I128_MIN_VALUE = new BN(1).shln(127).neg(); // -2^127
+const I128_MAX_VALUE = new BN(1).shln(127).subn(1); // 2^127 - 1
 
 export class FnkURange {
     readonly from: BN;
@@ -184,3 +187,163 @@ export class FnkRangeSchema implements FnkBorshSchema<FnkRange> {
 }
 
 export const TFnkRange = new FnkRangeSchema();
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export class FnkURange128 {
+    readonly from: BN;
+    readonly to: BN;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    constructor(from: BN, to: BN) {
+        if (from.gt(to)) {
+            throw new FnkBorshError('Invalid range: from > to');
+        }
+
+        if (from.lt(ZERO)) {
+            throw new RangeError('from(FnkUInt128) cannot be negative');
+        }
+
+        if (to.gt(U128_MAX_VALUE)) {
+            throw new RangeError(
+                'to(FnkUInt128) cannot be greater than 2^128 - 1'
+            );
+        }
+
+        this.from = from;
+        this.to = to;
+    }
+
+    static newUnbounded(from: BN) {
+        return new FnkURange128(from, U128_MAX_VALUE);
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    equals(other: FnkURange128): boolean {
+        return this.from.eq(other.from) && this.to.eq(other.to);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export class FnkURange128Schema implements FnkBorshSchema<FnkURange128> {
+    // METHODS ----------------------------------------------------------------
+
+    private pointAndLength(value: FnkURange128): { point: BN; length: BN } {
+        let point = value.from;
+        let distanceToEnd = U128_MAX_VALUE.sub(value.to);
+
+        // Shortcut for unbounded ranges.
+        if (distanceToEnd.isZero()) {
+            return {
+                point,
+                length: ZERO,
+            };
+        }
+
+        let distanceToStart = value.to.sub(value.from).addn(1);
+
+        let length: BN;
+
+        if (distanceToEnd.lte(distanceToStart)) {
+            length = distanceToEnd.neg();
+        } else {
+            length = distanceToStart;
+        }
+
+        return {
+            point,
+            length,
+        };
+    }
+
+    serialize(writer: FnkBorshWriter, value: FnkURange128) {
+        let { point, length } = this.pointAndLength(value);
+
+        new FnkUInt128Schema().serialize(writer, point);
+        new FnkInt128Schema().serialize(writer, length);
+    }
+
+    deserialize(reader: FnkBorshReader): FnkURange128 {
+        const point = new FnkUInt128Schema().deserialize(reader);
+        const length = new FnkInt128Schema().deserialize(reader);
+
+        let to = length.lten(0)
+            ? U128_MAX_VALUE.sub(length.abs())
+            : point.add(length).subn(1);
+
+        return new FnkURange128(point, to);
+    }
+}
+
+export const TFnkURange128 = new FnkURange128Schema();
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export class FnkRange128 {
+    readonly from: BN;
+    readonly to: BN;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    constructor(from: BN, to: BN) {
+        if (from.gt(to)) {
+            throw new FnkBorshError('Invalid range: from > to');
+        }
+
+        if (from.lt(I128_MIN_VALUE)) {
+            throw new RangeError('from(FnkInt128) cannot be lower than -2^127');
+        }
+
+        if (to.gt(I128_MAX_VALUE)) {
+            throw new RangeError(
+                'to(FnkInt128) cannot be greater than 2^127 - 1'
+            );
+        }
+
+        this.from = from;
+        this.to = to;
+    }
+
+    static newUnbounded(from: BN) {
+        return new FnkRange128(from, I128_MAX_VALUE);
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    equals(other: FnkRange128): boolean {
+        return this.from.eq(other.from) && this.to.eq(other.to);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export class FnkRange128Schema implements FnkBorshSchema<FnkRange128> {
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: FnkRange128) {
+        const fnkInt128Schema = new FnkInt128Schema();
+        fnkInt128Schema.serialize(writer, value.from);
+        fnkInt128Schema.serialize(writer, value.to);
+    }
+
+    deserialize(reader: FnkBorshReader): FnkRange128 {
+        const fnkInt128Schema = new FnkInt128Schema();
+        const from = fnkInt128Schema.deserialize(reader);
+        const to = fnkInt128Schema.deserialize(reader);
+
+        return new FnkRange128(from, to);
+    }
+}
+
+export const TFnkRange128 = new FnkRange128Schema();
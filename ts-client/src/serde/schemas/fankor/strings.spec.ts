@@ -1,5 +1,5 @@
 import assert from 'assert';
-import { FnkString } from './strings';
+import { FnkBoundedString, FnkFixedString, FnkString } from './strings';
 import { FnkBorshWriter } from '../../serializer';
 import { FnkBorshReader } from '../../deserializer';
 
@@ -29,3 +29,61 @@ describe('FnkString Tests', () => {
         }
     });
 });
+
+describe('FnkBoundedString Tests', () => {
+    it('test_serialize_deserialize', () => {
+        const schema = FnkBoundedString(12);
+        const text = 'Hello world!';
+
+        const writer = new FnkBorshWriter();
+        schema.serialize(writer, text);
+
+        const buffer = writer.buffer.slice(0, writer.length);
+        const reader = new FnkBorshReader(buffer);
+        const actual = schema.deserialize(reader);
+        assert(actual === text, `${actual} != ${text}`);
+
+        assert.throws(() =>
+            FnkBoundedString(11).deserialize(new FnkBorshReader(buffer))
+        );
+        assert.throws(() =>
+            FnkBoundedString(11).serialize(new FnkBorshWriter(), text)
+        );
+    });
+});
+
+describe('FnkFixedString Tests', () => {
+    it('test_serialize_deserialize', () => {
+        const schema = FnkFixedString(8);
+        const text = 'Hello';
+
+        const writer = new FnkBorshWriter();
+        schema.serialize(writer, text);
+
+        const buffer = writer.buffer.slice(0, writer.length);
+        assert(buffer.equals(Buffer.from('Hello\0\0\0', 'utf8')));
+
+        const reader = new FnkBorshReader(buffer);
+        const actual = schema.deserialize(reader);
+        assert(actual === text, `${actual} != ${text}`);
+        assert(reader.offset === 8);
+
+        assert.throws(() =>
+            schema.serialize(new FnkBorshWriter(), 'Too long!')
+        );
+    });
+
+    it('test_null_characters', () => {
+        const schema = FnkFixedString(8);
+
+        for (const text of ['Hi\0', '\0', 'H\0i']) {
+            assert.throws(() => schema.serialize(new FnkBorshWriter(), text));
+        }
+
+        assert.throws(() =>
+            schema.deserialize(
+                new FnkBorshReader(Buffer.from('H\0i\0\0\0\0\0', 'utf8'))
+            )
+        );
+    });
+});
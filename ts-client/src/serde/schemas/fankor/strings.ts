@@ -44,3 +44,128 @@ export class FnkStringSchema implements FnkBorshSchema<string> {
 }
 
 export const FnkString = new FnkStringSchema();
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export function FnkBoundedString(maxLength: number) {
+    return new FnkBoundedStringSchema(maxLength);
+}
+
+export class FnkBoundedStringSchema implements FnkBorshSchema<string> {
+    readonly maxLength: number;
+
+    // CONSTRUCTOR ------------------------------------------------------------
+
+    constructor(maxLength: number) {
+        this.maxLength = maxLength;
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: string) {
+        const length = Buffer.byteLength(value, 'utf8');
+
+        if (length > this.maxLength) {
+            throw new FnkBorshError(
+                `String length ${length} exceeds the maximum of ${this.maxLength}`
+            );
+        }
+
+        FnkString.serialize(writer, value);
+    }
+
+    deserialize(reader: FnkBorshReader): string {
+        const initialOffset = reader.offset;
+        const length = new FnkUIntSchema().deserialize(reader).toNumber();
+
+        if (length > this.maxLength) {
+            throw new FnkBorshError(
+                `String length ${length} exceeds the maximum of ${this.maxLength}`
+            );
+        }
+
+        reader.offset = initialOffset;
+        return FnkString.deserialize(reader);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export function FnkFixedString(size: number) {
+    return new FnkFixedStringSchema(size);
+}
+
+export class FnkFixedStringSchema implements FnkBorshSchema<string> {
+    readonly size: number;
+
+    // CONSTRUCTOR ------------------------------------------------------------
+
+    constructor(size: number) {
+        this.size = size;
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: string) {
+        const bytes = Buffer.from(value, 'utf8');
+
+        if (bytes.length > this.size) {
+            throw new FnkBorshError(
+                `String length ${bytes.length} exceeds the size of ${this.size}`
+            );
+        }
+
+        if (bytes.includes(0)) {
+            throw new FnkBorshError(
+                'Fixed strings cannot contain null characters'
+            );
+        }
+
+        const buffer = Buffer.alloc(this.size);
+        bytes.copy(buffer);
+        writer.writeBuffer(buffer);
+    }
+
+    deserialize(reader: FnkBorshReader): string {
+        const endIndex = reader.offset + this.size;
+
+        if (endIndex > reader.buffer.length) {
+            throw new FnkBorshError(
+                `Expected buffer length ${this.size} isn't within bounds`
+            );
+        }
+
+        const buf = reader.buffer.slice(reader.offset, endIndex);
+        reader.offset += this.size;
+
+        // Remove the zero padding.
+        let length = buf.length;
+        while (length > 0 && buf[length - 1] === 0) {
+            length -= 1;
+        }
+
+        if (buf.slice(0, length).includes(0)) {
+            throw new FnkBorshError(
+                'Fixed strings cannot contain null characters'
+            );
+        }
+
+        try {
+            // NOTE: Using TextDecoder to fail on invalid UTF-8
+            const ResolvedTextDecoder =
+                typeof TextDecoder !== 'function'
+                    ? encoding.TextDecoder
+                    : TextDecoder;
+            const textDecoder = new ResolvedTextDecoder('utf-8', {
+                fatal: true,
+            });
+            return textDecoder.decode(buf.slice(0, length));
+        } catch (e) {
+            throw new FnkBorshError(`Error decoding UTF-8 string: ${e}`);
+        }
+    }
+}
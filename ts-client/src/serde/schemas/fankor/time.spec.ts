@@ -0,0 +1,69 @@
+import assert from 'assert';
+import BN from 'bn.js';
+import { FnkDuration, FnkTimestamp } from './time';
+import { FnkInt } from './integers';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshReader } from '../../deserializer';
+
+describe('FnkTimestamp Tests', () => {
+    it('test_serialize_deserialize', () => {
+        for (const seconds of [
+            0, 31, -31, 1_700_000_000, -8_640_000_000_000,
+        ]) {
+            const date = new Date(seconds * 1000);
+            const writer = new FnkBorshWriter();
+            FnkTimestamp.serialize(writer, date);
+
+            const buffer = writer.buffer.slice(0, writer.length);
+            const reader = new FnkBorshReader(buffer);
+            const actual = FnkTimestamp.deserialize(reader);
+
+            assert.equal(actual.getTime(), date.getTime());
+        }
+    });
+
+    it('test_serialize_rounds_down', () => {
+        const writer = new FnkBorshWriter();
+        FnkTimestamp.serialize(writer, new Date(-1500));
+
+        const buffer = writer.buffer.slice(0, writer.length);
+        const reader = new FnkBorshReader(buffer);
+
+        assert(FnkInt.deserialize(reader).eqn(-2));
+    });
+
+    it('test_deserialize_out_of_range', () => {
+        const writer = new FnkBorshWriter();
+        FnkInt.serialize(writer, new BN('9223372036854775807'));
+
+        const buffer = writer.buffer.slice(0, writer.length);
+        const reader = new FnkBorshReader(buffer);
+
+        assert.throws(() => FnkTimestamp.deserialize(reader));
+    });
+});
+
+describe('FnkDuration Tests', () => {
+    it('test_serialize_deserialize', () => {
+        for (const milliseconds of [0, 1000, -1000, 3_600_000, 86_400_000]) {
+            const writer = new FnkBorshWriter();
+            FnkDuration.serialize(writer, milliseconds);
+
+            const buffer = writer.buffer.slice(0, writer.length);
+            const reader = new FnkBorshReader(buffer);
+            const actual = FnkDuration.deserialize(reader);
+
+            assert.equal(actual, milliseconds);
+        }
+    });
+
+    it('test_serialize_truncates', () => {
+        const writer = new FnkBorshWriter();
+        FnkDuration.serialize(writer, -1500);
+
+        const buffer = writer.buffer.slice(0, writer.length);
+        const reader = new FnkBorshReader(buffer);
+
+        assert(FnkInt.deserialize(reader).eqn(-1));
+    });
+});
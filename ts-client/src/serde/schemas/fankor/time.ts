@@ -0,0 +1,79 @@
+import BN from 'bn.js';
+import { FnkIntSchema } from './integers';
+import { FnkBorshReader } from '../../deserializer';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshError } from '../../errors';
+import { FnkBorshSchema } from '../../borsh';
+
+const MAX_DATE_SECONDS = new BN(8_640_000_000_000); // Max Date in seconds
+const MAX_SAFE_SECONDS = new BN(Math.floor(Number.MAX_SAFE_INTEGER / 1000));
+
+/**
+ * Unix timestamp in seconds mapped to a JS `Date`. The milliseconds of the date
+ * are discarded during serialization, i.e. it is rounded down to the second.
+ */
+export class FnkTimestampSchema implements FnkBorshSchema<Date> {
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: Date) {
+        const milliseconds = value.getTime();
+
+        if (Number.isNaN(milliseconds)) {
+            throw new FnkBorshError(
+                'FnkTimestamp cannot serialize an invalid Date'
+            );
+        }
+
+        const seconds = new BN(Math.floor(milliseconds / 1000));
+        new FnkIntSchema().serialize(writer, seconds);
+    }
+
+    deserialize(reader: FnkBorshReader): Date {
+        const seconds = new FnkIntSchema().deserialize(reader);
+
+        if (seconds.abs().gt(MAX_DATE_SECONDS)) {
+            throw new FnkBorshError(
+                `FnkTimestamp out of the Date range: ${seconds.toString()}`
+            );
+        }
+
+        return new Date(seconds.toNumber() * 1000);
+    }
+}
+
+export const FnkTimestamp = new FnkTimestampSchema();
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/**
+ * Duration in seconds mapped to a number of milliseconds. The milliseconds
+ * that do not complete a second are discarded during serialization.
+ */
+export class FnkDurationSchema implements FnkBorshSchema<number> {
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: number) {
+        if (!Number.isFinite(value)) {
+            throw new RangeError('FnkDuration must be a finite number');
+        }
+
+        const seconds = new BN(Math.trunc(value / 1000));
+        new FnkIntSchema().serialize(writer, seconds);
+    }
+
+    deserialize(reader: FnkBorshReader): number {
+        const seconds = new FnkIntSchema().deserialize(reader);
+
+        if (seconds.abs().gt(MAX_SAFE_SECONDS)) {
+            throw new FnkBorshError(
+                `FnkDuration cannot be represented in milliseconds: ${seconds.toString()}`
+            );
+        }
+
+        return seconds.toNumber() * 1000;
+    }
+}
+
+export const FnkDuration = new FnkDurationSchema();
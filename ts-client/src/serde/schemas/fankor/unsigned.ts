@@ -100,3 +100,89 @@ export class FnkUIntSchema implements FnkBorshSchema<BN> {
 }
 
 export const FnkUInt = new FnkUIntSchema();
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+const MAX_VALUE_128 = new BN(1).shln(128).subn(1); // 2^128 - 1
+
+/**
+ * Same encoding as `FnkUInt` but the length encoding can use up to 16 bytes,
+ * so numbers lower than 2^64 are encoded exactly as a `FnkUInt`.
+ */
+export class FnkUInt128Schema implements FnkBorshSchema<BN> {
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: BN) {
+        if (value.lt(ZERO)) {
+            throw new RangeError('FnkUInt128 cannot be negative');
+        }
+
+        if (value.gt(MAX_VALUE_128)) {
+            throw new RangeError('FnkUInt128 cannot be greater than 2^128 - 1');
+        }
+
+        if (value.lte(MAX_VALUE)) {
+            new FnkUIntSchema().serialize(writer, value);
+            return;
+        }
+
+        // Length encoding.
+        let byteLength = 16;
+        let bytes = value.toArrayLike(Buffer, 'le', 16);
+
+        for (let i = 15; i >= 0; i -= 1) {
+            if (bytes[i] != 0) {
+                break;
+            }
+
+            byteLength -= 1;
+        }
+
+        bytes = bytes.slice(0, byteLength);
+        byteLength = (byteLength - 2) | 0x80;
+
+        writer.writeByte(byteLength);
+        writer.writeBuffer(bytes);
+    }
+
+    deserialize(reader: FnkBorshReader): BN {
+        let firstByte = reader.readByte();
+
+        if ((firstByte & 0x80) === 0) {
+            // Flag encoding.
+            let number = new BN(firstByte & 0x3f);
+
+            if ((firstByte & 0x40) !== 0) {
+                // Read second byte.
+                let byte = reader.readByte();
+                number = number.or(new BN(byte).shln(6));
+            }
+
+            return number;
+        } else {
+            // Length encoding.
+            let byteLength = firstByte & 0x7f;
+
+            if (byteLength >= 15) {
+                throw new RangeError('Incorrect FnkUInt128 length');
+            }
+
+            byteLength += 2;
+
+            let number = ZERO;
+            let offset = 0;
+
+            for (let i = 0; i < byteLength; i += 1) {
+                let byte = new BN(reader.readByte()).shln(offset);
+                number = number.or(byte);
+                offset += 8;
+            }
+
+            return number;
+        }
+    }
+}
+
+export const FnkUInt128 = new FnkUInt128Schema();
@@ -75,3 +75,90 @@ export class FnkVecSchema<S extends FnkBorshSchema<any>>
         return result;
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export function FnkBoundedByteVec(maxLength: number) {
+    return new FnkBoundedByteVecSchema(maxLength);
+}
+
+export class FnkBoundedByteVecSchema implements FnkBorshSchema<Uint8Array> {
+    readonly maxLength: number;
+
+    // CONSTRUCTOR ------------------------------------------------------------
+
+    constructor(maxLength: number) {
+        this.maxLength = maxLength;
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: Uint8Array) {
+        checkBoundedLength(value.length, this.maxLength);
+        FnkByteVec.serialize(writer, value);
+    }
+
+    deserialize(reader: FnkBorshReader): Uint8Array {
+        const initialOffset = reader.offset;
+        const size = new FnkUIntSchema().deserialize(reader).toNumber();
+        checkBoundedLength(size, this.maxLength);
+
+        reader.offset = initialOffset;
+        return FnkByteVec.deserialize(reader);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export function FnkBoundedVec<S extends FnkBorshSchema<any>>(
+    schema: S,
+    maxLength: number
+) {
+    return new FnkBoundedVecSchema(schema, maxLength);
+}
+
+export class FnkBoundedVecSchema<S extends FnkBorshSchema<any>>
+    implements FnkBorshSchema<InferFnkBorshSchemaInner<S>[]>
+{
+    readonly schema: S;
+    readonly maxLength: number;
+
+    // CONSTRUCTOR ------------------------------------------------------------
+
+    constructor(schema: S, maxLength: number) {
+        this.schema = schema;
+        this.maxLength = maxLength;
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: InferFnkBorshSchemaInner<S>[]) {
+        checkBoundedLength(value.length, this.maxLength);
+        new FnkVecSchema(this.schema).serialize(writer, value);
+    }
+
+    deserialize(reader: FnkBorshReader): InferFnkBorshSchemaInner<S>[] {
+        const initialOffset = reader.offset;
+        const size = new FnkUIntSchema().deserialize(reader).toNumber();
+        checkBoundedLength(size, this.maxLength);
+
+        reader.offset = initialOffset;
+        return new FnkVecSchema(this.schema).deserialize(reader);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+function checkBoundedLength(length: number, maxLength: number) {
+    if (length > maxLength) {
+        throw new FnkBorshError(
+            `Vector length ${length} exceeds the maximum of ${maxLength}`
+        );
+    }
+}